@@ -0,0 +1,264 @@
+//! Derive macros for `crab-fp`.
+//!
+//! These are re-exported from `crab-fp` when its `derive` feature is enabled,
+//! so they are normally used as `crab_fp::Bifunctor`.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{
+    Data, DeriveInput, Error, Fields, GenericParam, Ident, Type, parse_macro_input,
+    spanned::Spanned,
+};
+
+/// Derives `Kinded2` and `Bifunctor` for a struct or enum with at least two
+/// type parameters.
+///
+/// The last two type parameters are the ones mapped: fields whose type is
+/// exactly the second-to-last parameter are mapped by `first`, and fields whose
+/// type is exactly the last parameter are mapped by `second`. Every other field
+/// is moved across unchanged. A field that mentions either parameter in any
+/// other way, such as `Vec<L>`, is rejected, since there is no way to map it
+/// without a functor instance for the outer type.
+///
+/// The derive also defines the `Generic2` kind for the type, named after it
+/// with a `Kind2` suffix, like `PairKind2` for `(A, B)`.
+///
+/// # Example
+/// ```
+/// use crab_fp::Bifunctor;
+///
+/// #[derive(Bifunctor, Debug, PartialEq)]
+/// enum Either<L, R> {
+///     Left(L),
+///     Right(R),
+/// }
+///
+/// assert_eq!(Either::<i32, &str>::Left(1).first(|x| x + 1), Either::Left(2));
+/// ```
+#[proc_macro_derive(Bifunctor)]
+pub fn derive_bifunctor(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_bifunctor(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Which mapping function, if any, a field is passed through.
+#[derive(Clone, Copy)]
+enum Slot {
+    First,
+    Second,
+    Untouched,
+}
+
+fn expand_bifunctor(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let mut params = Vec::new();
+    for param in &input.generics.params {
+        match param {
+            GenericParam::Type(ty) if ty.bounds.is_empty() && ty.default.is_none() => {
+                params.push(ty.ident.clone())
+            }
+            GenericParam::Type(ty) => {
+                return Err(Error::new(
+                    ty.span(),
+                    "#[derive(Bifunctor)] does not support bounds or defaults on type parameters",
+                ));
+            }
+            other => {
+                return Err(Error::new(
+                    other.span(),
+                    "#[derive(Bifunctor)] only supports type parameters",
+                ));
+            }
+        }
+    }
+    if let Some(where_clause) = &input.generics.where_clause {
+        return Err(Error::new(
+            where_clause.span(),
+            "#[derive(Bifunctor)] does not support where clauses",
+        ));
+    }
+    if params.len() < 2 {
+        return Err(Error::new(
+            input.ident.span(),
+            "#[derive(Bifunctor)] requires at least two type parameters",
+        ));
+    }
+
+    let (fixed, mapped) = params.split_at(params.len() - 2);
+    let (left, right) = (&mapped[0], &mapped[1]);
+    let name = &input.ident;
+    let vis = &input.vis;
+    let kind = format_ident!("{}Kind2", name);
+
+    let [a, b, c, d, f, g] =
+        ["__A", "__B", "__C", "__D", "__F", "__G"].map(|s| Ident::new(s, Span::call_site()));
+
+    let bimap = map_body(input, left, right, Slot::First, Slot::Second)?;
+    let first = map_body(input, left, right, Slot::First, Slot::Untouched)?;
+    let second = map_body(input, left, right, Slot::Untouched, Slot::Second)?;
+
+    let kind_doc = format!("The `Generic2` kind of `{name}`, derived by `#[derive(Bifunctor)]`.");
+
+    Ok(quote! {
+        #[doc = #kind_doc]
+        #vis struct #kind<#(#fixed),*>(::core::marker::PhantomData<fn() -> (#(#fixed,)*)>);
+
+        #[automatically_derived]
+        impl<#(#fixed),*> ::crab_fp::Generic2 for #kind<#(#fixed),*> {
+            type Rep2<#a, #b> = #name<#(#fixed,)* #a, #b>;
+        }
+
+        #[automatically_derived]
+        impl<#(#fixed,)* #left, #right> ::crab_fp::Kinded2<#left, #right>
+            for #name<#(#fixed,)* #left, #right>
+        {
+            type Kind2 = #kind<#(#fixed),*>;
+        }
+
+        #[automatically_derived]
+        #[allow(unused_mut, unused_variables)]
+        impl<#(#fixed,)* #a, #c> ::crab_fp::Bifunctor<#a, #c> for #name<#(#fixed,)* #a, #c> {
+            fn bimap<#b, #d, #f: FnMut(#a) -> #b, #g: FnMut(#c) -> #d>(
+                self,
+                mut f: #f,
+                mut g: #g,
+            ) -> #name<#(#fixed,)* #b, #d> {
+                #bimap
+            }
+
+            fn first<#b, #f: FnMut(#a) -> #b>(self, mut f: #f) -> #name<#(#fixed,)* #b, #c> {
+                #first
+            }
+
+            fn second<#d, #g: FnMut(#c) -> #d>(self, mut g: #g) -> #name<#(#fixed,)* #a, #d> {
+                #second
+            }
+        }
+    })
+}
+
+/// Builds a `match self` that rebuilds the value, passing `left` fields
+/// through `on_left` and `right` fields through `on_right`.
+fn map_body(
+    input: &DeriveInput,
+    left: &Ident,
+    right: &Ident,
+    on_left: Slot,
+    on_right: Slot,
+) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let arms = match &input.data {
+        Data::Struct(data) => vec![map_arm(
+            quote!(#name),
+            &data.fields,
+            left,
+            right,
+            on_left,
+            on_right,
+        )?],
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .map(|v| {
+                let variant = &v.ident;
+                map_arm(
+                    quote!(#name::#variant),
+                    &v.fields,
+                    left,
+                    right,
+                    on_left,
+                    on_right,
+                )
+            })
+            .collect::<syn::Result<_>>()?,
+        Data::Union(data) => {
+            return Err(Error::new(
+                data.union_token.span,
+                "#[derive(Bifunctor)] does not support unions",
+            ));
+        }
+    };
+    Ok(quote! {
+        match self {
+            #(#arms)*
+        }
+    })
+}
+
+fn map_arm(
+    path: TokenStream2,
+    fields: &Fields,
+    left: &Ident,
+    right: &Ident,
+    on_left: Slot,
+    on_right: Slot,
+) -> syn::Result<TokenStream2> {
+    let mut bindings = Vec::new();
+    let mut values = Vec::new();
+    for (i, field) in fields.iter().enumerate() {
+        let binding = format_ident!("__field{}", i);
+        let slot = match field_slot(&field.ty, left, right)? {
+            Some(true) => on_left,
+            Some(false) => on_right,
+            None => Slot::Untouched,
+        };
+        values.push(match slot {
+            Slot::First => quote!(f(#binding)),
+            Slot::Second => quote!(g(#binding)),
+            Slot::Untouched => quote!(#binding),
+        });
+        bindings.push(binding);
+    }
+    Ok(match fields {
+        Fields::Named(named) => {
+            let names: Vec<_> = named.named.iter().map(|f| &f.ident).collect();
+            quote! {
+                #path { #(#names: #bindings),* } => #path { #(#names: #values),* },
+            }
+        }
+        Fields::Unnamed(_) => quote! {
+            #path(#(#bindings),*) => #path(#(#values),*),
+        },
+        Fields::Unit => quote! {
+            #path => #path,
+        },
+    })
+}
+
+/// Returns `Some(true)` for a field of type `left`, `Some(false)` for a field
+/// of type `right`, and `None` for a field that mentions neither.
+fn field_slot(ty: &Type, left: &Ident, right: &Ident) -> syn::Result<Option<bool>> {
+    if let Type::Path(path) = ty
+        && path.qself.is_none()
+    {
+        if path.path.is_ident(left) {
+            return Ok(Some(true));
+        }
+        if path.path.is_ident(right) {
+            return Ok(Some(false));
+        }
+    }
+    if mentions(ty, left) || mentions(ty, right) {
+        return Err(Error::new(
+            ty.span(),
+            format!(
+                "#[derive(Bifunctor)] can only map fields whose type is exactly `{left}` or `{right}`"
+            ),
+        ));
+    }
+    Ok(None)
+}
+
+fn mentions(ty: &Type, param: &Ident) -> bool {
+    let param = param.to_string();
+    fn walk(tokens: TokenStream2, param: &str) -> bool {
+        tokens.into_iter().any(|tt| match tt {
+            proc_macro2::TokenTree::Ident(ident) => ident == param,
+            proc_macro2::TokenTree::Group(group) => walk(group.stream(), param),
+            _ => false,
+        })
+    }
+    walk(quote!(#ty), &param)
+}
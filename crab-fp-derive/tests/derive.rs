@@ -0,0 +1,7 @@
+#[test]
+fn bifunctor() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/bifunctor_enum.rs");
+    t.pass("tests/ui/bifunctor_tuple_struct.rs");
+    t.compile_fail("tests/ui/bifunctor_single_param.rs");
+}
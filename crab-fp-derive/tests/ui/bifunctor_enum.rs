@@ -0,0 +1,48 @@
+use crab_fp::Bifunctor;
+
+#[derive(Bifunctor, Debug, PartialEq)]
+enum MyEither<L, R> {
+    L(L),
+    R(R),
+}
+
+#[derive(Bifunctor, Debug, PartialEq)]
+enum Tagged<T, A, B> {
+    Both { tag: T, left: A, right: B },
+    Neither,
+}
+
+fn main() {
+    let l: MyEither<i32, &str> = MyEither::L(1);
+    assert_eq!(l.bimap(|x| x + 1, str::len), MyEither::L(2));
+
+    let r: MyEither<i32, &str> = MyEither::R("abc");
+    assert_eq!(r.bimap(|x| x + 1, str::len), MyEither::R(3));
+
+    let l: MyEither<i32, &str> = MyEither::L(1);
+    assert_eq!(l.first(|x| x * 10), MyEither::L(10));
+
+    let r: MyEither<i32, &str> = MyEither::R("abc");
+    assert_eq!(r.second(str::len), MyEither::R(3));
+
+    // Identity: bimap id id = id
+    let r: MyEither<i32, &str> = MyEither::R("abc");
+    assert_eq!(r.bimap(|x| x, |s| s), MyEither::R("abc"));
+
+    let both = Tagged::Both {
+        tag: 'x',
+        left: 2,
+        right: "four",
+    };
+    assert_eq!(
+        both.bimap(|x| x * 2, str::len),
+        Tagged::Both {
+            tag: 'x',
+            left: 4,
+            right: 4
+        }
+    );
+
+    let neither: Tagged<char, i32, &str> = Tagged::Neither;
+    assert_eq!(neither.first(|x| x + 1), Tagged::Neither);
+}
@@ -0,0 +1,6 @@
+use crab_fp::Bifunctor;
+
+#[derive(Bifunctor)]
+struct Wrapper<A>(A);
+
+fn main() {}
@@ -0,0 +1,28 @@
+use crab_fp::Bifunctor;
+
+#[derive(Bifunctor, Debug, PartialEq)]
+struct Pair<A, B>(A, B, &'static str);
+
+fn main() {
+    let p = Pair(2, 'a', "label");
+    assert_eq!(
+        p.bimap(|x| x * 3, |c: char| c.is_alphabetic()),
+        Pair(6, true, "label")
+    );
+
+    let p = Pair(2, 'a', "label");
+    assert_eq!(p.first(|x| x + 1), Pair(3, 'a', "label"));
+
+    let p = Pair(2, 'a', "label");
+    assert_eq!(
+        p.second(|c: char| c.to_ascii_uppercase()),
+        Pair(2, 'A', "label")
+    );
+
+    // Composition: bimap (f1 . g1) (f2 . g2) = bimap f1 f2 . bimap g1 g2
+    let lhs = Pair(2, 3, "").bimap(|x| (x + 1) * 2, |y| (y * 3) - 1);
+    let rhs = Pair(2, 3, "")
+        .bimap(|x| x + 1, |y| y * 3)
+        .bimap(|x| x * 2, |y| y - 1);
+    assert_eq!(lhs, rhs);
+}
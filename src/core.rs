@@ -6,12 +6,23 @@
 //!   resolution
 //! - `Functor` - Represents types that can be mapped over (supporting `fmap`
 //!   operation)
+//! - `Ref1` - Represents kinds whose containers can be mapped over by
+//!   reference (supporting `fmap_ref` operation)
 //! - `Applicative` - Extends `Functor` with the ability to apply functions
 //!   contained within a context to values in the same context
 //! - `Monad` - Extends `Applicative` with the ability to bind functions to
 //!   values in a context
+//! - `ShortCircuit` - Extends `Monad` for computations that stop at the
+//!   first failure, so they can be stepped in a loop
 //! - `Bifunctor` - Extends `Kinded2` with the ability to map over two type
 //!   parameters independently
+//! - `Foldable` - Represents types whose contained values can be reduced to a
+//!   single summary value
+//! - `Contravariant` - Represents consumers of values that can be adapted to
+//!   accept a different input type (supporting `comap` operation)
+//! - `Semigroup` - Represents types with an associative binary operation
+//!   (supporting `combine` operation)
+//! - `Monoid` - Extends `Semigroup` with an identity element
 //!
 //! These traits form a hierarchy (Applicative extends Functor) and enable
 //! composable, type-safe functional programming patterns in Rust.
@@ -83,6 +94,52 @@ pub trait Functor<A>: Kinded1<A> {
     fn fmap<B, M: FnMut(A) -> B>(self, f: M) -> Apply1<Self::Kind1, B>;
 }
 
+/// A kind whose containers can be mapped over by reference.
+///
+/// `Functor` consumes its container, and references to containers cannot be
+/// given a `Generic1` kind of their own, since `Rep1<A>` has no way to require
+/// that `A` outlives the borrow. `Ref1` is implemented on the kind instead:
+/// generic code takes `&Apply1<F, A>` with `F: Ref1` and gets an owned
+/// container of the same kind back, leaving the original untouched.
+///
+/// Laws:
+/// - Identity: `F::fmap_ref(&x, |a| a.clone()) == x`
+/// - Composition: `F::fmap_ref(&x, |a| g(f(a))) == F::fmap_ref(&x, f).fmap(g)`
+pub trait Ref1: Generic1 {
+    /// Maps a function over the values of a borrowed container.
+    ///
+    /// # Parameters
+    /// * `fa` - The container to map over, which is only borrowed
+    /// * `f` - A function from borrowed values of type `A` to values of type `B`
+    ///
+    /// # Returns
+    /// A new container of the same kind containing the transformed values.
+    fn fmap_ref<'a, A: 'a, B, M: FnMut(&'a A) -> B>(
+        fa: &'a Apply1<Self, A>,
+        f: M,
+    ) -> Apply1<Self, B>;
+}
+
+/// Containers whose values can be mapped in place.
+///
+/// Where `fmap` consumes its container and builds a new one, `fmap_mut`
+/// updates each value through a mutable reference, so the container and its
+/// allocation are reused. This only fits mappings from `A` to `A`.
+///
+/// Laws:
+/// - Agreement: after `x.fmap_mut(|a| *a = f(a.clone()))`, `x` equals the
+///   original `x.fmap(f)`
+///
+/// # Type Parameters
+/// * `A` - The type of values contained in this container
+pub trait FunctorMut<A> {
+    /// Mutates the contained value(s) in place.
+    ///
+    /// # Parameters
+    /// * `f` - A function called once with each contained value, in order
+    fn fmap_mut<F: FnMut(&mut A)>(&mut self, f: F);
+}
+
 /// A trait representing applicative functors.
 ///
 /// Applicative functors extend the capabilities of functors by allowing:
@@ -152,6 +209,63 @@ pub trait Monad<A>: Applicative<A> {
     fn bind<B, F: FnMut(A) -> Apply1<Self::Kind1, B>>(self, f: F) -> Apply1<Self::Kind1, B>;
 }
 
+/// A trait representing applicative functors with a choice between
+/// alternatives.
+///
+/// `alt` picks between two computations of the same kind, and `nothing` is
+/// the computation that always fails, so it is the identity of `alt`. For
+/// `Option`, `alt` keeps the first `Some`; for `Vec`, it concatenates.
+///
+/// Laws:
+/// - Left identity: `nothing().alt(x) == x`
+/// - Right identity: `x.alt(nothing()) == x`
+/// - Associativity: `x.alt(y).alt(z) == x.alt(y.alt(z))`
+///
+/// # Type Parameters
+/// * `A` - The type of values contained in this applicative functor
+pub trait Alternative<A>: Applicative<A> {
+    /// Returns the computation that always fails.
+    fn nothing() -> Apply1<Self::Kind1, A>;
+
+    /// Chooses between this computation and `other`.
+    ///
+    /// # Parameters
+    /// * `other` - The alternative to fall back on
+    ///
+    /// # Returns
+    /// The combination of both alternatives, which for failure-like kinds is
+    /// the first one that succeeded.
+    fn alt(self, other: Apply1<Self::Kind1, A>) -> Apply1<Self::Kind1, A>;
+}
+
+/// A trait representing monads that stop at the first failure.
+///
+/// A short-circuiting computation either carries a value or has stopped with
+/// a residual that no later step can recover from: `()` for a `None`, the
+/// error for an `Err`. Splitting a computation with `branch` lets a loop run
+/// its steps one after another in constant stack space, where chaining them
+/// with `bind` would nest one call per step. Since the residual does not
+/// depend on the value type, it can be rebuilt at any value type with
+/// `from_residual`.
+///
+/// Laws:
+/// - Round trip: `M::from_residual(r).branch() == ControlFlow::Break(r)`
+/// - Agreement with `bind`: `m.bind(f)` is `f(a)` when `m.branch()` is
+///   `Continue(a)`, and `from_residual(r)` when it is `Break(r)`
+///
+/// # Type Parameters
+/// * `A` - The type of values contained in this monad
+pub trait ShortCircuit<A>: Monad<A> {
+    /// What is left of a computation that has stopped.
+    type Residual;
+
+    /// Returns the value to continue with, or the residual to stop with.
+    fn branch(self) -> ::core::ops::ControlFlow<Self::Residual, A>;
+
+    /// Rebuilds a stopped computation from its residual.
+    fn from_residual(residual: Self::Residual) -> Self;
+}
+
 /// A trait representing types that can be mapped over in two dimensions (bifunctors).
 ///
 /// Bifunctors are types with two type parameters, both of which can be mapped over
@@ -197,3 +311,171 @@ pub trait Bifunctor<A, C>: Kinded2<A, C> {
     /// A new bifunctor with the second type parameter transformed.
     fn second<D, G: FnMut(C) -> D>(self, g: G) -> Apply2<Self::Kind2, A, D>;
 }
+
+/// A trait representing data structures that can be folded into a summary value.
+///
+/// Foldable types allow reducing all of their contained values to a single
+/// result by repeatedly applying a combining function, either from the left
+/// (first element first) or from the right (last element first).
+///
+/// Both folds are strict: the whole structure is consumed and the combining
+/// function is applied to every element before a result is returned. Unlike
+/// the lazy right fold found in Haskell, `fold_right` cannot short-circuit or
+/// operate on infinite structures, but in exchange implementations are
+/// iterative and do not grow the stack with the size of the input.
+///
+/// Laws:
+/// - For an associative operation `op` with identity `e`:
+///   `x.fold_left(e, op) == x.fold_right(e, |a, b| op(a, b))`
+///
+/// # Type Parameters
+/// * `A` - The type of values contained in this foldable
+pub trait Foldable<A> {
+    /// Folds the contained values from left to right.
+    ///
+    /// # Parameters
+    /// * `init` - The initial accumulator value
+    /// * `f` - A function combining the accumulator with the next value
+    ///
+    /// # Returns
+    /// The final accumulator value after visiting every element.
+    fn fold_left<B, F: FnMut(B, A) -> B>(self, init: B, f: F) -> B;
+
+    /// Folds the contained values from right to left.
+    ///
+    /// The last element is combined with `init` first, so for a list
+    /// `[a, b, c]` this computes `f(a, f(b, f(c, init)))`.
+    ///
+    /// # Parameters
+    /// * `init` - The initial accumulator value
+    /// * `f` - A function combining the next value with the accumulator
+    ///
+    /// # Returns
+    /// The final accumulator value after visiting every element.
+    fn fold_right<B, F: FnMut(A, B) -> B>(self, init: B, f: F) -> B;
+
+    /// Returns the first value satisfying a predicate.
+    ///
+    /// The predicate is not called again once a match has been found.
+    ///
+    /// # Parameters
+    /// * `f` - A predicate over references to the contained values
+    ///
+    /// # Returns
+    /// `Some` with the first matching value, or `None` if no value matches.
+    fn find<F: FnMut(&A) -> bool>(self, mut f: F) -> Option<A>
+    where
+        Self: Sized,
+    {
+        self.fold_left(None, |found, a| match found {
+            Some(_) => found,
+            None if f(&a) => Some(a),
+            None => None,
+        })
+    }
+
+    /// Checks whether any value satisfies a predicate.
+    ///
+    /// The predicate is not called again once it has returned `true`.
+    ///
+    /// # Parameters
+    /// * `f` - A predicate over references to the contained values
+    ///
+    /// # Returns
+    /// `true` if at least one value matches, `false` otherwise (including when empty).
+    fn any<F: FnMut(&A) -> bool>(self, mut f: F) -> bool
+    where
+        Self: Sized,
+    {
+        self.fold_left(false, |acc, a| acc || f(&a))
+    }
+
+    /// Checks whether every value satisfies a predicate.
+    ///
+    /// The predicate is not called again once it has returned `false`.
+    ///
+    /// # Parameters
+    /// * `f` - A predicate over references to the contained values
+    ///
+    /// # Returns
+    /// `true` if every value matches (including when empty), `false` otherwise.
+    fn all<F: FnMut(&A) -> bool>(self, mut f: F) -> bool
+    where
+        Self: Sized,
+    {
+        self.fold_left(true, |acc, a| acc && f(&a))
+    }
+
+    /// Maps every value into a monoid and combines the results, left to right.
+    ///
+    /// # Parameters
+    /// * `f` - A function mapping each value into the monoid `M`
+    ///
+    /// # Returns
+    /// The combination of every mapped value, or `M::empty()` when empty.
+    fn fold_map<M: Monoid, F: FnMut(A) -> M>(self, mut f: F) -> M
+    where
+        Self: Sized,
+    {
+        self.fold_left(M::empty(), |acc, a| acc.combine(f(a)))
+    }
+}
+
+/// A trait representing contravariant functors.
+///
+/// Where a `Functor` produces values that can be transformed after the fact, a
+/// contravariant functor consumes values, so it is adapted by transforming its
+/// input before it arrives. Mapping an `B -> A` function over a consumer of `A`
+/// yields a consumer of `B`.
+///
+/// Consumers usually erase their behaviour behind a boxed closure, so the
+/// adapting function must be `'static`.
+///
+/// Laws:
+/// - Identity: `x.comap(identity) == x`
+/// - Composition: `x.comap(f).comap(g) == x.comap(|b| f(g(b)))`
+///
+/// # Type Parameters
+/// * `A` - The type of values consumed by this contravariant functor
+pub trait Contravariant<A>: Kinded1<A> {
+    /// Adapts this consumer to accept values of another type.
+    ///
+    /// # Parameters
+    /// * `f` - A function that transforms values of type `B` into values of type `A`
+    ///   before they are consumed
+    ///
+    /// # Returns
+    /// A new consumer of the same kind accepting values of type `B`.
+    fn comap<B, F: FnMut(B) -> A + 'static>(self, f: F) -> Apply1<Self::Kind1, B>;
+}
+
+/// A trait representing semigroups.
+///
+/// A semigroup is a type with an associative binary operation that combines two
+/// values into one.
+///
+/// Laws:
+/// - Associativity: `a.combine(b).combine(c) == a.combine(b.combine(c))`
+pub trait Semigroup {
+    /// Combines this value with another value of the same type.
+    ///
+    /// # Parameters
+    /// * `other` - The value to combine with, placed on the right
+    ///
+    /// # Returns
+    /// The combination of both values.
+    fn combine(self, other: Self) -> Self;
+}
+
+/// A trait representing monoids.
+///
+/// Monoids extend semigroups with an identity element that leaves any value
+/// unchanged when combined with it.
+///
+/// Laws:
+/// - Left identity: `Monoid::empty().combine(a) == a`
+/// - Right identity: `a.combine(Monoid::empty()) == a`
+pub trait Monoid: Semigroup {
+    /// Returns the identity element for `combine`.
+    fn empty() -> Self;
+}
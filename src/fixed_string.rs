@@ -6,6 +6,10 @@
 
 const BUFFER_SIZE: usize = 256;
 
+/// The error returned when a string would grow past its fixed capacity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapacityError;
+
 #[derive(Clone, PartialEq, Eq)]
 pub struct String {
     /// The internal buffer that stores the string data
@@ -31,21 +35,36 @@ impl String {
     }
 
     pub fn push_str(&mut self, s: &str) {
+        if self.try_push_str(s).is_err() {
+            panic!("not enough space in buffer");
+        }
+    }
+
+    pub fn push(&mut self, c: char) {
+        let mut buf = [0; 4];
+        let encoded = c.encode_utf8(&mut buf);
+        self.push_str(encoded)
+    }
+
+    /// Appends a string slice, leaving the string unchanged if it does not fit
+    pub fn try_push_str(&mut self, s: &str) -> Result<(), CapacityError> {
         let current_len = self.len();
         if s.len() > self.available_space() {
-            panic!("not enough space in buffer");
+            return Err(CapacityError);
         }
 
         let src = s.as_bytes();
         let dst = &mut self.buffer[current_len..current_len + s.len()];
         dst.copy_from_slice(src);
         self.size += s.len();
+        Ok(())
     }
 
-    pub fn push(&mut self, c: char) {
+    /// Appends a char, leaving the string unchanged if it does not fit
+    pub fn try_push(&mut self, c: char) -> Result<(), CapacityError> {
         let mut buf = [0; 4];
         let encoded = c.encode_utf8(&mut buf);
-        self.push_str(encoded)
+        self.try_push_str(encoded)
     }
 
     /// Returns a string slice containing the entire string
@@ -0,0 +1,188 @@
+#[cfg(not(feature = "no_std"))]
+pub mod any_result_impls {
+    use std::error::Error;
+    use std::fmt;
+
+    /// A `Result` whose error can be any boxed error.
+    ///
+    /// Since `AnyResult` is an ordinary `Result`, it already has the `Functor`,
+    /// `Applicative` and `Monad` instances. `AnyResultExt` and `from_err` help
+    /// bring results with other concrete error types into it, so steps that
+    /// fail in different ways can be chained with one error type.
+    pub type AnyResult<A> = Result<A, Box<dyn Error>>;
+
+    /// Converts a result with any convertible error into an `AnyResult`.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::{AnyResult, from_err};
+    ///
+    /// let parsed: AnyResult<i32> = from_err("12".parse::<i32>());
+    /// assert_eq!(parsed.unwrap(), 12);
+    /// ```
+    pub fn from_err<A, E: Into<Box<dyn Error>>>(r: Result<A, E>) -> AnyResult<A> {
+        r.map_err(Into::into)
+    }
+
+    /// An error annotated with a message describing what was being done.
+    ///
+    /// Displays as `context: error`, and reports the wrapped error as its
+    /// `source`.
+    #[derive(Debug)]
+    pub struct ContextError {
+        context: String,
+        error: Box<dyn Error>,
+    }
+
+    impl fmt::Display for ContextError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}: {}", self.context, self.error)
+        }
+    }
+
+    impl Error for ContextError {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            Some(self.error.as_ref())
+        }
+    }
+
+    /// Combinators for moving results into `AnyResult`.
+    pub trait AnyResultExt<A> {
+        /// Chains a fallible step whose error type may differ from this
+        /// result's, converting both errors into a boxed error.
+        ///
+        /// # Example
+        /// ```
+        /// use crab_fp::{AnyResult, AnyResultExt};
+        ///
+        /// let port: AnyResult<u16> = Ok::<_, &str>("8080").try_map(|s| s.parse::<u16>());
+        /// assert_eq!(port.unwrap(), 8080);
+        /// ```
+        fn try_map<B, E2: Into<Box<dyn Error>>, F: FnOnce(A) -> Result<B, E2>>(
+            self,
+            f: F,
+        ) -> AnyResult<B>;
+
+        /// Annotates the error, if any, with a message describing what was
+        /// being done.
+        ///
+        /// # Example
+        /// ```
+        /// use crab_fp::AnyResultExt;
+        ///
+        /// let parsed = "x".parse::<i32>().with_context("reading the retry count");
+        /// assert_eq!(
+        ///     parsed.unwrap_err().to_string(),
+        ///     "reading the retry count: invalid digit found in string"
+        /// );
+        /// ```
+        fn with_context<C: fmt::Display>(self, context: C) -> AnyResult<A>;
+    }
+
+    impl<A, E: Into<Box<dyn Error>>> AnyResultExt<A> for Result<A, E> {
+        fn try_map<B, E2: Into<Box<dyn Error>>, F: FnOnce(A) -> Result<B, E2>>(
+            self,
+            f: F,
+        ) -> AnyResult<B> {
+            from_err(self).and_then(|a| from_err(f(a)))
+        }
+
+        fn with_context<C: fmt::Display>(self, context: C) -> AnyResult<A> {
+            self.map_err(|e| {
+                Box::new(ContextError {
+                    context: context.to_string(),
+                    error: e.into(),
+                }) as Box<dyn Error>
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(feature = "no_std"))]
+mod any_result_tests {
+    use super::any_result_impls::*;
+    use crate::*;
+    use std::fmt;
+
+    #[derive(Debug)]
+    struct OutOfRange(i64);
+
+    impl fmt::Display for OutOfRange {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{} is out of range", self.0)
+        }
+    }
+
+    impl std::error::Error for OutOfRange {}
+
+    fn in_range(x: i64) -> Result<i64, OutOfRange> {
+        if (0..=100).contains(&x) {
+            Ok(x)
+        } else {
+            Err(OutOfRange(x))
+        }
+    }
+
+    fn pipeline(input: &str) -> AnyResult<i64> {
+        Ok::<_, &str>(input)
+            .try_map(|s| {
+                if s.is_empty() {
+                    Err("empty input")
+                } else {
+                    Ok(s)
+                }
+            })
+            .try_map(|s| s.parse::<i64>())
+            .try_map(in_range)
+    }
+
+    #[test]
+    fn chains_different_error_types() {
+        assert_eq!(pipeline("42").unwrap(), 42);
+        assert_eq!(pipeline("").unwrap_err().to_string(), "empty input");
+        assert_eq!(
+            pipeline("x").unwrap_err().to_string(),
+            "invalid digit found in string"
+        );
+        assert_eq!(
+            pipeline("500").unwrap_err().to_string(),
+            "500 is out of range"
+        );
+    }
+
+    #[test]
+    fn error_keeps_concrete_type() {
+        let err = pipeline("500").unwrap_err();
+        assert!(err.downcast_ref::<OutOfRange>().is_some());
+    }
+
+    #[test]
+    fn from_err_converts() {
+        let r: AnyResult<i64> = from_err(in_range(-1));
+        assert_eq!(r.unwrap_err().to_string(), "-1 is out of range");
+
+        let r: AnyResult<i32> = from_err(Err("plain message"));
+        assert_eq!(r.unwrap_err().to_string(), "plain message");
+    }
+
+    #[test]
+    fn with_context_wraps_error() {
+        let r = pipeline("500").with_context("loading the volume");
+        let err = r.unwrap_err();
+        assert_eq!(err.to_string(), "loading the volume: 500 is out of range");
+        assert_eq!(err.source().unwrap().to_string(), "500 is out of range");
+    }
+
+    #[test]
+    fn with_context_leaves_ok_untouched() {
+        assert_eq!(pipeline("7").with_context("unused").unwrap(), 7);
+    }
+
+    #[test]
+    fn monad_instances_apply() {
+        let r: AnyResult<i64> = Ok(20);
+        let r = r.fmap(|x| x * 2).bind(|x| from_err(in_range(x)));
+        assert_eq!(r.unwrap(), 40);
+    }
+}
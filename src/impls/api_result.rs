@@ -0,0 +1,141 @@
+#[cfg(not(feature = "no_std"))]
+pub mod api_result_impls {
+    use std::error::Error;
+    use std::fmt;
+
+    /// An error with a numeric code and a human-readable message.
+    ///
+    /// The code is usually an HTTP status, but any `u16` is allowed;
+    /// `http_status` maps codes outside the HTTP range to `500`.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ApiError {
+        pub code: u16,
+        pub message: String,
+    }
+
+    impl ApiError {
+        /// Creates an error with the given code and message.
+        pub fn new(code: u16, message: impl Into<String>) -> Self {
+            ApiError {
+                code,
+                message: message.into(),
+            }
+        }
+
+        /// Returns the code as an HTTP status, or `500` if it is not one.
+        pub fn http_status(&self) -> u16 {
+            if (100..=599).contains(&self.code) {
+                self.code
+            } else {
+                500
+            }
+        }
+    }
+
+    impl fmt::Display for ApiError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{} {}", self.code, self.message)
+        }
+    }
+
+    impl Error for ApiError {}
+
+    /// A `Result` whose error is an `ApiError`.
+    ///
+    /// Since `ApiResult` is an ordinary `Result`, it already has the `Functor`,
+    /// `Applicative` and `Monad` instances, so a chain of steps stops at the
+    /// first failure and keeps its code. `ApiResultExt::into_http_status`
+    /// turns the outcome into a response status.
+    pub type ApiResult<A> = Result<A, ApiError>;
+
+    /// Combinators for turning an `ApiResult` into a response.
+    pub trait ApiResultExt<A> {
+        /// Maps the result to an HTTP status: `200` on success, otherwise the
+        /// error's `http_status`.
+        ///
+        /// # Example
+        /// ```
+        /// use crab_fp::*;
+        ///
+        /// let found: ApiResult<&str> = Ok("alice");
+        /// let missing = found.clone().bind(|_| Err::<&str, _>(ApiError::new(404, "no such user")));
+        /// assert_eq!(found.into_http_status(), 200);
+        /// assert_eq!(missing.into_http_status(), 404);
+        /// ```
+        fn into_http_status(self) -> u16;
+    }
+
+    impl<A> ApiResultExt<A> for ApiResult<A> {
+        fn into_http_status(self) -> u16 {
+            match self {
+                Ok(_) => 200,
+                Err(e) => e.http_status(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(feature = "no_std"))]
+mod api_result_tests {
+    use super::api_result_impls::*;
+    use crate::*;
+
+    fn authenticate(token: &str) -> ApiResult<u32> {
+        match token {
+            "" => Err(ApiError::new(401, "missing token")),
+            "admin" => Ok(1),
+            _ => Ok(42),
+        }
+    }
+
+    fn load_user(id: u32) -> ApiResult<String> {
+        if id == 1 {
+            Ok("root".to_string())
+        } else {
+            Err(ApiError::new(404, format!("user {id} not found")))
+        }
+    }
+
+    fn authorize(name: String) -> ApiResult<String> {
+        if name == "root" {
+            Ok(name)
+        } else {
+            Err(ApiError::new(403, "forbidden"))
+        }
+    }
+
+    fn handle(token: &str) -> ApiResult<usize> {
+        authenticate(token)
+            .bind(load_user)
+            .bind(authorize)
+            .fmap(|name| name.len())
+    }
+
+    #[test]
+    fn chain_succeeds() {
+        assert_eq!(handle("admin"), Ok(4));
+        assert_eq!(handle("admin").into_http_status(), 200);
+    }
+
+    #[test]
+    fn first_error_code_survives_chain() {
+        assert_eq!(handle(""), Err(ApiError::new(401, "missing token")));
+        assert_eq!(handle("").into_http_status(), 401);
+
+        assert_eq!(handle("guest").unwrap_err().message, "user 42 not found");
+        assert_eq!(handle("guest").into_http_status(), 404);
+    }
+
+    #[test]
+    fn http_status_out_of_range() {
+        assert_eq!(ApiError::new(418, "teapot").http_status(), 418);
+        assert_eq!(ApiError::new(42, "custom").http_status(), 500);
+        assert_eq!(ApiError::new(1001, "custom").http_status(), 500);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(ApiError::new(404, "not found").to_string(), "404 not found");
+    }
+}
@@ -0,0 +1,105 @@
+#[cfg(not(feature = "no_std"))]
+pub mod array_impls {
+    use crate::*;
+
+    pub struct ArrayKind<const N: usize>;
+
+    impl<const N: usize> Generic1 for ArrayKind<N> {
+        type Rep1<A> = [A; N];
+    }
+
+    impl<A, const N: usize> Kinded1<A> for [A; N] {
+        type Kind1 = ArrayKind<N>;
+    }
+
+    impl<A, const N: usize> Functor<A> for [A; N] {
+        fn fmap<B, F: FnMut(A) -> B>(self, f: F) -> [B; N] {
+            self.map(f)
+        }
+    }
+
+    impl<A: Clone, const N: usize> Applicative<A> for [A; N] {
+        /// Fills every position of the array with a clone of `b`.
+        fn pure(b: A) -> [A; N] {
+            std::array::from_fn(|_| b.clone())
+        }
+
+        /// Applies the function at each position to the value at the same
+        /// position, rather than forming every combination like `Vec` does.
+        fn apply<B, F: FnMut(A) -> B>(self, ff: [F; N]) -> [B; N] {
+            let mut fs = ff.into_iter();
+            self.map(|a| {
+                let mut f = fs.next().expect("arrays of the same length");
+                f(a)
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(feature = "no_std"))]
+mod array_tests {
+    mod functor {
+        use crate::*;
+
+        #[test]
+        fn fmap() {
+            let a = [1, 2, 3];
+            let mapped = a.fmap(multiply_by_two);
+            assert_eq!(mapped, [2, 4, 6]);
+
+            let a: [i32; 0] = [];
+            let mapped = a.fmap(multiply_by_two);
+            assert_eq!(mapped, []);
+        }
+
+        #[test]
+        fn identity_law() {
+            let a = [1, 2, 3];
+            assert_eq!(a.fmap(identity), a);
+        }
+
+        #[test]
+        fn composition_law() {
+            let a = [1, 2, 3];
+            let lhs = a.fmap(add_one).fmap(to_string);
+            let rhs = a.fmap(|x| to_string(add_one(x)));
+            assert_eq!(lhs, rhs);
+        }
+    }
+
+    mod applicative {
+        use crate::*;
+
+        #[test]
+        fn pure() {
+            let a: [i32; 3] = <[i32; 3]>::pure(69);
+            assert_eq!(a, [69, 69, 69]);
+        }
+
+        #[test]
+        fn ap_is_positional() {
+            let a = [1, 2, 3];
+            let fs = [add_one, multiply_by_two, square];
+            assert_eq!(a.apply(fs), [2, 4, 9]);
+        }
+
+        #[test]
+        fn identity_law() {
+            // Identity: pure id <*> v = v
+            let v = [1, 2, 3];
+            let p = <[fn(i32) -> i32; 3]>::pure(identity);
+            assert_eq!(v.apply(p), v);
+        }
+
+        #[test]
+        fn homomorphism_law() {
+            // Homomorphism: pure f <*> pure x = pure (f x)
+            let x = 69;
+            let p = <[fn(i32) -> String; 2]>::pure(to_string);
+            let lhs = <[i32; 2]>::pure(x).apply(p);
+            let rhs = <[String; 2]>::pure(to_string(x));
+            assert_eq!(lhs, rhs);
+        }
+    }
+}
@@ -0,0 +1,202 @@
+pub mod assoc_list_impls {
+    use crate::inline_vec::inline_vec_impls::InlineVec;
+    use crate::*;
+    use std::fmt;
+    use std::marker::PhantomData;
+
+    /// A map stored as a list of key-value pairs, with room for `N` entries.
+    ///
+    /// `AssocList` needs neither hashing nor allocation: lookups compare keys
+    /// with `PartialEq` in insertion order, and the pairs live in an
+    /// [`InlineVec`]. It is a map-like functor for `no_std` targets where
+    /// `HashMap` is unavailable, and suits the small maps typical there.
+    ///
+    /// Keys are unique. Inserting a key that is already present replaces its
+    /// value in place, so the key keeps its original position.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::*;
+    /// use crab_fp::assoc_list::assoc_list_impls::AssocList;
+    ///
+    /// let mut pins = AssocList::<&str, u8, 4>::new();
+    /// pins.insert("led", 13).unwrap();
+    /// pins.insert("button", 2).unwrap();
+    /// let masks = pins.fmap(|pin| 1u32 << pin);
+    /// assert_eq!(masks.get(&"button"), Some(&4));
+    /// ```
+    pub struct AssocList<K, V, const N: usize> {
+        entries: InlineVec<(K, V), N>,
+    }
+
+    impl<K, V, const N: usize> AssocList<K, V, N> {
+        /// Creates an empty list.
+        pub fn new() -> Self {
+            AssocList {
+                entries: InlineVec::new(),
+            }
+        }
+
+        /// Returns the number of entries.
+        pub fn len(&self) -> usize {
+            self.entries.len()
+        }
+
+        /// Returns `true` if the list has no entries.
+        pub fn is_empty(&self) -> bool {
+            self.entries.is_empty()
+        }
+
+        /// Returns the entries in insertion order.
+        pub fn entries(&self) -> &[(K, V)] {
+            self.entries.as_slice()
+        }
+    }
+
+    impl<K: PartialEq, V, const N: usize> AssocList<K, V, N> {
+        /// Returns the value stored under `key`, if any.
+        pub fn get(&self, key: &K) -> Option<&V> {
+            self.entries
+                .as_slice()
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v)
+        }
+
+        /// Inserts a value under `key`.
+        ///
+        /// If the key is already present its value is replaced and the old
+        /// value returned, leaving the key where it was. Otherwise the pair is
+        /// appended, or handed back as an error if the list is full.
+        pub fn insert(&mut self, key: K, value: V) -> Result<Option<V>, (K, V)> {
+            match self
+                .entries
+                .as_mut_slice()
+                .iter_mut()
+                .find(|(k, _)| *k == key)
+            {
+                Some((_, v)) => Ok(Some(std::mem::replace(v, value))),
+                None => self.entries.push((key, value)).map(|()| None),
+            }
+        }
+    }
+
+    impl<K, V, const N: usize> Default for AssocList<K, V, N> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl<K: Clone, V: Clone, const N: usize> Clone for AssocList<K, V, N> {
+        fn clone(&self) -> Self {
+            AssocList {
+                entries: self.entries.clone(),
+            }
+        }
+    }
+
+    impl<K: PartialEq, V: PartialEq, const N: usize> PartialEq for AssocList<K, V, N> {
+        fn eq(&self, other: &Self) -> bool {
+            self.entries == other.entries
+        }
+    }
+
+    impl<K: fmt::Debug, V: fmt::Debug, const N: usize> fmt::Debug for AssocList<K, V, N> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_map()
+                .entries(self.entries.as_slice().iter().map(|(k, v)| (k, v)))
+                .finish()
+        }
+    }
+
+    pub struct AssocListKind<K, const N: usize>(PhantomData<K>);
+
+    impl<K, const N: usize> Generic1 for AssocListKind<K, N> {
+        type Rep1<A> = AssocList<K, A, N>;
+    }
+
+    impl<K, A, const N: usize> Kinded1<A> for AssocList<K, A, N> {
+        type Kind1 = AssocListKind<K, N>;
+    }
+
+    /// Maps the values, leaving the keys and their order unchanged.
+    impl<K, A, const N: usize> Functor<A> for AssocList<K, A, N> {
+        fn fmap<B, F: FnMut(A) -> B>(self, mut f: F) -> AssocList<K, B, N> {
+            AssocList {
+                entries: self.entries.fmap(|(k, a)| (k, f(a))),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod assoc_list_tests {
+    use super::assoc_list_impls::*;
+    use crate::*;
+
+    fn from_pairs<const N: usize>(pairs: &[(char, i32)]) -> AssocList<char, i32, N> {
+        let mut list = AssocList::new();
+        for &(k, v) in pairs {
+            list.insert(k, v).unwrap();
+        }
+        list
+    }
+
+    #[test]
+    fn insert_and_get() {
+        let list = from_pairs::<4>(&[('a', 1), ('b', 2)]);
+        assert_eq!(list.get(&'a'), Some(&1));
+        assert_eq!(list.get(&'b'), Some(&2));
+        assert_eq!(list.get(&'c'), None);
+        assert_eq!(list.len(), 2);
+    }
+
+    #[test]
+    fn duplicate_key_replaces_in_place() {
+        let mut list = from_pairs::<4>(&[('a', 1), ('b', 2), ('c', 3)]);
+        assert_eq!(list.insert('a', 10), Ok(Some(1)));
+        assert_eq!(list.entries(), &[('a', 10), ('b', 2), ('c', 3)]);
+        assert_eq!(list.len(), 3);
+    }
+
+    #[test]
+    fn insert_when_full() {
+        let mut list = from_pairs::<2>(&[('a', 1), ('b', 2)]);
+        assert_eq!(list.insert('c', 3), Err(('c', 3)));
+        assert_eq!(list.insert('b', 20), Ok(Some(2)));
+        assert_eq!(list.entries(), &[('a', 1), ('b', 20)]);
+    }
+
+    mod functor {
+        use super::*;
+
+        #[test]
+        fn fmap_maps_values() {
+            let list = from_pairs::<4>(&[('x', 2), ('y', 3)]);
+            let squared = list.fmap(square);
+            assert_eq!(squared.get(&'x'), Some(&4));
+            assert_eq!(squared.get(&'y'), Some(&9));
+        }
+
+        #[test]
+        fn fmap_preserves_key_order() {
+            let list = from_pairs::<4>(&[('z', 1), ('a', 2), ('m', 3)]);
+            let doubled = list.fmap(multiply_by_two);
+            assert_eq!(doubled.entries(), &[('z', 2), ('a', 4), ('m', 6)]);
+        }
+
+        #[test]
+        fn identity_law() {
+            let list = from_pairs::<4>(&[('a', 1), ('b', 2)]);
+            assert_eq!(list.clone().fmap(identity), list);
+        }
+
+        #[test]
+        fn composition_law() {
+            let list = from_pairs::<4>(&[('a', 1), ('b', 2)]);
+            let lhs = list.clone().fmap(add_one).fmap(multiply_by_two);
+            let rhs = list.fmap(|x| multiply_by_two(add_one(x)));
+            assert_eq!(lhs, rhs);
+        }
+    }
+}
@@ -0,0 +1,157 @@
+#[cfg(not(feature = "no_std"))]
+pub mod binary_heap_impls {
+    use crate::*;
+    use std::collections::BinaryHeap;
+
+    /// The kind of `BinaryHeap`, so a heap can be named as
+    /// `Apply1<BinaryHeapKind, A>` in kind-generic code.
+    pub struct BinaryHeapKind;
+
+    impl Generic1 for BinaryHeapKind {
+        type Rep1<A> = BinaryHeap<A>;
+    }
+
+    impl<A> Kinded1<A> for BinaryHeap<A> {
+        type Kind1 = BinaryHeapKind;
+    }
+
+    /// Mapping support for `BinaryHeap`.
+    ///
+    /// `BinaryHeap` cannot be a `Functor`: building the mapped heap needs the
+    /// new element type to be `Ord`, and `Functor::fmap` places no bounds on
+    /// it. This extension trait provides `fmap` with that bound instead.
+    pub trait BinaryHeapExt<A> {
+        /// Maps a function over every element, rebuilding the heap.
+        ///
+        /// The function may change the relative order of elements, so the
+        /// result is re-heapified rather than mapped in place.
+        ///
+        /// # Example
+        /// ```
+        /// use std::collections::BinaryHeap;
+        /// use crab_fp::BinaryHeapExt;
+        ///
+        /// let heap = BinaryHeap::from([1, 5, 3]);
+        /// let negated = heap.fmap(|x: i32| -x);
+        /// assert_eq!(negated.peek(), Some(&-1));
+        /// ```
+        fn fmap<B: Ord, F: FnMut(A) -> B>(self, f: F) -> BinaryHeap<B>;
+    }
+
+    impl<A> BinaryHeapExt<A> for BinaryHeap<A> {
+        fn fmap<B: Ord, F: FnMut(A) -> B>(self, f: F) -> BinaryHeap<B> {
+            self.into_iter().map(f).collect()
+        }
+    }
+
+    /// Folds visit the elements in ascending order, so the result does not
+    /// depend on the heap's internal layout.
+    impl<A: Ord> Foldable<A> for BinaryHeap<A> {
+        fn fold_left<B, F: FnMut(B, A) -> B>(self, init: B, f: F) -> B {
+            self.into_sorted_vec().into_iter().fold(init, f)
+        }
+
+        fn fold_right<B, F: FnMut(A, B) -> B>(self, init: B, mut f: F) -> B {
+            self.into_sorted_vec()
+                .into_iter()
+                .rev()
+                .fold(init, |acc, a| f(a, acc))
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(feature = "no_std"))]
+mod binary_heap_tests {
+    use crate::*;
+    use std::cmp::Reverse;
+    use std::collections::BinaryHeap;
+
+    fn drain_sorted<A: Ord>(heap: BinaryHeap<A>) -> Vec<A> {
+        let mut heap = heap;
+        let mut out = vec![];
+        while let Some(a) = heap.pop() {
+            out.push(a);
+        }
+        out
+    }
+
+    mod functor {
+        use super::*;
+
+        #[test]
+        fn fmap() {
+            let heap = BinaryHeap::from([3, 1, 4, 1, 5]);
+            assert_eq!(
+                drain_sorted(heap.fmap(multiply_by_two)),
+                vec![10, 8, 6, 2, 2]
+            );
+        }
+
+        #[test]
+        fn heap_property_holds_after_reordering_map() {
+            let heap = BinaryHeap::from([3, 1, 4, 1, 5, 9, 2, 6]);
+            let mapped = heap.fmap(|x: i32| (x - 4).abs());
+            assert_eq!(mapped.peek(), Some(&5));
+            assert_eq!(drain_sorted(mapped), vec![5, 3, 3, 2, 2, 1, 1, 0]);
+        }
+
+        #[test]
+        fn fmap_into_min_heap() {
+            let heap = BinaryHeap::from([2, 7, 4]);
+            let min_heap = heap.fmap(Reverse);
+            assert_eq!(min_heap.peek(), Some(&Reverse(2)));
+        }
+
+        #[test]
+        fn identity_law() {
+            // Identity: fmap id = id
+            let heap = BinaryHeap::from([2, 7, 4]);
+            assert_eq!(
+                drain_sorted(heap.clone().fmap(identity)),
+                drain_sorted(heap)
+            );
+        }
+
+        #[test]
+        fn composition_law() {
+            // Composition: fmap (g . f) = fmap g . fmap f
+            let heap = BinaryHeap::from([2, 7, 4]);
+            let lhs = heap.clone().fmap(|x| square(add_one(x)));
+            let rhs = heap.fmap(add_one).fmap(square);
+            assert_eq!(drain_sorted(lhs), drain_sorted(rhs));
+        }
+    }
+
+    mod foldable {
+        use super::*;
+
+        #[test]
+        fn sum() {
+            let heap = BinaryHeap::from([3, 1, 4, 1, 5]);
+            assert_eq!(heap.fold_left(0, |acc, x| acc + x), 14);
+        }
+
+        #[test]
+        fn fold_order_is_ascending() {
+            let heap = BinaryHeap::from([3, 1, 2]);
+            let left = heap.clone().fold_left(vec![], |mut acc, x| {
+                acc.push(x);
+                acc
+            });
+            assert_eq!(left, vec![1, 2, 3]);
+
+            let right = heap.fold_right(vec![], |x, mut acc| {
+                acc.push(x);
+                acc
+            });
+            assert_eq!(right, vec![3, 2, 1]);
+        }
+
+        #[test]
+        fn empty() {
+            let heap: BinaryHeap<i32> = BinaryHeap::new();
+            assert_eq!(heap.fold_left(0, |acc, x| acc + x), 0);
+        }
+    }
+}
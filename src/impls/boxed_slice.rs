@@ -0,0 +1,141 @@
+#[cfg(not(feature = "no_std"))]
+pub mod boxed_slice_impls {
+    use crate::*;
+
+    pub struct BoxSliceKind;
+
+    impl Generic1 for BoxSliceKind {
+        type Rep1<A> = Box<[A]>;
+    }
+
+    impl<A> Kinded1<A> for Box<[A]> {
+        type Kind1 = BoxSliceKind;
+    }
+
+    /// Calls `f` once per element, from first to last.
+    impl<A> Functor<A> for Box<[A]> {
+        fn fmap<B, F: FnMut(A) -> B>(self, f: F) -> Box<[B]> {
+            self.into_iter().map(f).collect()
+        }
+    }
+
+    /// A boxed slice cannot grow, so the results are collected into a `Vec`
+    /// of the exact length and converted without reallocating.
+    impl<A: Clone> Applicative<A> for Box<[A]> {
+        fn pure(b: A) -> Box<[A]> {
+            Box::new([b])
+        }
+
+        fn apply<B, F: FnMut(A) -> B>(self, ff: Box<[F]>) -> Box<[B]> {
+            let mut result = Vec::with_capacity(self.len() * ff.len());
+            for mut f in ff {
+                result.extend(self.iter().cloned().map(&mut f));
+            }
+            result.into_boxed_slice()
+        }
+    }
+
+    impl<A: Clone> Monad<A> for Box<[A]> {
+        fn bind<B, F: FnMut(A) -> Box<[B]>>(self, f: F) -> Box<[B]> {
+            self.into_iter().flat_map(f).collect()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(feature = "no_std"))]
+mod boxed_slice_tests {
+    use crate::*;
+
+    fn boxed<const N: usize>(items: [i32; N]) -> Box<[i32]> {
+        Box::new(items)
+    }
+
+    mod functor {
+        use super::*;
+
+        #[test]
+        fn fmap() {
+            let mapped: Box<[String]> = boxed([1, 2, 3]).fmap(to_string);
+            assert_eq!(&*mapped, ["1", "2", "3"]);
+        }
+
+        #[test]
+        fn from_into_boxed_slice() {
+            let b = vec![1, 2, 3].into_boxed_slice();
+            assert_eq!(b.fmap(multiply_by_two), boxed([2, 4, 6]));
+        }
+
+        #[test]
+        fn identity_law() {
+            assert_eq!(boxed([1, 2, 3]).fmap(identity), boxed([1, 2, 3]));
+        }
+
+        #[test]
+        fn composition_law() {
+            let lhs = boxed([1, 2, 3]).fmap(add_one).fmap(multiply_by_two);
+            let rhs = boxed([1, 2, 3]).fmap(|x| multiply_by_two(add_one(x)));
+            assert_eq!(lhs, rhs);
+        }
+    }
+
+    mod applicative {
+        use super::*;
+
+        #[test]
+        fn apply_matches_vec() {
+            let fs: [fn(i32) -> i32; 2] = [add_one, square];
+            let b = boxed([2, 3]).apply(Box::new(fs));
+            assert_eq!(b.into_vec(), vec![2, 3].apply(fs.to_vec()));
+        }
+
+        #[test]
+        fn identity_law() {
+            let v = boxed([1, 2]);
+            assert_eq!(v.clone().apply(Box::pure(identity)), v);
+        }
+
+        #[test]
+        fn homomorphism_law() {
+            let lhs = <Box<[i32]>>::pure(5).apply(Box::pure(add_one));
+            assert_eq!(lhs, Box::pure(add_one(5)));
+        }
+    }
+
+    mod monad {
+        use super::*;
+
+        #[test]
+        fn bind() {
+            let b: Box<[i32]> = boxed([1, 2, 3]).bind(|x| vec![x; x as usize].into_boxed_slice());
+            assert_eq!(b, boxed([1, 2, 2, 3, 3, 3]));
+        }
+
+        #[test]
+        fn bind_empty() {
+            let b = boxed([]).bind(|x| boxed([x, x]));
+            assert!(b.is_empty());
+        }
+
+        #[test]
+        fn left_identity_law() {
+            let f = |x: i32| boxed([x, x + 1]);
+            assert_eq!(<Box<[i32]>>::pure(3).bind(f), f(3));
+        }
+
+        #[test]
+        fn right_identity_law() {
+            let m = boxed([1, 2, 3]);
+            assert_eq!(m.clone().bind(Box::pure), m);
+        }
+
+        #[test]
+        fn associativity_law() {
+            let f = |x: i32| boxed([x, x * 10]);
+            let g = |x: i32| boxed([x + 1]);
+            let lhs = boxed([1, 2]).bind(f).bind(g);
+            let rhs = boxed([1, 2]).bind(|x| f(x).bind(g));
+            assert_eq!(lhs, rhs);
+        }
+    }
+}
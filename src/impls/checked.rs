@@ -0,0 +1,166 @@
+pub mod checked_impls {
+    use std::num::{
+        NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize, NonZeroU8,
+        NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    };
+
+    /// The `NonZero*` integer types, viewed through their underlying primitive.
+    pub trait NonZeroInt: Copy {
+        /// The primitive integer type this non-zero type wraps.
+        type Prim;
+
+        /// Creates a non-zero value, or `None` if `n` is zero.
+        fn from_prim(n: Self::Prim) -> Option<Self>;
+
+        /// Returns the underlying primitive value.
+        fn into_prim(self) -> Self::Prim;
+    }
+
+    macro_rules! impl_non_zero_int {
+        ($($nz:ty => $t:ty),*) => {$(
+            impl NonZeroInt for $nz {
+                type Prim = $t;
+
+                fn from_prim(n: $t) -> Option<Self> {
+                    <$nz>::new(n)
+                }
+
+                fn into_prim(self) -> $t {
+                    self.get()
+                }
+            }
+        )*};
+    }
+
+    impl_non_zero_int!(
+        NonZeroI8 => i8, NonZeroI16 => i16, NonZeroI32 => i32, NonZeroI64 => i64,
+        NonZeroI128 => i128, NonZeroIsize => isize, NonZeroU8 => u8, NonZeroU16 => u16,
+        NonZeroU32 => u32, NonZeroU64 => u64, NonZeroU128 => u128, NonZeroUsize => usize
+    );
+
+    /// A non-zero integer whose transformations keep it non-zero.
+    ///
+    /// `fmap` maps over the underlying primitive and re-validates the result,
+    /// returning `None` when the function produces zero. The target type may
+    /// differ from the source, so a `NonZeroU32` can be mapped to a
+    /// `NonZeroU64`.
+    ///
+    /// `Checked` provides `fmap` and `bind` as inherent methods rather than
+    /// `Functor`/`Monad` instances: mapping can fail, so the result is an
+    /// `Option` rather than another `Checked`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::num::NonZeroU32;
+    /// use crab_fp::checked::checked_impls::Checked;
+    ///
+    /// let n = Checked::new(NonZeroU32::new(3).unwrap());
+    /// assert_eq!(n.fmap::<NonZeroU32, _>(|x| x * 2).map(Checked::value), Some(6));
+    /// assert_eq!(n.fmap::<NonZeroU32, _>(|x| x - 3), None);
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Checked<T>(T);
+
+    impl<T: NonZeroInt> Checked<T> {
+        /// Wraps a non-zero value.
+        pub fn new(value: T) -> Self {
+            Checked(value)
+        }
+
+        /// Creates a checked value from a primitive, or `None` if it is zero.
+        pub fn from_prim(n: T::Prim) -> Option<Self> {
+            T::from_prim(n).map(Checked)
+        }
+
+        /// Maps the underlying primitive, returning `None` if the result is
+        /// zero.
+        pub fn fmap<U: NonZeroInt, F: FnOnce(T::Prim) -> U::Prim>(
+            self,
+            f: F,
+        ) -> Option<Checked<U>> {
+            Checked::from_prim(f(self.0.into_prim()))
+        }
+
+        /// Chains a computation that may itself fail to produce a non-zero
+        /// value.
+        pub fn bind<U: NonZeroInt, F: FnOnce(T::Prim) -> Option<Checked<U>>>(
+            self,
+            f: F,
+        ) -> Option<Checked<U>> {
+            f(self.0.into_prim())
+        }
+
+        /// Returns the wrapped non-zero value.
+        pub fn get(self) -> T {
+            self.0
+        }
+
+        /// Returns the underlying primitive value.
+        pub fn value(self) -> T::Prim {
+            self.0.into_prim()
+        }
+    }
+}
+
+#[cfg(test)]
+mod checked_tests {
+    use super::checked_impls::*;
+    use std::num::{NonZeroI8, NonZeroU32, NonZeroU64};
+
+    fn three() -> Checked<NonZeroU32> {
+        Checked::new(NonZeroU32::new(3).unwrap())
+    }
+
+    #[test]
+    fn from_prim() {
+        assert_eq!(
+            Checked::<NonZeroU32>::from_prim(5).map(Checked::value),
+            Some(5)
+        );
+        assert_eq!(Checked::<NonZeroU32>::from_prim(0), None);
+    }
+
+    #[test]
+    fn fmap_non_zero_result() {
+        let mapped = three().fmap::<NonZeroU32, _>(|x| x * 7);
+        assert_eq!(mapped.map(Checked::get), NonZeroU32::new(21));
+    }
+
+    #[test]
+    fn fmap_zero_result() {
+        assert_eq!(three().fmap::<NonZeroU32, _>(|x| x - 3), None);
+    }
+
+    #[test]
+    fn fmap_changes_type() {
+        let wide = three().fmap::<NonZeroU64, _>(|x| x as u64 * 1_000_000_000_000);
+        assert_eq!(wide.map(Checked::value), Some(3_000_000_000_000));
+
+        let signed = three().fmap::<NonZeroI8, _>(|x| -(x as i8));
+        assert_eq!(signed.map(Checked::value), Some(-3));
+    }
+
+    #[test]
+    fn bind() {
+        let halve = |x: u32| {
+            if x.is_multiple_of(2) {
+                Checked::<NonZeroU32>::from_prim(x / 2)
+            } else {
+                None
+            }
+        };
+        assert_eq!(
+            Checked::<NonZeroU32>::from_prim(8)
+                .and_then(|c| c.bind(halve))
+                .map(Checked::value),
+            Some(4)
+        );
+        assert_eq!(three().bind(halve), None);
+    }
+
+    // Identity: fmap id = Some
+    #[test]
+    fn identity_law() {
+        assert_eq!(three().fmap::<NonZeroU32, _>(|x| x), Some(three()));
+    }
+}
@@ -0,0 +1,159 @@
+pub mod clamped_impls {
+    /// Numeric types that can be clamped into an `i64`-bounded range.
+    pub trait Clampable: Sized {
+        /// Clamps the value into `lo..=hi`.
+        ///
+        /// A bound outside the type's own range saturates to the type's
+        /// nearest limit.
+        fn clamp_to(self, lo: i64, hi: i64) -> Self;
+    }
+
+    macro_rules! impl_clampable_int {
+        ($($t:ty),*) => {$(
+            impl Clampable for $t {
+                fn clamp_to(self, lo: i64, hi: i64) -> Self {
+                    // Widen so bounds outside the type's own range compare correctly,
+                    // then saturate so such a bound becomes the type's own limit
+                    // instead of wrapping.
+                    (self as i128)
+                        .clamp(lo as i128, hi as i128)
+                        .clamp(<$t>::MIN as i128, <$t>::MAX as i128) as $t
+                }
+            }
+        )*};
+    }
+
+    impl_clampable_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+    macro_rules! impl_clampable_float {
+        ($($t:ty),*) => {$(
+            impl Clampable for $t {
+                fn clamp_to(self, lo: i64, hi: i64) -> Self {
+                    // NaN is outside every range, so it is pinned to the lower bound.
+                    if self.is_nan() {
+                        lo as $t
+                    } else {
+                        self.clamp(lo as $t, hi as $t)
+                    }
+                }
+            }
+        )*};
+    }
+
+    impl_clampable_float!(f32, f64);
+
+    /// A value kept within the inclusive range `LO..=HI`.
+    ///
+    /// Every way of producing a `Clamped` value clamps it into the range,
+    /// including `fmap` and `bind`, so a mapped value that overshoots a bound
+    /// is pulled back to that bound. A floating-point NaN becomes `LO`.
+    ///
+    /// `Clamped` provides `fmap`, `pure` and `bind` as inherent methods rather
+    /// than `Functor`/`Monad` instances: those traits place no bounds on the
+    /// type produced by the mapping function, so they cannot express the clamp.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::clamped::clamped_impls::Clamped;
+    ///
+    /// let volume = Clamped::<i32, 0, 10>::new(8);
+    /// assert_eq!(volume.fmap(|v| v + 5).get(), 10);
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+    pub struct Clamped<T, const LO: i64, const HI: i64>(T);
+
+    impl<T: Clampable, const LO: i64, const HI: i64> Clamped<T, LO, HI> {
+        /// Creates a clamped value, moving `value` into the range if needed.
+        pub fn new(value: T) -> Self {
+            const { assert!(LO <= HI, "Clamped range must satisfy LO <= HI") };
+            Clamped(value.clamp_to(LO, HI))
+        }
+
+        /// Lifts a value into the range. Equivalent to `Clamped::new`.
+        pub fn pure(value: T) -> Self {
+            Self::new(value)
+        }
+
+        /// Maps the contained value, clamping the result into the same range.
+        pub fn fmap<B: Clampable, F: FnOnce(T) -> B>(self, f: F) -> Clamped<B, LO, HI> {
+            Clamped::new(f(self.0))
+        }
+
+        /// Chains a computation producing another value in the same range.
+        pub fn bind<B: Clampable, F: FnOnce(T) -> Clamped<B, LO, HI>>(
+            self,
+            f: F,
+        ) -> Clamped<B, LO, HI> {
+            f(self.0)
+        }
+
+        /// Returns the contained value.
+        pub fn get(self) -> T {
+            self.0
+        }
+    }
+}
+
+#[cfg(test)]
+mod clamped_tests {
+    use super::clamped_impls::*;
+
+    type Percent = Clamped<i32, 0, 100>;
+
+    #[test]
+    fn new_clamps() {
+        assert_eq!(Percent::new(50).get(), 50);
+        assert_eq!(Percent::new(150).get(), 100);
+        assert_eq!(Percent::new(-5).get(), 0);
+    }
+
+    #[test]
+    fn fmap_in_range() {
+        assert_eq!(Percent::new(40).fmap(|x| x + 10).get(), 50);
+    }
+
+    #[test]
+    fn fmap_clamps() {
+        assert_eq!(Percent::new(90).fmap(|x| x * 2).get(), 100);
+        assert_eq!(Percent::new(10).fmap(|x| x - 50).get(), 0);
+    }
+
+    #[test]
+    fn fmap_changes_type() {
+        let half: Clamped<f64, 0, 100> = Percent::new(99).fmap(|x| x as f64 / 2.0);
+        assert_eq!(half.get(), 49.5);
+
+        let wide: Clamped<u16, 0, 100> = Percent::new(80).fmap(|x| x as u16 * 4);
+        assert_eq!(wide.get(), 100);
+    }
+
+    #[test]
+    fn range_outside_type_saturates() {
+        assert_eq!(Clamped::<u8, 300, 400>::new(5).get(), u8::MAX);
+        assert_eq!(Clamped::<i8, -300, -200>::new(5).get(), i8::MIN);
+        assert_eq!(Clamped::<u8, 200, 400>::new(5).get(), 200);
+        assert_eq!(Clamped::<u8, 200, 400>::new(250).get(), 250);
+    }
+
+    #[test]
+    fn nan_becomes_lower_bound() {
+        assert_eq!(Clamped::<f64, 0, 100>::new(f64::NAN).get(), 0.0);
+        assert_eq!(Percent::new(50).fmap(|_| f32::NAN).get(), 0.0);
+    }
+
+    #[test]
+    fn unsigned_with_negative_bound() {
+        let c = Clamped::<u8, -10, 300>::new(255);
+        assert_eq!(c.get(), 255);
+        assert_eq!(c.fmap(|x| x - 5).get(), 250);
+    }
+
+    #[test]
+    fn bind() {
+        let c = Percent::new(30).bind(|x| Percent::new(x * 5));
+        assert_eq!(c.get(), 100);
+
+        let c = Percent::pure(30).bind(|x| Clamped::new(x as f32 / 3.0));
+        assert_eq!(c.get(), 10.0);
+    }
+}
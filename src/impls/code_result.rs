@@ -0,0 +1,217 @@
+pub mod code_result_impls {
+    use crate::*;
+
+    /// A result whose only possible error is the fixed code `CODE`.
+    ///
+    /// Embedded pipelines often report failure as a numeric code rather than
+    /// an error value. `CodeResult` carries that code in its type, so a failed
+    /// step stores nothing but its tag: no heap, no strings. It is a full
+    /// `Functor`/`Applicative`/`Monad`, and since every step bound in a chain
+    /// shares the same `CODE`, the code comes out of `bind` unchanged.
+    /// `recode` moves a result to another code when chaining across stages.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::*;
+    /// use crab_fp::code_result::code_result_impls::CodeResult;
+    ///
+    /// const E_OVERFLOW: u32 = 0x12;
+    ///
+    /// let double = |x: u8| CodeResult::<u8, E_OVERFLOW>::from_option(x.checked_mul(2));
+    /// assert_eq!(CodeResult::ok(100).bind(double).into_result(), Ok(200));
+    /// assert_eq!(CodeResult::ok(200).bind(double).into_result(), Err(E_OVERFLOW));
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct CodeResult<A, const CODE: u32>(Option<A>);
+
+    impl<A, const CODE: u32> CodeResult<A, CODE> {
+        /// A successful result.
+        pub const fn ok(a: A) -> Self {
+            CodeResult(Some(a))
+        }
+
+        /// A failed result, carrying `CODE`.
+        pub const fn err() -> Self {
+            CodeResult(None)
+        }
+
+        /// Succeeds with the value if present, failing with `CODE` otherwise.
+        pub fn from_option(a: Option<A>) -> Self {
+            CodeResult(a)
+        }
+
+        /// Returns `true` if the result is a success.
+        pub const fn is_ok(&self) -> bool {
+            self.0.is_some()
+        }
+
+        /// Returns the error code, or `None` on success.
+        pub const fn code(&self) -> Option<u32> {
+            match self.0 {
+                Some(_) => None,
+                None => Some(CODE),
+            }
+        }
+
+        /// Converts into a standard `Result` with the code as the error.
+        pub fn into_result(self) -> Result<A, u32> {
+            self.0.ok_or(CODE)
+        }
+
+        /// Moves the result to another error code, keeping a success as is.
+        pub fn recode<const OTHER: u32>(self) -> CodeResult<A, OTHER> {
+            CodeResult(self.0)
+        }
+    }
+
+    pub struct CodeResultKind<const CODE: u32>;
+
+    impl<const CODE: u32> Generic1 for CodeResultKind<CODE> {
+        type Rep1<A> = CodeResult<A, CODE>;
+    }
+
+    impl<A, const CODE: u32> Kinded1<A> for CodeResult<A, CODE> {
+        type Kind1 = CodeResultKind<CODE>;
+    }
+
+    impl<A, const CODE: u32> Functor<A> for CodeResult<A, CODE> {
+        fn fmap<B, F: FnOnce(A) -> B>(self, f: F) -> CodeResult<B, CODE> {
+            CodeResult(self.0.map(f))
+        }
+    }
+
+    impl<A, const CODE: u32> Applicative<A> for CodeResult<A, CODE> {
+        fn pure(a: A) -> CodeResult<A, CODE> {
+            CodeResult::ok(a)
+        }
+
+        fn apply<B, F: FnOnce(A) -> B>(self, ff: CodeResult<F, CODE>) -> CodeResult<B, CODE> {
+            match (self.0, ff.0) {
+                (Some(a), Some(f)) => CodeResult::ok(f(a)),
+                _ => CodeResult::err(),
+            }
+        }
+    }
+
+    impl<A, const CODE: u32> Monad<A> for CodeResult<A, CODE> {
+        fn bind<B, F: FnOnce(A) -> CodeResult<B, CODE>>(self, f: F) -> CodeResult<B, CODE> {
+            match self.0 {
+                Some(a) => f(a),
+                None => CodeResult::err(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod code_result_tests {
+    use super::code_result_impls::*;
+    use crate::*;
+
+    const E_PARSE: u32 = 1;
+    const E_RANGE: u32 = 2;
+
+    type Step<A> = CodeResult<A, E_RANGE>;
+
+    fn parse_digit(b: u8) -> CodeResult<u8, E_PARSE> {
+        CodeResult::from_option((b as char).to_digit(10).map(|d| d as u8))
+    }
+
+    fn halve(x: u8) -> Step<u8> {
+        if x.is_multiple_of(2) {
+            Step::ok(x / 2)
+        } else {
+            Step::err()
+        }
+    }
+
+    fn scale(x: u8) -> Step<u8> {
+        Step::from_option(x.checked_mul(100))
+    }
+
+    mod functor {
+        use super::*;
+
+        #[test]
+        fn fmap() {
+            assert_eq!(Step::ok(4).fmap(|x| x + 1), Step::ok(5));
+            assert_eq!(Step::<u8>::err().fmap(|x| x + 1), Step::err());
+        }
+
+        #[test]
+        fn identity_law() {
+            // Identity: fmap id = id
+            assert_eq!(Step::ok(4).fmap(identity), Step::ok(4));
+        }
+    }
+
+    mod applicative {
+        use super::*;
+
+        #[test]
+        fn apply() {
+            let f = Step::ok(fn_ptr!(add_one));
+            assert_eq!(Step::ok(1).apply(f), Step::ok(2));
+            assert_eq!(Step::err().apply(f), Step::err());
+            assert_eq!(
+                Step::ok(1).apply(Step::<fn(i32) -> i32>::err()),
+                Step::err()
+            );
+        }
+
+        #[test]
+        fn homomorphism_law() {
+            // Homomorphism: pure f <*> pure x = pure (f x)
+            let lhs = Step::pure(3).apply(Step::pure(square));
+            assert_eq!(lhs, Step::pure(square(3)));
+        }
+    }
+
+    mod monad {
+        use super::*;
+
+        #[test]
+        fn two_steps_compose() {
+            assert_eq!(Step::ok(4).bind(halve).bind(scale), Step::ok(200));
+        }
+
+        #[test]
+        fn code_propagates_through_bind() {
+            let failed = Step::ok(3).bind(halve).bind(scale);
+            assert_eq!(failed.code(), Some(E_RANGE));
+            assert_eq!(failed.into_result(), Err(E_RANGE));
+
+            let failed = Step::ok(4).bind(scale).bind(halve);
+            assert_eq!(failed.code(), Some(E_RANGE));
+        }
+
+        #[test]
+        fn recode_between_stages() {
+            let run = |b: u8| parse_digit(b).recode::<E_RANGE>().bind(halve);
+            assert_eq!(run(b'8').into_result(), Ok(4));
+            assert_eq!(run(b'x').into_result(), Err(E_RANGE));
+            assert_eq!(parse_digit(b'x').into_result(), Err(E_PARSE));
+        }
+
+        #[test]
+        fn left_identity_law() {
+            // Left identity: return a >>= f = f a
+            assert_eq!(Step::pure(4).bind(halve), halve(4));
+        }
+
+        #[test]
+        fn right_identity_law() {
+            // Right identity: m >>= return = m
+            assert_eq!(Step::ok(4).bind(Step::pure), Step::ok(4));
+            assert_eq!(Step::<u8>::err().bind(Step::pure), Step::err());
+        }
+
+        #[test]
+        fn associativity_law() {
+            // Associativity: (m >>= f) >>= g = m >>= (|x| f x >>= g)
+            for m in [Step::ok(4), Step::ok(3), Step::err()] {
+                assert_eq!(m.bind(halve).bind(scale), m.bind(|x| halve(x).bind(scale)));
+            }
+        }
+    }
+}
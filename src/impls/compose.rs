@@ -0,0 +1,114 @@
+pub mod compose_impls {
+    use crate::*;
+
+    /// The composition of two functors, `F(G(A))`.
+    ///
+    /// `ComposeF` wraps a value of the outer functor of kind `F` whose contents
+    /// are the inner functor of kind `G`, and is a functor over the innermost
+    /// `A`: a single `fmap` maps through both layers. This is the principled
+    /// form of `NestedFunctor::fmap_inner`, for code that is generic over a
+    /// `Functor`.
+    ///
+    /// # Type Parameters
+    /// * `F` - The kind of the outer functor
+    /// * `G` - The kind of the inner functor
+    /// * `A` - The type of the innermost values
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::*;
+    /// use crab_fp::compose::compose_impls::ComposeF;
+    /// use crab_fp::option::option_impls::OptionKind;
+    /// use crab_fp::result::result_impls::ResultKind;
+    ///
+    /// let c: ComposeF<OptionKind, ResultKind<&str>, i32> = ComposeF(Some(Ok(1)));
+    /// assert_eq!(c.fmap(|x| x + 1).0, Some(Ok(2)));
+    /// ```
+    pub struct ComposeF<F: Generic1, G: Generic1, A>(pub Apply1<F, Apply1<G, A>>);
+
+    impl<F: Generic1, G: Generic1, A> ComposeF<F, G, A> {
+        /// Unwraps the composition, returning the nested functors.
+        pub fn into_inner(self) -> Apply1<F, Apply1<G, A>> {
+            self.0
+        }
+    }
+
+    pub struct ComposeFKind<F, G>(std::marker::PhantomData<(F, G)>);
+
+    impl<F: Generic1, G: Generic1> Generic1 for ComposeFKind<F, G> {
+        type Rep1<A> = ComposeF<F, G, A>;
+    }
+
+    impl<F: Generic1, G: Generic1, A> Kinded1<A> for ComposeF<F, G, A> {
+        type Kind1 = ComposeFKind<F, G>;
+    }
+
+    impl<F: Generic1, G: Generic1, A> Functor<A> for ComposeF<F, G, A>
+    where
+        Apply1<F, Apply1<G, A>>: Functor<Apply1<G, A>, Kind1 = F>,
+        Apply1<G, A>: Functor<A, Kind1 = G>,
+    {
+        fn fmap<B, M: FnMut(A) -> B>(self, mut f: M) -> ComposeF<F, G, B> {
+            ComposeF(self.0.fmap(|ga| ga.fmap(&mut f)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod compose_tests {
+    use super::compose_impls::ComposeF;
+    use crate::option::option_impls::OptionKind;
+    use crate::*;
+
+    #[test]
+    fn option_of_option() {
+        let c: ComposeF<OptionKind, OptionKind, i32> = ComposeF(Some(Some(2)));
+        assert_eq!(c.fmap(square).into_inner(), Some(Some(4)));
+
+        let c: ComposeF<OptionKind, OptionKind, i32> = ComposeF(Some(None));
+        assert_eq!(c.fmap(square).into_inner(), Some(None));
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    mod vec_option {
+        use super::*;
+        use crate::vec::vec_impls::VecKind;
+
+        type VecOption<A> = ComposeF<VecKind, OptionKind, A>;
+
+        #[test]
+        fn fmap_hits_inner_value() {
+            let c: VecOption<i32> = ComposeF(vec![Some(1), None, Some(3)]);
+            assert_eq!(
+                c.fmap(to_string).into_inner(),
+                vec![Some("1".to_string()), None, Some("3".to_string())]
+            );
+        }
+
+        #[test]
+        fn option_vec() {
+            let c: ComposeF<OptionKind, VecKind, i32> = ComposeF(Some(vec![1, 2]));
+            assert_eq!(c.fmap(add_one).into_inner(), Some(vec![2, 3]));
+        }
+
+        // Identity: fmap id = id
+        #[test]
+        fn identity_law() {
+            let v = vec![Some(1), None, Some(3)];
+            let c: VecOption<i32> = ComposeF(v.clone());
+            assert_eq!(c.fmap(identity).into_inner(), v);
+        }
+
+        // Composition: fmap (f . g) = fmap f . fmap g
+        #[test]
+        fn composition_law() {
+            let v = vec![Some(1), None, Some(3)];
+            let lhs =
+                ComposeF::<VecKind, OptionKind, _>(v.clone()).fmap(|x| multiply_by_two(add_one(x)));
+            let rhs = ComposeF::<VecKind, OptionKind, _>(v)
+                .fmap(add_one)
+                .fmap(multiply_by_two);
+            assert_eq!(lhs.into_inner(), rhs.into_inner());
+        }
+    }
+}
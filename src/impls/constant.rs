@@ -0,0 +1,191 @@
+pub mod constant_impls {
+    use crate::*;
+    use std::fmt;
+    use std::marker::PhantomData;
+
+    /// A functor that ignores its values and carries a fixed `M` instead.
+    ///
+    /// `fmap` leaves the carried value alone, and when `M` is a `Monoid`,
+    /// `apply` combines the carried values (the function side's first) while
+    /// `pure` carries `M::empty()`. Running an applicative traversal in
+    /// `Const` therefore accumulates a summary of every element without
+    /// building a result container.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::*;
+    /// use crab_fp::constant::constant_impls::Const;
+    /// use crab_fp::monoid::monoid_impls::Max;
+    ///
+    /// let c: Const<Max<i32>, &str> = Const::new(Max(3));
+    /// let mapped = c.fmap(|s| s.len());
+    /// assert_eq!(mapped.get_const(), Max(3));
+    /// ```
+    pub struct Const<M, A> {
+        value: M,
+        phantom: PhantomData<A>,
+    }
+
+    impl<M, A> Const<M, A> {
+        /// Wraps the carried value.
+        pub fn new(value: M) -> Self {
+            Const {
+                value,
+                phantom: PhantomData,
+            }
+        }
+
+        /// Returns the carried value.
+        pub fn get_const(self) -> M {
+            self.value
+        }
+    }
+
+    // Manual impls, since derives would also require `A` to implement each
+    // trait.
+
+    impl<M: Clone, A> Clone for Const<M, A> {
+        fn clone(&self) -> Self {
+            Const::new(self.value.clone())
+        }
+    }
+
+    impl<M: PartialEq, A> PartialEq for Const<M, A> {
+        fn eq(&self, other: &Self) -> bool {
+            self.value == other.value
+        }
+    }
+
+    impl<M: fmt::Debug, A> fmt::Debug for Const<M, A> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_tuple("Const").field(&self.value).finish()
+        }
+    }
+
+    pub struct ConstKind<M>(PhantomData<M>);
+
+    impl<M> Generic1 for ConstKind<M> {
+        type Rep1<A> = Const<M, A>;
+    }
+
+    impl<M, A> Kinded1<A> for Const<M, A> {
+        type Kind1 = ConstKind<M>;
+    }
+
+    impl<M, A> Functor<A> for Const<M, A> {
+        fn fmap<B, F: FnMut(A) -> B>(self, _f: F) -> Const<M, B> {
+            Const::new(self.value)
+        }
+    }
+
+    impl<M: Monoid, A> Applicative<A> for Const<M, A> {
+        fn pure(_b: A) -> Const<M, A> {
+            Const::new(M::empty())
+        }
+
+        fn apply<B, F: FnMut(A) -> B>(self, ff: Const<M, F>) -> Const<M, B> {
+            Const::new(ff.value.combine(self.value))
+        }
+    }
+}
+
+#[cfg(test)]
+mod constant_tests {
+    use super::constant_impls::*;
+    use crate::monoid::monoid_impls::*;
+    use crate::*;
+
+    /// Traverses `items` in the `Const` applicative, which amounts to
+    /// combining `f` of every item, left to right.
+    fn traverse_const<M: Monoid, A>(
+        items: impl Iterator<Item = A>,
+        mut f: impl FnMut(A) -> M,
+    ) -> M {
+        items
+            .fold(Const::<M, ()>::pure(()), |acc, a| {
+                Const::new(f(a)).apply(acc.fmap(|()| |u: ()| u))
+            })
+            .get_const()
+    }
+
+    mod functor {
+        use super::*;
+
+        #[test]
+        fn fmap_keeps_value() {
+            let c: Const<i32, i32> = Const::new(7);
+            assert_eq!(c.fmap(add_one).get_const(), 7);
+        }
+
+        #[test]
+        fn fmap_never_calls_function() {
+            let c: Const<(), i32> = Const::new(());
+            c.fmap(|_: i32| -> i32 { panic!("Const has no values to map") });
+        }
+
+        #[test]
+        fn identity_law() {
+            let c: Const<i32, i32> = Const::new(7);
+            assert_eq!(c.clone().fmap(identity), c);
+        }
+    }
+
+    mod applicative {
+        use super::*;
+
+        #[test]
+        fn pure_is_empty() {
+            assert_eq!(Const::<All, i32>::pure(5).get_const(), All::empty());
+        }
+
+        #[test]
+        fn apply_combines_function_side_first() {
+            let value: Const<First<i32>, i32> = Const::new(First(Some(1)));
+            let function: Const<First<i32>, fn(i32) -> i32> = Const::new(First(Some(2)));
+            assert_eq!(value.apply(function).get_const(), First(Some(2)));
+        }
+
+        #[test]
+        fn identity_law() {
+            let v: Const<Max<i32>, i32> = Const::new(Max(4));
+            assert_eq!(v.clone().apply(Const::pure(identity)), v);
+        }
+
+        #[test]
+        fn homomorphism_law() {
+            let lhs = Const::<Min<i32>, i32>::pure(5).apply(Const::pure(add_one));
+            assert_eq!(lhs, Const::pure(add_one(5)));
+        }
+    }
+
+    mod stats {
+        use super::*;
+
+        #[test]
+        fn single_pass() {
+            let values = || [17, -4, 23, 8, -15, 42, 0].into_iter();
+            let stats = traverse_const(values(), Stats::of);
+            assert_eq!(stats.count, values().count());
+            assert_eq!(stats.sum, values().sum::<i32>());
+            assert_eq!(stats.min, values().min());
+            assert_eq!(stats.max, values().max());
+        }
+
+        #[test]
+        fn keeps_order() {
+            let first = traverse_const([None, Some(1), Some(2)].into_iter(), First);
+            let last = traverse_const([Some(1), Some(2), None].into_iter(), Last);
+            assert_eq!((first, last), (First(Some(1)), Last(Some(2))));
+        }
+
+        #[test]
+        #[cfg(not(feature = "no_std"))]
+        fn matches_fold_map() {
+            let values = vec![5, 3, 9, 1];
+            assert_eq!(
+                traverse_const(values.clone().into_iter(), Stats::of),
+                values.fold_map(Stats::of)
+            );
+        }
+    }
+}
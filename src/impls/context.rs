@@ -0,0 +1,256 @@
+#[cfg(not(feature = "no_std"))]
+pub mod context_impls {
+    use crate::*;
+
+    /// A `Result` that records a trail of labelled steps.
+    ///
+    /// Each `bind_with` names the step it runs. The label is added to the
+    /// trail when the step is entered, so if a step fails the trail lists
+    /// every step leading up to and including the failing one, in order. This
+    /// gives lightweight error breadcrumbs without a full error library.
+    ///
+    /// Unlabelled `bind` joins the trails of both computations without adding
+    /// a label of its own.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::context::context_impls::Context;
+    ///
+    /// let port = Context::<_, String>::ok("port = x")
+    ///     .bind_with("read config", |s| Context::ok(s.trim_start_matches("port = ")))
+    ///     .bind_with("parse port", |s| Context::from_result(s.parse::<u16>().map_err(|e| e.to_string())));
+    /// assert_eq!(port.run(), Err(vec!["read config", "parse port"]));
+    /// ```
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Context<A, E> {
+        result: Result<A, E>,
+        trail: Vec<&'static str>,
+    }
+
+    impl<A, E> Context<A, E> {
+        /// Creates a successful computation with an empty trail.
+        pub fn ok(a: A) -> Self {
+            Self::from_result(Ok(a))
+        }
+
+        /// Creates a failed computation with an empty trail.
+        pub fn fail(e: E) -> Self {
+            Self::from_result(Err(e))
+        }
+
+        /// Wraps a `Result` with an empty trail.
+        pub fn from_result(result: Result<A, E>) -> Self {
+            Context {
+                result,
+                trail: Vec::new(),
+            }
+        }
+
+        /// Runs a labelled step that depends on the successful value.
+        ///
+        /// The label is recorded only if the step runs, so a failure earlier
+        /// in the chain leaves it out of the trail.
+        pub fn bind_with<B, F: FnOnce(A) -> Context<B, E>>(
+            self,
+            label: &'static str,
+            f: F,
+        ) -> Context<B, E> {
+            let mut trail = self.trail;
+            match self.result {
+                Ok(a) => {
+                    trail.push(label);
+                    let mut next = f(a);
+                    trail.append(&mut next.trail);
+                    Context {
+                        result: next.result,
+                        trail,
+                    }
+                }
+                Err(e) => Context {
+                    result: Err(e),
+                    trail,
+                },
+            }
+        }
+
+        /// Returns the labels recorded so far.
+        pub fn trail(&self) -> &[&'static str] {
+            &self.trail
+        }
+
+        /// Returns the value, or the trail of steps that led to the failure.
+        pub fn run(self) -> Result<A, Vec<&'static str>> {
+            self.result.map_err(|_| self.trail)
+        }
+
+        /// Returns the value, or the error together with the trail of steps
+        /// that led to it.
+        pub fn into_result(self) -> Result<A, (E, Vec<&'static str>)> {
+            self.result.map_err(|e| (e, self.trail))
+        }
+    }
+
+    pub struct ContextKind<E>(std::marker::PhantomData<E>);
+
+    impl<E> Generic1 for ContextKind<E> {
+        type Rep1<A> = Context<A, E>;
+    }
+
+    impl<A, E> Kinded1<A> for Context<A, E> {
+        type Kind1 = ContextKind<E>;
+    }
+
+    impl<A, E> Functor<A> for Context<A, E> {
+        fn fmap<B, F: FnOnce(A) -> B>(self, f: F) -> Context<B, E> {
+            Context {
+                result: self.result.map(f),
+                trail: self.trail,
+            }
+        }
+    }
+
+    /// Like `Result`, the value's error wins when both sides fail. The trails
+    /// of both sides are kept, the value's first.
+    impl<A, E> Applicative<A> for Context<A, E> {
+        fn pure(b: A) -> Context<A, E> {
+            Context::ok(b)
+        }
+
+        fn apply<B, F: FnOnce(A) -> B>(self, ff: Context<F, E>) -> Context<B, E> {
+            let mut trail = self.trail;
+            trail.extend(ff.trail);
+            Context {
+                result: match (self.result, ff.result) {
+                    (Ok(a), Ok(f)) => Ok(f(a)),
+                    (Err(e), _) | (_, Err(e)) => Err(e),
+                },
+                trail,
+            }
+        }
+    }
+
+    impl<A, E> Monad<A> for Context<A, E> {
+        fn bind<B, F: FnOnce(A) -> Context<B, E>>(self, f: F) -> Context<B, E> {
+            let mut trail = self.trail;
+            match self.result {
+                Ok(a) => {
+                    let mut next = f(a);
+                    trail.append(&mut next.trail);
+                    Context {
+                        result: next.result,
+                        trail,
+                    }
+                }
+                Err(e) => Context {
+                    result: Err(e),
+                    trail,
+                },
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(feature = "no_std"))]
+mod context_tests {
+    use super::context_impls::*;
+    use crate::*;
+
+    fn positive(x: i32) -> Context<i32, &'static str> {
+        if x > 0 {
+            Context::ok(x)
+        } else {
+            Context::fail("not positive")
+        }
+    }
+
+    #[test]
+    fn success_returns_value() {
+        let c = Context::ok(5)
+            .bind_with("check", positive)
+            .bind_with("double", |x| Context::ok(x * 2));
+        assert_eq!(c.trail(), &["check", "double"]);
+        assert_eq!(c.run(), Ok(10));
+    }
+
+    #[test]
+    fn trail_accumulates_in_order_on_failure() {
+        let c = Context::ok(5)
+            .bind_with("load", |x| Context::ok(x - 10))
+            .bind_with("validate", positive)
+            .bind_with("save", |x| Context::ok(x * 2));
+        assert_eq!(c.run(), Err(vec!["load", "validate"]));
+    }
+
+    #[test]
+    fn nested_steps_are_flattened() {
+        let inner = |x: i32| Context::ok(x).bind_with("inner check", positive);
+        let c = Context::ok(-1).bind_with("outer", inner);
+        assert_eq!(
+            c.into_result(),
+            Err(("not positive", vec!["outer", "inner check"]))
+        );
+    }
+
+    #[test]
+    fn initial_failure_has_empty_trail() {
+        let c = Context::<i32, _>::fail("missing").bind_with("never", positive);
+        assert_eq!(c.run(), Err(vec![]));
+    }
+
+    mod functor {
+        use super::*;
+
+        #[test]
+        fn fmap_keeps_trail() {
+            let c = Context::ok(3).bind_with("check", positive).fmap(square);
+            assert_eq!(c.trail(), &["check"]);
+            assert_eq!(c.run(), Ok(9));
+        }
+    }
+
+    mod applicative {
+        use super::*;
+
+        #[test]
+        fn apply_joins_trails() {
+            let v = Context::ok(1).bind_with("value", positive);
+            let f = Context::ok(()).bind_with("function", |()| Context::ok(add_one));
+            let c = v.apply(f);
+            assert_eq!(c.trail(), &["value", "function"]);
+            assert_eq!(c.run(), Ok(2));
+        }
+    }
+
+    mod monad {
+        use super::*;
+
+        fn step(x: i32) -> Context<i32, &'static str> {
+            Context::ok(x).bind_with("step", |x| positive(x - 1))
+        }
+
+        // Left identity: return a >>= f = f a
+        #[test]
+        fn left_identity() {
+            assert_eq!(Context::pure(2).bind(step), step(2));
+            assert_eq!(Context::pure(1).bind(step), step(1));
+        }
+
+        // Right identity: m >>= return = m
+        #[test]
+        fn right_identity() {
+            assert_eq!(step(2).bind(Context::pure), step(2));
+            assert_eq!(step(1).bind(Context::pure), step(1));
+        }
+
+        // Associativity: (m >>= f) >>= g = m >>= (\x -> f x >>= g)
+        #[test]
+        fn associativity() {
+            for x in [1, 2, 3] {
+                let lhs = step(x).bind(step).bind(step);
+                let rhs = step(x).bind(|y| step(y).bind(step));
+                assert_eq!(lhs, rhs);
+            }
+        }
+    }
+}
@@ -0,0 +1,157 @@
+#[cfg(not(feature = "no_std"))]
+pub mod counter_impls {
+    /// A computation that threads an incrementing counter.
+    ///
+    /// `Counter` is a specialised state monad whose state is a `usize`. It is
+    /// meant for handing out sequential ids in a pipeline without passing the
+    /// next free id around by hand: `next_id` yields the current value and
+    /// advances it, and `bind` chains the steps so each sees the counter left
+    /// by the previous one. Nothing runs until `run` is called with a start
+    /// value.
+    ///
+    /// `Counter` has no `Functor`/`Monad` instance: each step is stored in
+    /// the box until `run` hands it a counter, so `fmap` and `bind` only take
+    /// `'static` closures, while the trait methods must accept closures that
+    /// borrow from the caller.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::counter::counter_impls::Counter;
+    ///
+    /// let ids = Counter::next_id().bind(|a| Counter::next_id().fmap(move |b| (a, b)));
+    /// assert_eq!(ids.run(10), ((10, 11), 12));
+    /// ```
+    pub struct Counter<A>(Box<dyn FnOnce(usize) -> (A, usize)>);
+
+    impl Counter<usize> {
+        /// Yields the current counter value and advances the counter by one.
+        pub fn next_id() -> Self {
+            Counter::new(|n| (n, n + 1))
+        }
+    }
+
+    impl<A: 'static> Counter<A> {
+        /// Wraps a function from the current counter to a value and the next
+        /// counter.
+        pub fn new<F: FnOnce(usize) -> (A, usize) + 'static>(f: F) -> Self {
+            Counter(Box::new(f))
+        }
+
+        /// Lifts a value into a computation that leaves the counter untouched.
+        pub fn pure(a: A) -> Self {
+            Counter::new(move |n| (a, n))
+        }
+
+        /// Runs the computation from `start`, returning the value and the
+        /// final counter.
+        pub fn run(self, start: usize) -> (A, usize) {
+            (self.0)(start)
+        }
+
+        /// Runs the computation from `start`, returning only the value.
+        pub fn eval(self, start: usize) -> A {
+            self.run(start).0
+        }
+
+        /// Maps a function over the produced value.
+        pub fn fmap<B, F: FnOnce(A) -> B + 'static>(self, f: F) -> Counter<B> {
+            Counter(Box::new(move |n| {
+                let (a, n) = self.run(n);
+                (f(a), n)
+            }))
+        }
+
+        /// Applies the function produced by `ff` to the value produced by this
+        /// computation, running this computation first.
+        pub fn apply<B, F: FnOnce(A) -> B + 'static>(self, ff: Counter<F>) -> Counter<B> {
+            Counter(Box::new(move |n| {
+                let (a, n) = self.run(n);
+                let (f, n) = ff.0(n);
+                (f(a), n)
+            }))
+        }
+
+        /// Sequences a computation that depends on this computation's value,
+        /// continuing from the counter it left behind.
+        pub fn bind<B, F: FnOnce(A) -> Counter<B> + 'static>(self, f: F) -> Counter<B> {
+            Counter(Box::new(move |n| {
+                let (a, n) = self.run(n);
+                f(a).0(n)
+            }))
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(feature = "no_std"))]
+mod counter_tests {
+    use super::counter_impls::*;
+
+    #[test]
+    fn pure() {
+        assert_eq!(Counter::pure(69).run(3), (69, 3));
+    }
+
+    #[test]
+    fn next_id() {
+        assert_eq!(Counter::next_id().run(0), (0, 1));
+    }
+
+    #[test]
+    fn fmap() {
+        let result = Counter::next_id().fmap(|n| n * 2).fmap(|n| n.to_string());
+        assert_eq!(result.run(5), ("10".to_string(), 6));
+    }
+
+    #[test]
+    fn apply() {
+        let ff = Counter::next_id().fmap(|n| move |x: usize| x * 100 + n);
+        assert_eq!(Counter::next_id().apply(ff).run(1), (102, 3));
+    }
+
+    #[test]
+    fn three_sequential_ids() {
+        let ids = Counter::next_id().bind(|a| {
+            Counter::next_id().bind(move |b| Counter::next_id().fmap(move |c| vec![a, b, c]))
+        });
+        assert_eq!(ids.run(0), (vec![0, 1, 2], 3));
+    }
+
+    #[test]
+    fn eval() {
+        let labelled = Counter::next_id().fmap(|id| format!("node-{id}"));
+        assert_eq!(labelled.eval(7), "node-7");
+    }
+
+    mod monad {
+        use super::*;
+
+        fn tag(x: usize) -> Counter<(usize, usize)> {
+            Counter::next_id().fmap(move |id| (id, x))
+        }
+
+        // Left identity: return a >>= f = f a
+        #[test]
+        fn left_identity() {
+            assert_eq!(Counter::pure(9).bind(tag).run(0), tag(9).run(0));
+        }
+
+        // Right identity: m >>= return = m
+        #[test]
+        fn right_identity() {
+            assert_eq!(
+                Counter::next_id().bind(Counter::pure).run(4),
+                Counter::next_id().run(4)
+            );
+        }
+
+        // Associativity: (m >>= f) >>= g = m >>= (\x -> f x >>= g)
+        #[test]
+        fn associativity() {
+            let g = |(id, x): (usize, usize)| Counter::pure(id + x);
+            let lhs = Counter::next_id().bind(tag).bind(g);
+            let rhs = Counter::next_id().bind(move |x| tag(x).bind(g));
+            assert_eq!(lhs.run(2), rhs.run(2));
+        }
+    }
+}
@@ -0,0 +1,261 @@
+#[cfg(all(feature = "backtrace", not(feature = "no_std")))]
+pub mod fallible_impls {
+    use crate::*;
+    use std::backtrace::Backtrace;
+    use std::error::Error;
+    use std::fmt;
+
+    /// A `Result` that records a backtrace where it first failed.
+    ///
+    /// `fail` and `from_result` capture a `Backtrace` when they produce an
+    /// error, and `fmap`, `apply` and `bind` pass an existing failure along
+    /// untouched, so the backtrace of a failed chain always points at the step
+    /// that failed rather than at the end of the chain.
+    ///
+    /// Backtraces are captured with `Backtrace::force_capture`, regardless of
+    /// `RUST_BACKTRACE`, which makes every failure noticeably slower than a
+    /// plain `Err`. The error type defaults to `Box<dyn Error>`.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::*;
+    /// use crab_fp::fallible::fallible_impls::Fallible;
+    ///
+    /// let port = Fallible::<_, String>::ok("x")
+    ///     .bind(|s| Fallible::from_result(s.parse::<u16>().map_err(|e| e.to_string())))
+    ///     .fmap(|p| p + 1);
+    /// assert!(port.backtrace().is_some());
+    /// assert_eq!(port.into_result(), Err("invalid digit found in string".to_string()));
+    /// ```
+    pub struct Fallible<A, E = Box<dyn Error>>(Result<A, Failure<E>>);
+
+    /// An error together with the backtrace captured when it occurred.
+    pub struct Failure<E> {
+        error: E,
+        backtrace: Backtrace,
+    }
+
+    impl<E> Failure<E> {
+        /// Returns the error.
+        pub fn error(&self) -> &E {
+            &self.error
+        }
+
+        /// Returns the backtrace captured when the error occurred.
+        pub fn backtrace(&self) -> &Backtrace {
+            &self.backtrace
+        }
+    }
+
+    impl<E: fmt::Debug> fmt::Debug for Failure<E> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("Failure")
+                .field("error", &self.error)
+                .field("backtrace", &self.backtrace)
+                .finish()
+        }
+    }
+
+    impl<A, E> Fallible<A, E> {
+        /// Creates a successful computation.
+        pub fn ok(a: A) -> Self {
+            Fallible(Ok(a))
+        }
+
+        /// Creates a failed computation, capturing a backtrace here.
+        pub fn fail(error: E) -> Self {
+            Fallible(Err(Failure {
+                error,
+                backtrace: Backtrace::force_capture(),
+            }))
+        }
+
+        /// Wraps a `Result`, capturing a backtrace here if it is `Err`.
+        pub fn from_result(result: Result<A, E>) -> Self {
+            match result {
+                Ok(a) => Fallible::ok(a),
+                Err(e) => Fallible::fail(e),
+            }
+        }
+
+        /// Returns `true` if the computation succeeded.
+        pub fn is_ok(&self) -> bool {
+            self.0.is_ok()
+        }
+
+        /// Returns the backtrace of the failure, or `None` on success.
+        pub fn backtrace(&self) -> Option<&Backtrace> {
+            self.0.as_ref().err().map(Failure::backtrace)
+        }
+
+        /// Converts into a `Result` that keeps the backtrace.
+        pub fn into_failure(self) -> Result<A, Failure<E>> {
+            self.0
+        }
+
+        /// Converts into a plain `Result`, discarding the backtrace.
+        pub fn into_result(self) -> Result<A, E> {
+            self.0.map_err(|f| f.error)
+        }
+    }
+
+    impl<A: fmt::Debug, E: fmt::Debug> fmt::Debug for Fallible<A, E> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_tuple("Fallible").field(&self.0).finish()
+        }
+    }
+
+    pub struct FallibleKind<E>(std::marker::PhantomData<E>);
+
+    impl<E> Generic1 for FallibleKind<E> {
+        type Rep1<A> = Fallible<A, E>;
+    }
+
+    impl<A, E> Kinded1<A> for Fallible<A, E> {
+        type Kind1 = FallibleKind<E>;
+    }
+
+    impl<A, E> Functor<A> for Fallible<A, E> {
+        fn fmap<B, F: FnOnce(A) -> B>(self, f: F) -> Fallible<B, E> {
+            Fallible(self.0.map(f))
+        }
+    }
+
+    /// Like `Result`, the value's failure wins when both sides fail.
+    impl<A, E> Applicative<A> for Fallible<A, E> {
+        fn pure(a: A) -> Fallible<A, E> {
+            Fallible::ok(a)
+        }
+
+        fn apply<B, F: FnOnce(A) -> B>(self, ff: Fallible<F, E>) -> Fallible<B, E> {
+            match (self.0, ff.0) {
+                (Ok(a), Ok(f)) => Fallible::ok(f(a)),
+                (Err(e), _) | (_, Err(e)) => Fallible(Err(e)),
+            }
+        }
+    }
+
+    impl<A, E> Monad<A> for Fallible<A, E> {
+        fn bind<B, F: FnOnce(A) -> Fallible<B, E>>(self, f: F) -> Fallible<B, E> {
+            match self.0 {
+                Ok(a) => f(a),
+                Err(e) => Fallible(Err(e)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(all(feature = "backtrace", not(feature = "no_std")))]
+mod fallible_tests {
+    use super::fallible_impls::*;
+    use crate::*;
+    use std::backtrace::BacktraceStatus;
+
+    fn failing_step(x: i32) -> Fallible<i32, String> {
+        if x > 0 {
+            Fallible::ok(x)
+        } else {
+            Fallible::fail(format!("{x} is not positive"))
+        }
+    }
+
+    #[test]
+    fn success_has_no_backtrace() {
+        let f = failing_step(3);
+        assert!(f.is_ok());
+        assert!(f.backtrace().is_none());
+        assert_eq!(f.into_result(), Ok(3));
+    }
+
+    #[test]
+    fn from_result() {
+        let ok: Fallible<i32, &str> = Fallible::from_result(Ok(1));
+        assert!(ok.backtrace().is_none());
+        let err: Fallible<i32, &str> = Fallible::from_result(Err("bad"));
+        assert!(err.backtrace().is_some());
+        assert_eq!(err.into_result(), Err("bad"));
+    }
+
+    #[test]
+    fn default_error_type() {
+        let f: Fallible<u8> = Fallible::from_result("x".parse::<u8>().map_err(Into::into));
+        assert!(f.into_result().is_err());
+    }
+
+    #[test]
+    fn failure_captures_backtrace() {
+        let f = failing_step(-1);
+        assert_eq!(f.backtrace().unwrap().status(), BacktraceStatus::Captured);
+    }
+
+    mod functor {
+        use super::*;
+
+        #[test]
+        fn fmap() {
+            assert_eq!(failing_step(3).fmap(add_one).into_result(), Ok(4));
+            assert!(failing_step(-1).fmap(add_one).backtrace().is_some());
+        }
+
+        #[test]
+        fn identity_law() {
+            assert_eq!(failing_step(3).fmap(identity).into_result(), Ok(3));
+        }
+    }
+
+    mod applicative {
+        use super::*;
+
+        #[test]
+        fn value_failure_wins() {
+            let ff: Fallible<fn(i32) -> i32, String> = Fallible::fail("function".to_string());
+            let value = failing_step(-1);
+            assert_eq!(
+                value.apply(ff).into_result(),
+                Err("-1 is not positive".to_string())
+            );
+        }
+
+        #[test]
+        fn homomorphism_law() {
+            let lhs: Fallible<i32, String> = Fallible::pure(5).apply(Fallible::pure(add_one));
+            assert_eq!(lhs.into_result(), Ok(add_one(5)));
+        }
+    }
+
+    mod monad {
+        use super::*;
+
+        #[test]
+        fn backtrace_not_overwritten_by_later_binds() {
+            let failed = Fallible::<i32, String>::ok(-1).bind(failing_step);
+            let original = failed.backtrace().unwrap().to_string();
+
+            let mut later_ran = false;
+            let chained = failed
+                .bind(|x| {
+                    later_ran = true;
+                    Fallible::ok(x)
+                })
+                .fmap(add_one)
+                .bind(|_| Fallible::<i32, _>::fail("later".to_string()));
+
+            assert!(!later_ran);
+            assert_eq!(chained.backtrace().unwrap().to_string(), original);
+            let failure = chained.into_failure().unwrap_err();
+            assert_eq!(failure.error(), "-1 is not positive");
+        }
+
+        #[test]
+        fn left_identity_law() {
+            let lhs = Fallible::pure(3).bind(failing_step).into_result();
+            assert_eq!(lhs, failing_step(3).into_result());
+        }
+
+        #[test]
+        fn right_identity_law() {
+            assert_eq!(failing_step(3).bind(Fallible::pure).into_result(), Ok(3));
+        }
+    }
+}
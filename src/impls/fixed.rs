@@ -0,0 +1,218 @@
+pub mod fixed_impls {
+    use std::fmt;
+    use std::ops::{Add, Sub};
+
+    /// A fixed-point decimal with `SCALE` digits after the point.
+    ///
+    /// The value is stored as an `i64` count of `10^-SCALE` units, so a
+    /// `Fixed<2>` holds whole cents and `0.10 + 0.20` is exactly `0.30`.
+    /// Addition and subtraction are exact, and `mul_round` multiplies by a
+    /// decimal of any scale, rounding half away from zero only once at the
+    /// end. A result that does not fit in the `i64` panics rather than
+    /// silently wrapping, so no operation ever returns an inexact value.
+    ///
+    /// `Fixed` holds a single `i64` rather than an arbitrary value, so it has
+    /// no `Functor` instance. `map` is its analogue: it maps over the raw
+    /// units and keeps the scale.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::fixed::fixed_impls::Fixed;
+    ///
+    /// let price = Fixed::<2>::from_raw(1999);
+    /// let tax_rate = Fixed::<4>::from_raw(10825);
+    /// assert_eq!(price.mul_round(tax_rate), Fixed::from_raw(2164));
+    /// assert_eq!(price.map(|cents| cents * 2).to_string(), "39.98");
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+    pub struct Fixed<const SCALE: u32>(i64);
+
+    impl<const SCALE: u32> Fixed<SCALE> {
+        /// The number of raw units in one whole.
+        pub const ONE: i64 = 10i64.pow(SCALE);
+
+        /// Creates a value from a count of `10^-SCALE` units.
+        pub const fn from_raw(raw: i64) -> Self {
+            Fixed(raw)
+        }
+
+        /// Creates a whole-number value.
+        ///
+        /// # Panics
+        /// Panics if `n` whole units do not fit in an `i64`.
+        pub const fn from_int(n: i64) -> Self {
+            match n.checked_mul(Self::ONE) {
+                Some(raw) => Fixed(raw),
+                None => panic!("Fixed::from_int overflowed i64"),
+            }
+        }
+
+        /// Returns the count of `10^-SCALE` units.
+        pub const fn raw(self) -> i64 {
+            self.0
+        }
+
+        /// Maps a function over the raw units, keeping the scale.
+        pub fn map<F: FnOnce(i64) -> i64>(self, f: F) -> Self {
+            Fixed(f(self.0))
+        }
+
+        /// Multiplies by a decimal of any scale, rounding half away from zero
+        /// back to this scale.
+        ///
+        /// # Panics
+        /// Panics if the rounded product does not fit in an `i64`.
+        pub fn mul_round<const S: u32>(self, factor: Fixed<S>) -> Self {
+            // Two `i64`s multiply without overflow in an `i128`.
+            let product = self.0 as i128 * factor.0 as i128;
+            let divisor = Fixed::<S>::ONE as i128;
+            let half = product.signum() * (divisor / 2);
+            let rounded = (product + half) / divisor;
+            Fixed(i64::try_from(rounded).expect("Fixed::mul_round overflowed i64"))
+        }
+
+        /// Converts to the nearest `f64`, for display or interop.
+        pub fn to_f64(self) -> f64 {
+            self.0 as f64 / Self::ONE as f64
+        }
+    }
+
+    impl<const SCALE: u32> Add for Fixed<SCALE> {
+        type Output = Self;
+
+        /// # Panics
+        /// Panics if the sum does not fit in an `i64`.
+        fn add(self, other: Self) -> Self {
+            Fixed(
+                self.0
+                    .checked_add(other.0)
+                    .expect("Fixed addition overflowed i64"),
+            )
+        }
+    }
+
+    impl<const SCALE: u32> Sub for Fixed<SCALE> {
+        type Output = Self;
+
+        /// # Panics
+        /// Panics if the difference does not fit in an `i64`.
+        fn sub(self, other: Self) -> Self {
+            Fixed(
+                self.0
+                    .checked_sub(other.0)
+                    .expect("Fixed subtraction overflowed i64"),
+            )
+        }
+    }
+
+    impl<const SCALE: u32> fmt::Display for Fixed<SCALE> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let sign = if self.0 < 0 { "-" } else { "" };
+            let abs = self.0.unsigned_abs();
+            let one = Self::ONE as u64;
+            if SCALE == 0 {
+                write!(f, "{sign}{abs}")
+            } else {
+                let width = SCALE as usize;
+                write!(f, "{sign}{}.{:0width$}", abs / one, abs % one)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod fixed_tests {
+    use super::fixed_impls::*;
+
+    type Price = Fixed<2>;
+
+    #[test]
+    fn map_keeps_scale() {
+        let doubled: Price = Price::from_raw(1499).map(|c| c * 2);
+        assert_eq!(doubled, Price::from_raw(2998));
+    }
+
+    #[test]
+    fn map_identity() {
+        assert_eq!(Price::from_raw(1499).map(|c| c), Price::from_raw(1499));
+    }
+
+    #[test]
+    fn tax_without_drift() {
+        // The price calculator doubles a price and adds 15% tax. In `f64`,
+        // 1.15 is slightly below 1.15, so truncating to cents loses a cent.
+        let cents = 100u32;
+        let with_f64 = ((cents * 2) as f64 * 1.15) as u32;
+        assert_eq!(with_f64, 229);
+
+        let tax = Fixed::<2>::from_raw(115);
+        let with_fixed = Price::from_raw(cents as i64).map(|c| c * 2).mul_round(tax);
+        assert_eq!(with_fixed, Price::from_raw(230));
+    }
+
+    #[test]
+    fn sums_exactly() {
+        let dime = Price::from_raw(10);
+        let total = (0..10).fold(Price::default(), |acc, _| acc + dime);
+        assert_eq!(total, Price::from_int(1));
+
+        let float_total = (0..10).fold(0.0, |acc: f64, _| acc + 0.1);
+        assert_ne!(float_total, 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Fixed::mul_round overflowed i64")]
+    fn mul_round_overflow_panics() {
+        let _ = Price::from_raw(i64::MAX).mul_round(Fixed::<0>::from_raw(2));
+    }
+
+    #[test]
+    fn mul_round_near_limit() {
+        let max = Price::from_raw(i64::MAX);
+        assert_eq!(max.mul_round(Fixed::<2>::from_raw(100)), max);
+    }
+
+    #[test]
+    #[should_panic(expected = "Fixed::from_int overflowed i64")]
+    fn from_int_overflow_panics() {
+        let _ = Price::from_int(i64::MAX / 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "Fixed addition overflowed i64")]
+    fn add_overflow_panics() {
+        let _ = Price::from_raw(i64::MAX) + Price::from_raw(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Fixed subtraction overflowed i64")]
+    fn sub_overflow_panics() {
+        let _ = Price::from_raw(i64::MIN) - Price::from_raw(1);
+    }
+
+    #[test]
+    fn mul_round_half_away_from_zero() {
+        let half = Fixed::<1>::from_raw(5);
+        assert_eq!(Price::from_raw(1).mul_round(half), Price::from_raw(1));
+        assert_eq!(Price::from_raw(-1).mul_round(half), Price::from_raw(-1));
+        assert_eq!(Price::from_raw(3).mul_round(half), Price::from_raw(2));
+        assert_eq!(
+            Price::from_raw(1999).mul_round(Fixed::<0>::from_int(3)),
+            Price::from_raw(5997)
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn display() {
+        assert_eq!(Price::from_raw(1999).to_string(), "19.99");
+        assert_eq!(Price::from_raw(-5).to_string(), "-0.05");
+        assert_eq!(Fixed::<3>::from_int(7).to_string(), "7.000");
+        assert_eq!(Fixed::<0>::from_raw(42).to_string(), "42");
+    }
+
+    #[test]
+    fn to_f64() {
+        assert_eq!(Price::from_raw(1999).to_f64(), 19.99);
+    }
+}
@@ -0,0 +1,137 @@
+#[cfg(all(feature = "async", not(feature = "no_std")))]
+pub mod future_impls {
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+
+    /// A boxed, thread-safe asynchronous computation producing an `A`.
+    ///
+    /// `Async` wraps any `Send` future so it can be composed with the crate's
+    /// vocabulary: `fmap` transforms the eventual output, `apply` combines two
+    /// computations, and `bind` sequences a computation that depends on the
+    /// output of the previous one. `Async` is itself a future, so the composed
+    /// computation is run by awaiting it on any executor.
+    ///
+    /// `Async` has no `Functor`/`Monad` instance: the executor may poll it on
+    /// any thread, so the closures `fmap` and `bind` capture, and the values
+    /// they produce, have to be `Send + 'static`, bounds the trait methods do
+    /// not place on their arguments.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::future::future_impls::Async;
+    ///
+    /// let computation = Async::pure(20)
+    ///     .fmap(|x| x + 1)
+    ///     .bind(|x| Async::new(async move { x * 2 }));
+    ///
+    /// let rt = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    /// assert_eq!(rt.block_on(computation), 42);
+    /// ```
+    pub struct Async<A>(Pin<Box<dyn Future<Output = A> + Send>>);
+
+    impl<A: Send + 'static> Async<A> {
+        /// Wraps a future.
+        pub fn new<Fut: Future<Output = A> + Send + 'static>(future: Fut) -> Self {
+            Async(Box::pin(future))
+        }
+
+        /// Lifts a value into an already-completed computation.
+        pub fn pure(a: A) -> Self {
+            Self::new(std::future::ready(a))
+        }
+
+        /// Maps a function over the eventual output.
+        pub fn fmap<B, F>(self, f: F) -> Async<B>
+        where
+            B: Send + 'static,
+            F: FnOnce(A) -> B + Send + 'static,
+        {
+            Async::new(async move { f(self.await) })
+        }
+
+        /// Applies the eventual function of `ff` to the eventual output of
+        /// this computation, running this computation first.
+        pub fn apply<B, F>(self, ff: Async<F>) -> Async<B>
+        where
+            B: Send + 'static,
+            F: FnOnce(A) -> B + Send + 'static,
+        {
+            Async::new(async move {
+                let a = self.await;
+                let f = ff.await;
+                f(a)
+            })
+        }
+
+        /// Sequences a computation that depends on this computation's output.
+        pub fn bind<B, F>(self, f: F) -> Async<B>
+        where
+            B: Send + 'static,
+            F: FnOnce(A) -> Async<B> + Send + 'static,
+        {
+            Async::new(async move { f(self.await).await })
+        }
+    }
+
+    impl<A> Future for Async<A> {
+        type Output = A;
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<A> {
+            self.0.as_mut().poll(cx)
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(all(feature = "async", not(feature = "no_std")))]
+mod future_tests {
+    use super::future_impls::*;
+    use crate::*;
+
+    #[tokio::test]
+    async fn pure() {
+        assert_eq!(Async::pure(69).await, 69);
+    }
+
+    #[tokio::test]
+    async fn fmap() {
+        let result = Async::pure(5).fmap(multiply_by_two).fmap(to_string).await;
+        assert_eq!(result, "10");
+    }
+
+    #[tokio::test]
+    async fn apply() {
+        let result = Async::pure(5).apply(Async::pure(add_one)).await;
+        assert_eq!(result, 6);
+    }
+
+    #[tokio::test]
+    async fn bind_chains_two_steps() {
+        let fetch_id = |name: &'static str| Async::new(async move { name.len() as i32 });
+        let fetch_score = |id: i32| Async::new(async move { id * 10 });
+
+        let result = Async::pure("crab").bind(fetch_id).bind(fetch_score).await;
+        assert_eq!(result, 40);
+    }
+
+    #[tokio::test]
+    async fn left_identity_law() {
+        let f = |x: i32| Async::pure(x * 2);
+        assert_eq!(Async::pure(5).bind(f).await, f(5).await);
+    }
+
+    #[tokio::test]
+    async fn right_identity_law() {
+        assert_eq!(Async::pure(5).bind(Async::pure).await, 5);
+    }
+
+    #[tokio::test]
+    async fn associativity_law() {
+        let f = |x: i32| Async::pure(x * 2);
+        let g = |x: i32| Async::pure(x + 3);
+        let lhs = Async::pure(5).bind(f).bind(g).await;
+        let rhs = Async::pure(5).bind(move |x| f(x).bind(g)).await;
+        assert_eq!(lhs, rhs);
+    }
+}
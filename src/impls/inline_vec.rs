@@ -0,0 +1,320 @@
+pub mod inline_vec_impls {
+    use crate::*;
+    use std::fmt;
+    use std::mem::MaybeUninit;
+
+    /// A vector with a fixed capacity of `N`, stored inline without allocating.
+    ///
+    /// `InlineVec` is a collection functor that works without `alloc`, so it
+    /// is available in `no_std` builds where `Vec` is not. Pushing past the
+    /// capacity fails and hands the rejected value back.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::*;
+    /// use crab_fp::inline_vec::inline_vec_impls::InlineVec;
+    ///
+    /// let mut v = InlineVec::<i32, 4>::new();
+    /// v.push(1).unwrap();
+    /// v.push(2).unwrap();
+    /// assert_eq!(v.fmap(|x| x * 10).as_slice(), &[10, 20]);
+    /// ```
+    pub struct InlineVec<A, const N: usize> {
+        data: [MaybeUninit<A>; N],
+        len: usize,
+    }
+
+    impl<A, const N: usize> InlineVec<A, N> {
+        /// Creates an empty vector.
+        pub fn new() -> Self {
+            InlineVec {
+                data: [const { MaybeUninit::uninit() }; N],
+                len: 0,
+            }
+        }
+
+        /// Appends a value, or returns it as an error if the vector is full.
+        pub fn push(&mut self, a: A) -> Result<(), A> {
+            if self.len == N {
+                return Err(a);
+            }
+            self.data[self.len].write(a);
+            self.len += 1;
+            Ok(())
+        }
+
+        /// Removes and returns the last element, if any.
+        pub fn pop(&mut self) -> Option<A> {
+            if self.len == 0 {
+                return None;
+            }
+            self.len -= 1;
+            // SAFETY: the element at the old `len - 1` is initialised and is
+            // no longer counted, so it is read exactly once.
+            Some(unsafe { self.data[self.len].assume_init_read() })
+        }
+
+        /// Returns the number of elements.
+        pub fn len(&self) -> usize {
+            self.len
+        }
+
+        /// Returns `true` if the vector has no elements.
+        pub fn is_empty(&self) -> bool {
+            self.len == 0
+        }
+
+        /// Returns `true` if no more elements can be pushed.
+        pub fn is_full(&self) -> bool {
+            self.len == N
+        }
+
+        /// Returns the elements as a slice.
+        pub fn as_slice(&self) -> &[A] {
+            // SAFETY: the first `len` elements are initialised.
+            unsafe { std::slice::from_raw_parts(self.data.as_ptr().cast(), self.len) }
+        }
+
+        /// Returns the elements as a mutable slice.
+        pub fn as_mut_slice(&mut self) -> &mut [A] {
+            // SAFETY: the first `len` elements are initialised.
+            unsafe { std::slice::from_raw_parts_mut(self.data.as_mut_ptr().cast(), self.len) }
+        }
+    }
+
+    impl<A, const N: usize> Default for InlineVec<A, N> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl<A, const N: usize> Drop for InlineVec<A, N> {
+        fn drop(&mut self) {
+            for slot in &mut self.data[..self.len] {
+                // SAFETY: the first `len` elements are initialised.
+                unsafe { slot.assume_init_drop() };
+            }
+        }
+    }
+
+    impl<A: Clone, const N: usize> Clone for InlineVec<A, N> {
+        fn clone(&self) -> Self {
+            let mut out = Self::new();
+            for a in self.as_slice() {
+                let _ = out.push(a.clone());
+            }
+            out
+        }
+    }
+
+    impl<A: PartialEq, const N: usize> PartialEq for InlineVec<A, N> {
+        fn eq(&self, other: &Self) -> bool {
+            self.as_slice() == other.as_slice()
+        }
+    }
+
+    impl<A: fmt::Debug, const N: usize> fmt::Debug for InlineVec<A, N> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_list().entries(self.as_slice()).finish()
+        }
+    }
+
+    /// An owning iterator over the elements of an `InlineVec`.
+    pub struct IntoIter<A, const N: usize> {
+        data: [MaybeUninit<A>; N],
+        pos: usize,
+        len: usize,
+    }
+
+    impl<A, const N: usize> Iterator for IntoIter<A, N> {
+        type Item = A;
+
+        fn next(&mut self) -> Option<A> {
+            if self.pos == self.len {
+                return None;
+            }
+            // SAFETY: elements in `pos..len` are initialised and each is read
+            // at most once, since `pos` moves past it.
+            let a = unsafe { self.data[self.pos].assume_init_read() };
+            self.pos += 1;
+            Some(a)
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            let n = self.len - self.pos;
+            (n, Some(n))
+        }
+    }
+
+    impl<A, const N: usize> Drop for IntoIter<A, N> {
+        fn drop(&mut self) {
+            for slot in &mut self.data[self.pos..self.len] {
+                // SAFETY: elements in `pos..len` have not been read yet.
+                unsafe { slot.assume_init_drop() };
+            }
+        }
+    }
+
+    impl<A, const N: usize> IntoIterator for InlineVec<A, N> {
+        type Item = A;
+        type IntoIter = IntoIter<A, N>;
+
+        fn into_iter(self) -> IntoIter<A, N> {
+            let this = std::mem::ManuallyDrop::new(self);
+            IntoIter {
+                // SAFETY: `this` is never dropped, so ownership of the
+                // initialised elements moves to the iterator.
+                data: unsafe { std::ptr::read(&this.data) },
+                pos: 0,
+                len: this.len,
+            }
+        }
+    }
+
+    pub struct InlineVecKind<const N: usize>;
+
+    impl<const N: usize> Generic1 for InlineVecKind<N> {
+        type Rep1<A> = InlineVec<A, N>;
+    }
+
+    impl<A, const N: usize> Kinded1<A> for InlineVec<A, N> {
+        type Kind1 = InlineVecKind<N>;
+    }
+
+    impl<A, const N: usize> Functor<A> for InlineVec<A, N> {
+        fn fmap<B, F: FnMut(A) -> B>(self, mut f: F) -> InlineVec<B, N> {
+            let mut out = InlineVec::new();
+            for a in self {
+                // The output has the same capacity as the input, so this
+                // cannot overflow.
+                let _ = out.push(f(a));
+            }
+            out
+        }
+    }
+
+    impl<A, const N: usize> Foldable<A> for InlineVec<A, N> {
+        fn fold_left<B, F: FnMut(B, A) -> B>(self, init: B, f: F) -> B {
+            self.into_iter().fold(init, f)
+        }
+
+        fn fold_right<B, F: FnMut(A, B) -> B>(mut self, init: B, mut f: F) -> B {
+            let mut acc = init;
+            while let Some(a) = self.pop() {
+                acc = f(a, acc);
+            }
+            acc
+        }
+    }
+}
+
+#[cfg(test)]
+mod inline_vec_tests {
+    use super::inline_vec_impls::*;
+    use crate::*;
+
+    fn from_slice<const N: usize>(items: &[i32]) -> InlineVec<i32, N> {
+        let mut v = InlineVec::new();
+        for &x in items {
+            v.push(x).unwrap();
+        }
+        v
+    }
+
+    #[test]
+    fn push_and_overflow() {
+        let mut v = InlineVec::<i32, 8>::new();
+        for x in 0..8 {
+            assert_eq!(v.push(x), Ok(()));
+        }
+        assert!(v.is_full());
+        assert_eq!(v.push(8), Err(8));
+        assert_eq!(v.len(), 8);
+        assert_eq!(v.as_slice(), &[0, 1, 2, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn pop() {
+        let mut v = from_slice::<8>(&[1, 2]);
+        assert_eq!(v.pop(), Some(2));
+        assert_eq!(v.pop(), Some(1));
+        assert_eq!(v.pop(), None);
+        assert!(v.is_empty());
+    }
+
+    #[test]
+    fn drops_elements() {
+        use std::cell::Cell;
+
+        struct Counted<'a>(&'a Cell<usize>);
+        impl Drop for Counted<'_> {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let drops = Cell::new(0);
+        let mut v = InlineVec::<Counted, 4>::new();
+        for _ in 0..3 {
+            let _ = v.push(Counted(&drops));
+        }
+        let mut iter = v.into_iter();
+        drop(iter.next());
+        assert_eq!(drops.get(), 1);
+        drop(iter);
+        assert_eq!(drops.get(), 3);
+    }
+
+    mod functor {
+        use super::*;
+
+        #[test]
+        fn fmap() {
+            let v = from_slice::<8>(&[1, 2, 3]);
+            assert_eq!(v.fmap(multiply_by_two).as_slice(), &[2, 4, 6]);
+        }
+
+        #[test]
+        fn fmap_full() {
+            let v = from_slice::<8>(&[1, 2, 3, 4, 5, 6, 7, 8]);
+            let mapped = v.fmap(|x| x as i64 * 100);
+            assert_eq!(mapped.as_slice(), &[100, 200, 300, 400, 500, 600, 700, 800]);
+        }
+
+        #[test]
+        fn identity_law() {
+            let v = from_slice::<8>(&[1, 2, 3]);
+            assert_eq!(v.clone().fmap(identity), v);
+        }
+
+        #[test]
+        fn composition_law() {
+            let v = from_slice::<8>(&[1, 2, 3]);
+            let lhs = v.clone().fmap(|x| multiply_by_two(add_one(x)));
+            let rhs = v.fmap(add_one).fmap(multiply_by_two);
+            assert_eq!(lhs, rhs);
+        }
+    }
+
+    mod foldable {
+        use super::*;
+
+        #[test]
+        fn fold_left() {
+            let v = from_slice::<8>(&[1, 2, 3, 4]);
+            assert_eq!(v.fold_left(0, |acc, x| acc * 10 + x), 1234);
+        }
+
+        #[test]
+        fn fold_right() {
+            let v = from_slice::<8>(&[1, 2, 3, 4]);
+            assert_eq!(v.fold_right(0, |x, acc| acc * 10 + x), 4321);
+        }
+
+        #[test]
+        fn empty() {
+            let v = InlineVec::<i32, 8>::new();
+            assert_eq!(v.fold_left(7, |acc, x| acc + x), 7);
+        }
+    }
+}
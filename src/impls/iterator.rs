@@ -0,0 +1,85 @@
+pub mod iterator_impls {
+    use std::iter::Map;
+
+    /// An extension trait bridging standard iterators with the crate's
+    /// functor vocabulary.
+    ///
+    /// Every iterator gains `fmap_iter`, which maps lazily like
+    /// `Iterator::map` without collecting into a container first. The result
+    /// is an ordinary iterator, so it can be chained or collected as usual.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::iterator::iterator_impls::IteratorFunctor;
+    ///
+    /// let total: i32 = [1, 2, 3].into_iter().fmap_iter(|x| x * 2).sum();
+    /// assert_eq!(total, 12);
+    /// ```
+    pub trait IteratorFunctor<A>: Iterator<Item = A> + Sized {
+        /// Lazily maps a function over every item of the iterator.
+        ///
+        /// # Parameters
+        /// * `f` - A function that transforms items of type `A` into values of type `B`
+        ///
+        /// # Returns
+        /// An iterator yielding the transformed items.
+        fn fmap_iter<B, F: FnMut(A) -> B>(self, f: F) -> Map<Self, F> {
+            self.map(f)
+        }
+    }
+
+    impl<A, I: Iterator<Item = A>> IteratorFunctor<A> for I {}
+}
+
+#[cfg(test)]
+mod iterator_tests {
+    use super::iterator_impls::*;
+    use crate::*;
+
+    #[test]
+    fn fmap_iter() {
+        let mut it = [1, 2, 3].into_iter().fmap_iter(multiply_by_two);
+        assert_eq!(it.next(), Some(2));
+        assert_eq!(it.next(), Some(4));
+        assert_eq!(it.next(), Some(6));
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn fmap_iter_is_lazy() {
+        let mut calls = 0;
+        let mut it = (1..).fmap_iter(|x| {
+            calls += 1;
+            x * 2
+        });
+        assert_eq!(it.next(), Some(2));
+        assert_eq!(it.next(), Some(4));
+        drop(it);
+        assert_eq!(calls, 2);
+    }
+
+    #[test]
+    fn result_is_an_iterator() {
+        let total: i32 = (1..=3).fmap_iter(square).filter(|x| x % 2 == 1).sum();
+        assert_eq!(total, 10);
+
+        #[cfg(not(feature = "no_std"))]
+        {
+            let v: Vec<_> = vec![1, 2].into_iter().fmap_iter(to_string).collect();
+            assert_eq!(v, vec!["1", "2"]);
+        }
+    }
+
+    #[test]
+    fn identity_law() {
+        let lhs = [1, 2, 3].into_iter().fmap_iter(identity);
+        assert!(lhs.eq([1, 2, 3]));
+    }
+
+    #[test]
+    fn composition_law() {
+        let lhs = (1..4).fmap_iter(add_one).fmap_iter(square);
+        let rhs = (1..4).fmap_iter(|x| square(add_one(x)));
+        assert!(lhs.eq(rhs));
+    }
+}
@@ -0,0 +1,157 @@
+#[cfg(not(feature = "no_std"))]
+pub mod linked_list_impls {
+    use crate::*;
+    use std::collections::LinkedList;
+
+    pub struct LinkedListKind;
+
+    impl Generic1 for LinkedListKind {
+        type Rep1<A> = LinkedList<A>;
+    }
+
+    impl<A> Kinded1<A> for LinkedList<A> {
+        type Kind1 = LinkedListKind;
+    }
+
+    impl<A> Functor<A> for LinkedList<A> {
+        fn fmap<B, F: FnMut(A) -> B>(self, f: F) -> LinkedList<B> {
+            self.into_iter().map(f).collect()
+        }
+    }
+
+    /// Each function in `ff` contributes one run of results, appended node by
+    /// node in the order of `self`.
+    impl<A: Clone> Applicative<A> for LinkedList<A> {
+        fn pure(b: A) -> LinkedList<A> {
+            LinkedList::from([b])
+        }
+
+        fn apply<B, F: FnMut(A) -> B>(self, ff: LinkedList<F>) -> LinkedList<B> {
+            let mut result = LinkedList::new();
+            for mut f in ff {
+                for a in self.iter().cloned() {
+                    result.push_back(f(a));
+                }
+            }
+            result
+        }
+    }
+
+    impl<A: Clone> Monad<A> for LinkedList<A> {
+        fn bind<B, F: FnMut(A) -> LinkedList<B>>(self, f: F) -> LinkedList<B> {
+            self.into_iter().flat_map(f).collect()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(feature = "no_std"))]
+mod linked_list_tests {
+    use crate::*;
+    use std::collections::LinkedList;
+
+    fn list<A, const N: usize>(items: [A; N]) -> LinkedList<A> {
+        LinkedList::from(items)
+    }
+
+    mod functor {
+        use super::*;
+
+        #[test]
+        fn fmap() {
+            let l = list([1, 2, 3]);
+            assert_eq!(l.fmap(multiply_by_two), list([2, 4, 6]));
+        }
+
+        #[test]
+        fn identity_law() {
+            let l = list([1, 2, 3]);
+            assert_eq!(l.clone().fmap(identity), l);
+        }
+
+        #[test]
+        fn composition_law() {
+            let l = list([1, 2, 3]);
+            let lhs = l.clone().fmap(add_one).fmap(to_string);
+            let rhs = l.fmap(|x| to_string(add_one(x)));
+            assert_eq!(lhs, rhs);
+        }
+    }
+
+    mod applicative {
+        use super::*;
+
+        #[test]
+        fn pure() {
+            assert_eq!(LinkedList::pure(69), list([69]));
+        }
+
+        #[test]
+        fn ap_order_matches_vec() {
+            let fs = [add_one, multiply_by_two, square];
+            let result = list([1, 2, 3]).apply(list(fs));
+            assert_eq!(result, list([2, 3, 4, 2, 4, 6, 1, 4, 9]));
+
+            let vec_result = vec![1, 2, 3].apply(fs.to_vec());
+            assert!(result.into_iter().eq(vec_result));
+        }
+
+        #[test]
+        fn empty_ap() {
+            let empty: LinkedList<i32> = LinkedList::new();
+            assert_eq!(empty.apply(list([add_one])), LinkedList::new());
+
+            let no_fns: LinkedList<fn(i32) -> i32> = LinkedList::new();
+            assert_eq!(list([1, 2]).apply(no_fns), LinkedList::new());
+        }
+
+        #[test]
+        fn identity_law() {
+            // Identity: pure id <*> v = v
+            let v = list([1, 2, 3]);
+            assert_eq!(v.clone().apply(LinkedList::pure(identity)), v);
+        }
+
+        #[test]
+        fn homomorphism_law() {
+            // Homomorphism: pure f <*> pure x = pure (f x)
+            let lhs = LinkedList::pure(69).apply(LinkedList::pure(to_string));
+            assert_eq!(lhs, LinkedList::pure(to_string(69)));
+        }
+    }
+
+    mod monad {
+        use super::*;
+
+        #[test]
+        fn bind() {
+            let l = list([1, 2]);
+            assert_eq!(l.bind(|x| list([x, x * 10])), list([1, 10, 2, 20]));
+        }
+
+        #[test]
+        fn left_identity_law() {
+            // Left identity: return a >>= f = f a
+            let f = |x: i32| list([x, x * 2]);
+            assert_eq!(LinkedList::pure(5).bind(f), f(5));
+        }
+
+        #[test]
+        fn right_identity_law() {
+            // Right identity: m >>= return = m
+            let m = list([1, 2, 3]);
+            assert_eq!(m.clone().bind(LinkedList::pure), m);
+        }
+
+        #[test]
+        fn associativity_law() {
+            // Associativity: (m >>= f) >>= g = m >>= (|x| f x >>= g)
+            let m = list([1, 2, 3]);
+            let f = |x: i32| list([x, x * 2]);
+            let g = |x: i32| list([to_string(x)]);
+            let lhs = m.clone().bind(f).bind(g);
+            let rhs = m.bind(|x| f(x).bind(g));
+            assert_eq!(lhs, rhs);
+        }
+    }
+}
@@ -0,0 +1,223 @@
+pub mod measured_impls {
+    use crate::*;
+    use std::fmt;
+    use std::marker::PhantomData;
+    use std::ops::{Add, Sub};
+
+    /// A quantity tagged with a phantom unit.
+    ///
+    /// The unit is any marker type, such as `struct Seconds;`. Mapping keeps
+    /// the tag, so a conversion like `|s| s * 1000.0` stays a
+    /// `Measured<Seconds, _>` unless the caller retags it with `retag`.
+    /// Quantities with the same unit can be added and subtracted; quantities
+    /// with different units do not unify:
+    ///
+    /// ```compile_fail
+    /// use crab_fp::measured::measured_impls::Measured;
+    ///
+    /// struct Seconds;
+    /// struct Meters;
+    ///
+    /// let t = Measured::<Seconds, f64>::new(1.0);
+    /// let d = Measured::<Meters, f64>::new(2.0);
+    /// let _ = t + d;
+    /// ```
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::*;
+    /// use crab_fp::measured::measured_impls::Measured;
+    ///
+    /// struct Seconds;
+    ///
+    /// let timeout = Measured::<Seconds, f64>::new(1.5);
+    /// let doubled = timeout.map_value(|s| s * 2.0);
+    /// assert_eq!((doubled + timeout).into_value(), 4.5);
+    /// ```
+    pub struct Measured<U, T> {
+        value: T,
+        unit: PhantomData<U>,
+    }
+
+    impl<U, T> Measured<U, T> {
+        /// Tags a value with the unit `U`.
+        pub fn new(value: T) -> Self {
+            Measured {
+                value,
+                unit: PhantomData,
+            }
+        }
+
+        /// Returns a reference to the untagged value.
+        pub fn value(&self) -> &T {
+            &self.value
+        }
+
+        /// Discards the unit and returns the value.
+        pub fn into_value(self) -> T {
+            self.value
+        }
+
+        /// Maps a function over the value, keeping the unit.
+        pub fn map_value<B, F: FnOnce(T) -> B>(self, f: F) -> Measured<U, B> {
+            Measured::new(f(self.value))
+        }
+
+        /// Replaces the unit tag, for conversions that change the unit.
+        pub fn retag<V>(self) -> Measured<V, T> {
+            Measured::new(self.value)
+        }
+    }
+
+    // Manual impls, since derives would also require the marker `U` to
+    // implement each trait.
+
+    impl<U, T: Clone> Clone for Measured<U, T> {
+        fn clone(&self) -> Self {
+            Measured::new(self.value.clone())
+        }
+    }
+
+    impl<U, T: Copy> Copy for Measured<U, T> {}
+
+    impl<U, T: PartialEq> PartialEq for Measured<U, T> {
+        fn eq(&self, other: &Self) -> bool {
+            self.value == other.value
+        }
+    }
+
+    impl<U, T: PartialOrd> PartialOrd for Measured<U, T> {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            self.value.partial_cmp(&other.value)
+        }
+    }
+
+    impl<U, T: fmt::Debug> fmt::Debug for Measured<U, T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_tuple("Measured")
+                .field(&self.value)
+                .field(&std::any::type_name::<U>())
+                .finish()
+        }
+    }
+
+    impl<U, T: Add<Output = T>> Add for Measured<U, T> {
+        type Output = Measured<U, T>;
+
+        fn add(self, other: Self) -> Self {
+            Measured::new(self.value + other.value)
+        }
+    }
+
+    impl<U, T: Sub<Output = T>> Sub for Measured<U, T> {
+        type Output = Measured<U, T>;
+
+        fn sub(self, other: Self) -> Self {
+            Measured::new(self.value - other.value)
+        }
+    }
+
+    pub struct MeasuredKind<U>(PhantomData<U>);
+
+    impl<U> Generic1 for MeasuredKind<U> {
+        type Rep1<A> = Measured<U, A>;
+    }
+
+    impl<U, A> Kinded1<A> for Measured<U, A> {
+        type Kind1 = MeasuredKind<U>;
+    }
+
+    impl<U, A> Functor<A> for Measured<U, A> {
+        fn fmap<B, F: FnOnce(A) -> B>(self, f: F) -> Measured<U, B> {
+            self.map_value(f)
+        }
+    }
+
+    impl<U, A> Applicative<A> for Measured<U, A> {
+        fn pure(a: A) -> Measured<U, A> {
+            Measured::new(a)
+        }
+
+        fn apply<B, F: FnOnce(A) -> B>(self, ff: Measured<U, F>) -> Measured<U, B> {
+            Measured::new((ff.value)(self.value))
+        }
+    }
+}
+
+#[cfg(test)]
+mod measured_tests {
+    use super::measured_impls::*;
+    use crate::*;
+
+    struct Seconds;
+    struct Millis;
+
+    fn secs(x: f64) -> Measured<Seconds, f64> {
+        Measured::new(x)
+    }
+
+    #[test]
+    fn map_value_keeps_unit() {
+        let mapped: Measured<Seconds, f64> = secs(1.5).map_value(|s| s * 2.0);
+        assert_eq!(mapped, secs(3.0));
+    }
+
+    #[test]
+    fn retag() {
+        let ms: Measured<Millis, f64> = secs(1.5).map_value(|s| s * 1000.0).retag();
+        assert_eq!(ms.into_value(), 1500.0);
+    }
+
+    #[test]
+    fn arithmetic_same_unit() {
+        assert_eq!(secs(1.5) + secs(2.0), secs(3.5));
+        assert_eq!(secs(1.5) - secs(2.0), secs(-0.5));
+        assert!(secs(1.0) < secs(2.0));
+    }
+
+    mod functor {
+        use super::*;
+
+        #[test]
+        fn fmap() {
+            let rounded: Measured<Seconds, i64> = secs(2.6).fmap(|s| s.round() as i64);
+            assert_eq!(rounded.value(), &3);
+        }
+
+        #[test]
+        fn identity_law() {
+            assert_eq!(secs(2.5).fmap(identity), secs(2.5));
+        }
+
+        #[test]
+        fn composition_law() {
+            let m = Measured::<Seconds, i32>::new(5);
+            assert_eq!(
+                m.fmap(|x| multiply_by_two(add_one(x))),
+                m.fmap(add_one).fmap(multiply_by_two)
+            );
+        }
+    }
+
+    mod applicative {
+        use super::*;
+
+        #[test]
+        fn apply() {
+            let ff = Measured::<Seconds, _>::pure(|s: f64| s / 2.0);
+            assert_eq!(secs(3.0).apply(ff), secs(1.5));
+        }
+
+        #[test]
+        fn identity_law() {
+            let v = Measured::<Seconds, i32>::new(5);
+            assert_eq!(v.apply(Measured::pure(identity)), v);
+        }
+
+        #[test]
+        fn homomorphism_law() {
+            let lhs = Measured::<Seconds, i32>::pure(5).apply(Measured::pure(add_one));
+            assert_eq!(lhs, Measured::pure(add_one(5)));
+        }
+    }
+}
@@ -5,16 +5,119 @@
 //! - `Functor` - for mapping over contained values
 //! - `Applicative` - for applying functions wrapped in a context to values in
 //!   the same context
+//! - `Contravariant` - for adapting the input of consumers
+//! - `Semigroup` and `Monoid` - for combining values
+//! - `Bifunctor` - for mapping over both sides of two-parameter types
 //!
 //! Implementations are provided for common types:
 //! - `Option<T>`
-//! - `Result<T, E>`
+//! - `Result<T, E>`, with `AnyResult<T>` helpers for boxed errors and
+//!   `ApiResult<T>` helpers for coded errors
+//! - `CodeResult<T, CODE>`, a result whose error is a const code
 //! - `Vec<T>`
+//! - `Box<[T]>`
+//! - `LinkedList<T>`
+//! - `AssocList<K, T, N>`, a map of key-value pairs for `no_std`
+//! - `BinaryHeap<T>`, with `fmap` via `BinaryHeapExt`
+//! - `SmallVec<[T; N]>`, with the `smallvec` feature
+//! - `InlineVec<T, N>`, a fixed-capacity vector for `no_std`
+//! - `Text`, a fixed-capacity string for `no_std`, via `map_chars`
+//! - `Tree<T>`
+//! - `[T; N]`
+//! - `(A, B)`
+//! - `Reverse<T>`
+//! - `Poll<Option<T>>`, the item shape of `Stream::poll_next`
+//! - `OnceCell<T>` and `OnceLock<T>`, lazily via `OnceCellExt` and
+//!   `OnceLockExt`
+//! - `Measured<U, T>`, a value tagged with a phantom unit
+//! - `Signed<S, T>`, a value tagged with its sign
+//! - `Fixed<SCALE>`, an exact decimal, via `map` over its raw units
+//! - `Sink<T>`
+//! - `Checked<T>` over the `NonZero*` integers
+//! - `Counter<T>`
+//! - `ParserState<'a, A, P>`, an allocation-free parser over `&str`
+//! - `Context<T, E>`
+//! - `Fallible<T, E>`, a result with a backtrace, with the `backtrace` feature
+//! - `Weighted<T>`, a discrete probability distribution
+//! - `Outcome<T, W, E>`, a success with accumulated warnings or a failure
+//! - `First<T>`, `Last<T>`, `Min<T>`, `Max<T>`, `All`, `Any`, `Stats<T>` and
+//!   `()`
+//! - `Const<M, T>`, which carries a monoid and ignores its values
+//! - any `Iterator`, via `IteratorFunctor`, and lazily as a monad via
+//!   `RangeGen`
+//! - any functor nested in another, via `NestedFunctor`
+//! - the composition of two functors, via `ComposeF<F, G, T>`
+//! - the product of two functors, via `Product<F, G, T>`
+//!
+//! The `transformers` module provides `ResultT` and `OptionT`, which stack
+//! `Result` and `Option` on top of another monad, with `MonadTrans` lifting
+//! base-monad actions into either. The `Transpose` trait swaps the layers of
+//! nested `Vec`s, `Option`s and `Result`s. `build_with` and
+//! `validate_struct!` assemble a value from fallible fields, collecting every
+//! field's error.
 //!
 //! Each implementation comes with test cases validating both the basic
 //! functionality and the typeclass laws (identity, composition, homomorphism,
 //! etc).
 
+pub mod any_result;
+pub mod api_result;
+pub mod array;
+pub mod assoc_list;
+pub mod binary_heap;
+pub mod boxed_slice;
+pub mod checked;
+pub mod clamped;
+pub mod code_result;
+pub mod compose;
+pub mod constant;
+pub mod context;
+pub mod counter;
+pub mod fallible;
+pub mod fixed;
+pub mod future;
+pub mod inline_vec;
+pub mod iterator;
+pub mod linked_list;
+pub mod measured;
+pub mod monoid;
+pub mod nested;
+pub mod once_cell;
 pub mod option;
+pub mod outcome;
+pub mod parser_state;
+pub mod poll;
+pub mod product;
+pub mod range_gen;
 pub mod result;
+pub mod reverse;
+pub mod signed;
+pub mod sink;
+pub mod small_vec;
+pub mod text;
+pub mod transformers;
+pub mod transpose;
+pub mod tree;
+pub mod tuple;
+pub mod validate;
 pub mod vec;
+pub mod weighted;
+
+#[cfg(not(feature = "no_std"))]
+pub use any_result::any_result_impls::{AnyResult, AnyResultExt, from_err};
+#[cfg(not(feature = "no_std"))]
+pub use api_result::api_result_impls::{ApiError, ApiResult, ApiResultExt};
+#[cfg(not(feature = "no_std"))]
+pub use binary_heap::binary_heap_impls::BinaryHeapExt;
+pub use nested::nested_impls::NestedFunctor;
+pub use once_cell::once_cell_impls::{MappedCell, OnceCellExt};
+#[cfg(not(feature = "no_std"))]
+pub use once_cell::once_cell_impls::{MappedLock, OnceLockExt};
+pub use result::result_impls::ResultExt;
+pub use transformers::transformers_impls::MonadTrans;
+#[cfg(not(feature = "no_std"))]
+pub use transpose::transpose_impls::Transpose;
+#[cfg(not(feature = "no_std"))]
+pub use validate::validate_impls::{ValidateFields, build_with};
+#[cfg(not(feature = "no_std"))]
+pub use vec::vec_impls::VecExt;
@@ -0,0 +1,513 @@
+pub mod monoid_impls {
+    use crate::*;
+
+    /// A monoid keeping the first present value.
+    ///
+    /// Combining two `First` values keeps the left one unless it is `None`, so
+    /// combining a sequence yields its first `Some`. The identity is
+    /// `First(None)`.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::*;
+    /// use crab_fp::monoid::monoid_impls::First;
+    ///
+    /// let first = First(None).combine(First(Some(1))).combine(First(Some(2)));
+    /// assert_eq!(first, First(Some(1)));
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct First<A>(pub Option<A>);
+
+    impl<A> Semigroup for First<A> {
+        fn combine(self, other: Self) -> Self {
+            match self.0 {
+                Some(_) => self,
+                None => other,
+            }
+        }
+    }
+
+    impl<A> Monoid for First<A> {
+        fn empty() -> Self {
+            First(None)
+        }
+    }
+
+    /// A monoid keeping the last present value.
+    ///
+    /// Combining two `Last` values keeps the right one unless it is `None`, so
+    /// combining a sequence yields its last `Some`. The identity is
+    /// `Last(None)`.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::*;
+    /// use crab_fp::monoid::monoid_impls::Last;
+    ///
+    /// let last = Last(Some(1)).combine(Last(Some(2))).combine(Last(None));
+    /// assert_eq!(last, Last(Some(2)));
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Last<A>(pub Option<A>);
+
+    impl<A> Semigroup for Last<A> {
+        fn combine(self, other: Self) -> Self {
+            match other.0 {
+                Some(_) => other,
+                None => self,
+            }
+        }
+    }
+
+    impl<A> Monoid for Last<A> {
+        fn empty() -> Self {
+            Last(None)
+        }
+    }
+    impl Semigroup for () {
+        fn combine(self, _other: Self) -> Self {}
+    }
+
+    impl Monoid for () {
+        fn empty() -> Self {}
+    }
+
+    /// The boolean monoid under conjunction.
+    ///
+    /// Combining two `All` values is `&&`, and the identity is `All(true)`, so
+    /// combining a sequence checks that every value is `true`.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::*;
+    /// use crab_fp::monoid::monoid_impls::All;
+    ///
+    /// assert_eq!(All(true).combine(All(false)), All(false));
+    /// assert_eq!(All::empty(), All(true));
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct All(pub bool);
+
+    impl Semigroup for All {
+        fn combine(self, other: Self) -> Self {
+            All(self.0 && other.0)
+        }
+    }
+
+    impl Monoid for All {
+        fn empty() -> Self {
+            All(true)
+        }
+    }
+
+    /// The boolean monoid under disjunction.
+    ///
+    /// Combining two `Any` values is `||`, and the identity is `Any(false)`, so
+    /// combining a sequence checks that at least one value is `true`.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::*;
+    /// use crab_fp::monoid::monoid_impls::Any;
+    ///
+    /// assert_eq!(Any(false).combine(Any(true)), Any(true));
+    /// assert_eq!(Any::empty(), Any(false));
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Any(pub bool);
+
+    impl Semigroup for Any {
+        fn combine(self, other: Self) -> Self {
+            Any(self.0 || other.0)
+        }
+    }
+
+    impl Monoid for Any {
+        fn empty() -> Self {
+            Any(false)
+        }
+    }
+
+    /// Types with a smallest and a largest value.
+    ///
+    /// Used as the identity elements of the `Max` and `Min` monoids.
+    pub trait Bounded {
+        const MIN: Self;
+        const MAX: Self;
+    }
+
+    macro_rules! impl_bounded {
+        ($($t:ty),*) => {$(
+            impl Bounded for $t {
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+            }
+        )*};
+    }
+
+    impl_bounded!(
+        i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, char
+    );
+
+    /// A semigroup keeping the smaller of two values.
+    ///
+    /// When the values are bounded, `Min` is also a monoid whose identity is
+    /// the largest value of the type.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::*;
+    /// use crab_fp::monoid::monoid_impls::Min;
+    ///
+    /// let lowest = [Min(3), Min(1), Min(2)].into_iter().fold(Min::empty(), Min::combine);
+    /// assert_eq!(lowest, Min(1));
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct Min<A>(pub A);
+
+    impl<A: Ord> Semigroup for Min<A> {
+        fn combine(self, other: Self) -> Self {
+            Min(self.0.min(other.0))
+        }
+    }
+
+    impl<A: Ord + Bounded> Monoid for Min<A> {
+        fn empty() -> Self {
+            Min(A::MAX)
+        }
+    }
+
+    /// A semigroup keeping the larger of two values.
+    ///
+    /// When the values are bounded, `Max` is also a monoid whose identity is
+    /// the smallest value of the type.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::*;
+    /// use crab_fp::monoid::monoid_impls::Max;
+    ///
+    /// let highest = [Max(3), Max(1), Max(2)].into_iter().fold(Max::empty(), Max::combine);
+    /// assert_eq!(highest, Max(3));
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct Max<A>(pub A);
+
+    impl<A: Ord> Semigroup for Max<A> {
+        fn combine(self, other: Self) -> Self {
+            Max(self.0.max(other.0))
+        }
+    }
+
+    impl<A: Ord + Bounded> Monoid for Max<A> {
+        fn empty() -> Self {
+            Max(A::MIN)
+        }
+    }
+
+    /// Summary statistics gathered in a single pass.
+    ///
+    /// `Stats::of` describes one value, and combining two `Stats` adds their
+    /// counts and sums and keeps the smaller minimum and the larger maximum.
+    /// The identity has a count and sum of zero and no minimum or maximum, so
+    /// `fold_map(Stats::of)` computes all four statistics at once.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::*;
+    /// use crab_fp::monoid::monoid_impls::Stats;
+    ///
+    /// #[cfg(not(feature = "no_std"))]
+    /// {
+    ///     let stats = vec![3, 1, 2].fold_map(Stats::of);
+    ///     assert_eq!((stats.count, stats.sum, stats.min, stats.max), (3, 6, Some(1), Some(3)));
+    /// }
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Stats<A> {
+        pub count: usize,
+        pub sum: A,
+        pub min: Option<A>,
+        pub max: Option<A>,
+    }
+
+    impl<A: Clone> Stats<A> {
+        /// The statistics of a single value.
+        pub fn of(a: A) -> Self {
+            Stats {
+                count: 1,
+                sum: a.clone(),
+                min: Some(a.clone()),
+                max: Some(a),
+            }
+        }
+    }
+
+    impl<A: Ord + std::ops::Add<Output = A>> Semigroup for Stats<A> {
+        fn combine(self, other: Self) -> Self {
+            let pick = |a: Option<A>, b: Option<A>, f: fn(A, A) -> A| match (a, b) {
+                (Some(a), Some(b)) => Some(f(a, b)),
+                (a, None) => a,
+                (None, b) => b,
+            };
+            Stats {
+                count: self.count + other.count,
+                sum: self.sum + other.sum,
+                min: pick(self.min, other.min, Ord::min),
+                max: pick(self.max, other.max, Ord::max),
+            }
+        }
+    }
+
+    impl<A: Ord + std::ops::Add<Output = A> + Default> Monoid for Stats<A> {
+        fn empty() -> Self {
+            Stats {
+                count: 0,
+                sum: A::default(),
+                min: None,
+                max: None,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod monoid_tests {
+    use super::monoid_impls::*;
+    use crate::*;
+
+    mod first {
+        use super::*;
+
+        #[test]
+        fn combine() {
+            let values = [First(None), First(Some(1)), First(None), First(Some(2))];
+            let first = values.into_iter().fold(First::empty(), First::combine);
+            assert_eq!(first, First(Some(1)));
+
+            let values: [First<i32>; 2] = [First(None), First(None)];
+            let first = values.into_iter().fold(First::empty(), First::combine);
+            assert_eq!(first, First(None));
+        }
+
+        #[test]
+        fn associativity_law() {
+            let (a, b, c) = (First(None), First(Some(1)), First(Some(2)));
+            assert_eq!(a.combine(b).combine(c), a.combine(b.combine(c)));
+        }
+
+        #[test]
+        fn identity_laws() {
+            for a in [First(Some(1)), First(None)] {
+                assert_eq!(First::empty().combine(a), a);
+                assert_eq!(a.combine(First::empty()), a);
+            }
+        }
+    }
+
+    mod last {
+        use super::*;
+
+        #[test]
+        fn combine() {
+            let values = [Last(Some(1)), Last(None), Last(Some(2)), Last(None)];
+            let last = values.into_iter().fold(Last::empty(), Last::combine);
+            assert_eq!(last, Last(Some(2)));
+
+            let values: [Last<i32>; 2] = [Last(None), Last(None)];
+            let last = values.into_iter().fold(Last::empty(), Last::combine);
+            assert_eq!(last, Last(None));
+        }
+
+        #[test]
+        fn associativity_law() {
+            let (a, b, c) = (Last(Some(1)), Last(Some(2)), Last(None));
+            assert_eq!(a.combine(b).combine(c), a.combine(b.combine(c)));
+        }
+
+        #[test]
+        fn identity_laws() {
+            for a in [Last(Some(1)), Last(None)] {
+                assert_eq!(Last::empty().combine(a), a);
+                assert_eq!(a.combine(Last::empty()), a);
+            }
+        }
+    }
+
+    fn check_monoid_laws<M: Monoid + Clone + PartialEq + ::core::fmt::Debug>(a: M, b: M, c: M) {
+        // Associativity: (a <> b) <> c = a <> (b <> c)
+        assert_eq!(
+            a.clone().combine(b.clone()).combine(c.clone()),
+            a.clone().combine(b.combine(c))
+        );
+        // Left identity: empty <> a = a
+        assert_eq!(M::empty().combine(a.clone()), a);
+        // Right identity: a <> empty = a
+        assert_eq!(a.clone().combine(M::empty()), a);
+    }
+
+    mod unit {
+        use super::*;
+
+        #[test]
+        fn laws() {
+            check_monoid_laws((), (), ());
+        }
+    }
+
+    mod all {
+        use super::*;
+
+        #[test]
+        fn combine() {
+            assert_eq!(All(true).combine(All(true)), All(true));
+            assert_eq!(All(true).combine(All(false)), All(false));
+            assert_eq!(All(false).combine(All(true)), All(false));
+        }
+
+        #[test]
+        fn laws() {
+            for (a, b, c) in [(true, true, false), (true, false, true), (true, true, true)] {
+                check_monoid_laws(All(a), All(b), All(c));
+            }
+        }
+    }
+
+    mod any {
+        use super::*;
+
+        #[test]
+        fn combine() {
+            assert_eq!(Any(false).combine(Any(false)), Any(false));
+            assert_eq!(Any(true).combine(Any(false)), Any(true));
+            assert_eq!(Any(false).combine(Any(true)), Any(true));
+        }
+
+        #[test]
+        fn laws() {
+            for (a, b, c) in [
+                (false, false, true),
+                (true, false, false),
+                (false, false, false),
+            ] {
+                check_monoid_laws(Any(a), Any(b), Any(c));
+            }
+        }
+    }
+
+    mod min {
+        use super::*;
+
+        #[test]
+        fn combine() {
+            let lowest = [Min(5), Min(-3), Min(8), Min(0)]
+                .into_iter()
+                .fold(Min::empty(), Min::combine);
+            assert_eq!(lowest, Min(-3));
+        }
+
+        #[test]
+        fn empty_sequence_is_identity() {
+            let lowest = [].into_iter().fold(Min::empty(), Min::combine);
+            assert_eq!(lowest, Min(u8::MAX));
+        }
+
+        #[test]
+        fn laws() {
+            let (a, b, c) = (Min(3), Min(1), Min(2));
+            assert_eq!(a.combine(b).combine(c), a.combine(b.combine(c)));
+            assert_eq!(Min::empty().combine(a), a);
+            assert_eq!(a.combine(Min::empty()), a);
+        }
+
+        #[test]
+        fn semigroup_without_bounds() {
+            assert_eq!(Min("pear").combine(Min("apple")), Min("apple"));
+        }
+    }
+
+    mod max {
+        use super::*;
+
+        #[test]
+        fn combine() {
+            let highest = [Max(5), Max(-3), Max(8), Max(0)]
+                .into_iter()
+                .fold(Max::empty(), Max::combine);
+            assert_eq!(highest, Max(8));
+        }
+
+        #[test]
+        fn empty_sequence_is_identity() {
+            let highest = [].into_iter().fold(Max::empty(), Max::combine);
+            assert_eq!(highest, Max(i64::MIN));
+        }
+
+        #[test]
+        fn laws() {
+            let (a, b, c) = (Max('b'), Max('z'), Max('a'));
+            assert_eq!(a.combine(b).combine(c), a.combine(b.combine(c)));
+            assert_eq!(Max::empty().combine(a), a);
+            assert_eq!(a.combine(Max::empty()), a);
+        }
+    }
+
+    mod stats {
+        use super::*;
+
+        /// A small linear congruential generator, so the test data is
+        /// arbitrary but reproducible.
+        fn pseudo_random(seed: u64, len: usize) -> impl Iterator<Item = i64> {
+            let mut state = seed;
+            (0..len).map(move |_| {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                (state >> 33) as i64 % 1000 - 500
+            })
+        }
+
+        #[test]
+        fn single_pass_matches_separate_passes() {
+            let values = || pseudo_random(42, 200);
+            let stats = values().fold(Stats::empty(), |acc, x| acc.combine(Stats::of(x)));
+            assert_eq!(stats.count, values().count());
+            assert_eq!(stats.sum, values().sum::<i64>());
+            assert_eq!(stats.min, values().min());
+            assert_eq!(stats.max, values().max());
+        }
+
+        #[test]
+        #[cfg(not(feature = "no_std"))]
+        fn fold_map() {
+            let stats = vec![4, -2, 9, 0].fold_map(Stats::of);
+            assert_eq!(
+                stats,
+                Stats {
+                    count: 4,
+                    sum: 11,
+                    min: Some(-2),
+                    max: Some(9)
+                }
+            );
+        }
+
+        #[test]
+        #[cfg(not(feature = "no_std"))]
+        fn empty_sequence() {
+            let stats = Vec::<i32>::new().fold_map(Stats::of);
+            assert_eq!(stats, Stats::empty());
+            assert_eq!((stats.count, stats.min, stats.max), (0, None, None));
+        }
+
+        #[test]
+        fn laws() {
+            check_monoid_laws(Stats::of(3), Stats::of(-1), Stats::empty());
+            check_monoid_laws(
+                Stats::of(3).combine(Stats::of(8)),
+                Stats::of(5),
+                Stats::of(5),
+            );
+        }
+    }
+}
@@ -0,0 +1,86 @@
+pub mod nested_impls {
+    use crate::*;
+
+    /// An extension trait for mapping through two layers of functors at once.
+    ///
+    /// Any functor whose contents are themselves a functor gains `fmap_inner`,
+    /// which maps the innermost values while leaving both layers of structure
+    /// as they were. It is `fmap` composed with `fmap`.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::NestedFunctor;
+    ///
+    /// #[cfg(not(feature = "no_std"))]
+    /// assert_eq!(vec![Some(1), None].fmap_inner(|x| x + 1), vec![Some(2), None]);
+    /// assert_eq!(Some(Ok::<_, ()>(1)).fmap_inner(|x| x * 10), Some(Ok(10)));
+    /// ```
+    pub trait NestedFunctor<G: Functor<A>, A>: Functor<G> + Sized {
+        /// Maps a function over the values inside the inner functor.
+        ///
+        /// # Parameters
+        /// * `f` - A function that transforms values of type `A` into values of type `B`
+        ///
+        /// # Returns
+        /// The same nested structure with every inner value transformed.
+        fn fmap_inner<B, F: FnMut(A) -> B>(
+            self,
+            mut f: F,
+        ) -> Apply1<Self::Kind1, Apply1<G::Kind1, B>> {
+            self.fmap(|g| g.fmap(&mut f))
+        }
+    }
+
+    impl<A, G: Functor<A>, T: Functor<G>> NestedFunctor<G, A> for T {}
+}
+
+#[cfg(test)]
+mod nested_tests {
+    use crate::*;
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn vec_of_options() {
+        let v = vec![Some(1), None, Some(3)];
+        assert_eq!(v.fmap_inner(add_one), vec![Some(2), None, Some(4)]);
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn option_of_vec() {
+        assert_eq!(
+            Some(vec![1, 2]).fmap_inner(to_string),
+            Some(vec!["1".to_string(), "2".to_string()])
+        );
+        assert_eq!(None::<Vec<i32>>.fmap_inner(to_string), None);
+    }
+
+    #[test]
+    fn option_of_result() {
+        let ok: Option<Result<i32, &str>> = Some(Ok(3));
+        assert_eq!(ok.fmap_inner(square), Some(Ok(9)));
+
+        let err: Option<Result<i32, &str>> = Some(Err("bad"));
+        assert_eq!(err.fmap_inner(square), Some(Err("bad")));
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn stateful_function_sees_every_inner_value() {
+        let mut seen = vec![];
+        let v = vec![vec![1, 2], vec![], vec![3]];
+        let mapped = v.fmap_inner(|x| {
+            seen.push(x);
+            x * 2
+        });
+        assert_eq!(mapped, vec![vec![2, 4], vec![], vec![6]]);
+        assert_eq!(seen, vec![1, 2, 3]);
+    }
+
+    // Identity: fmap_inner id = id
+    #[test]
+    fn identity_law() {
+        let x: Option<Option<i32>> = Some(Some(5));
+        assert_eq!(x.fmap_inner(identity), x);
+    }
+}
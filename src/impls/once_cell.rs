@@ -0,0 +1,249 @@
+pub mod once_cell_impls {
+    use core::cell::OnceCell;
+    #[cfg(not(feature = "no_std"))]
+    use std::sync::OnceLock;
+
+    /// A lazily mapped view of a `OnceCell`.
+    ///
+    /// Created by [`OnceCellExt::fmap`]. The function runs the first time
+    /// [`get`](MappedCell::get) is called after the source cell has been
+    /// initialized, and its result is cached in a cell of its own.
+    pub struct MappedCell<'a, A, B, F> {
+        source: &'a OnceCell<A>,
+        cell: OnceCell<B>,
+        f: F,
+    }
+
+    impl<'a, A, B, F> MappedCell<'a, A, B, F> {
+        /// Creates a view of `source` that maps its value with `f`.
+        pub const fn new(source: &'a OnceCell<A>, f: F) -> Self {
+            MappedCell {
+                source,
+                cell: OnceCell::new(),
+                f,
+            }
+        }
+    }
+
+    impl<A, B, F: Fn(&A) -> B> MappedCell<'_, A, B, F> {
+        /// Returns the mapped value, or `None` while the source cell is still
+        /// empty.
+        ///
+        /// The function is called at most once; later calls return the
+        /// cached result.
+        pub fn get(&self) -> Option<&B> {
+            let a = self.source.get()?;
+            Some(self.cell.get_or_init(|| (self.f)(a)))
+        }
+    }
+
+    /// Lazy mapping support for `OnceCell`.
+    ///
+    /// `OnceCell` cannot be a `Functor`: its value is only ever borrowed, and
+    /// mapping it into another `OnceCell` would have to run the function
+    /// before the source is necessarily initialized. This extension trait
+    /// instead returns a [`MappedCell`] that defers the function until the
+    /// mapped value is first read.
+    pub trait OnceCellExt<A> {
+        /// Maps a function over the cell's value once it is available.
+        ///
+        /// # Example
+        /// ```
+        /// use core::cell::OnceCell;
+        /// use crab_fp::OnceCellExt;
+        ///
+        /// let cell = OnceCell::new();
+        /// let doubled = cell.fmap(|x: &i32| x * 2);
+        /// assert_eq!(doubled.get(), None);
+        ///
+        /// cell.set(21).unwrap();
+        /// assert_eq!(doubled.get(), Some(&42));
+        /// ```
+        fn fmap<B, F: Fn(&A) -> B>(&self, f: F) -> MappedCell<'_, A, B, F>;
+    }
+
+    impl<A> OnceCellExt<A> for OnceCell<A> {
+        fn fmap<B, F: Fn(&A) -> B>(&self, f: F) -> MappedCell<'_, A, B, F> {
+            MappedCell::new(self, f)
+        }
+    }
+
+    /// A lazily mapped view of a `OnceLock`.
+    ///
+    /// The thread-safe counterpart of [`MappedCell`], created by
+    /// [`OnceLockExt::fmap`] or, for statics, by [`MappedLock::new`]. Threads
+    /// racing on the first [`get`](MappedLock::get) still run the function
+    /// only once.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::OnceLock;
+    /// use crab_fp::MappedLock;
+    ///
+    /// static PORT: OnceLock<u16> = OnceLock::new();
+    /// static ADDR: MappedLock<u16, String, fn(&u16) -> String> =
+    ///     MappedLock::new(&PORT, |port| format!("127.0.0.1:{port}"));
+    ///
+    /// PORT.set(8080).unwrap();
+    /// assert_eq!(ADDR.get().map(String::as_str), Some("127.0.0.1:8080"));
+    /// ```
+    #[cfg(not(feature = "no_std"))]
+    pub struct MappedLock<'a, A, B, F> {
+        source: &'a OnceLock<A>,
+        cell: OnceLock<B>,
+        f: F,
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    impl<'a, A, B, F> MappedLock<'a, A, B, F> {
+        /// Creates a view of `source` that maps its value with `f`.
+        pub const fn new(source: &'a OnceLock<A>, f: F) -> Self {
+            MappedLock {
+                source,
+                cell: OnceLock::new(),
+                f,
+            }
+        }
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    impl<A, B, F: Fn(&A) -> B> MappedLock<'_, A, B, F> {
+        /// Returns the mapped value, or `None` while the source lock is still
+        /// empty.
+        ///
+        /// The function is called at most once; later calls return the
+        /// cached result.
+        pub fn get(&self) -> Option<&B> {
+            let a = self.source.get()?;
+            Some(self.cell.get_or_init(|| (self.f)(a)))
+        }
+    }
+
+    /// Lazy mapping support for `OnceLock`, for lazily transformed globals.
+    ///
+    /// See [`OnceCellExt`] for why this is not a `Functor`.
+    #[cfg(not(feature = "no_std"))]
+    pub trait OnceLockExt<A> {
+        /// Maps a function over the lock's value once it is available.
+        ///
+        /// # Example
+        /// ```
+        /// use std::sync::OnceLock;
+        /// use crab_fp::OnceLockExt;
+        ///
+        /// let lock = OnceLock::new();
+        /// let label = lock.fmap(|n: &u32| format!("#{n}"));
+        /// assert_eq!(label.get(), None);
+        ///
+        /// lock.set(7).unwrap();
+        /// assert_eq!(label.get().map(String::as_str), Some("#7"));
+        /// ```
+        fn fmap<B, F: Fn(&A) -> B>(&self, f: F) -> MappedLock<'_, A, B, F>;
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    impl<A> OnceLockExt<A> for OnceLock<A> {
+        fn fmap<B, F: Fn(&A) -> B>(&self, f: F) -> MappedLock<'_, A, B, F> {
+            MappedLock::new(self, f)
+        }
+    }
+}
+
+#[cfg(test)]
+mod once_cell_tests {
+    use crate::*;
+    use ::core::cell::{Cell, OnceCell};
+
+    mod once_cell {
+        use super::*;
+
+        #[test]
+        fn empty_until_source_initialized() {
+            let calls = Cell::new(0);
+            let cell = OnceCell::new();
+            let mapped = cell.fmap(|x: &i32| {
+                calls.set(calls.get() + 1);
+                square(*x)
+            });
+            assert_eq!(mapped.get(), None);
+            assert_eq!(calls.get(), 0);
+
+            cell.set(7).unwrap();
+            assert_eq!(calls.get(), 0);
+            assert_eq!(mapped.get(), Some(&49));
+            assert_eq!(calls.get(), 1);
+        }
+
+        #[test]
+        fn maps_only_once() {
+            let calls = Cell::new(0);
+            let cell = OnceCell::new();
+            cell.set(5).unwrap();
+            let mapped = cell.fmap(|x: &i32| {
+                calls.set(calls.get() + 1);
+                add_one(*x)
+            });
+            assert_eq!(mapped.get(), Some(&6));
+            assert_eq!(mapped.get(), Some(&6));
+            assert_eq!(calls.get(), 1);
+        }
+
+        #[test]
+        fn agrees_with_option_fmap() {
+            let cell = OnceCell::new();
+            cell.set(3).unwrap();
+            let mapped = cell.fmap(|x: &i32| multiply_by_two(*x));
+            assert_eq!(
+                mapped.get().copied(),
+                cell.get().copied().fmap(multiply_by_two)
+            );
+        }
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    mod once_lock {
+        use super::*;
+        use std::sync::OnceLock;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::thread;
+
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+        static SOURCE: OnceLock<i32> = OnceLock::new();
+        static MAPPED: MappedLock<i32, String, fn(&i32) -> String> =
+            MappedLock::new(&SOURCE, |x| {
+                CALLS.fetch_add(1, Ordering::SeqCst);
+                to_string(*x)
+            });
+
+        #[test]
+        fn static_maps_once_across_threads() {
+            assert_eq!(MAPPED.get(), None);
+            assert_eq!(CALLS.load(Ordering::SeqCst), 0);
+
+            SOURCE.set(42).unwrap();
+            let handles: Vec<_> = (0..4)
+                .map(|_| thread::spawn(|| MAPPED.get().cloned()))
+                .collect();
+            for handle in handles {
+                assert_eq!(handle.join().unwrap(), Some(to_string(42)));
+            }
+            assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+        }
+
+        #[test]
+        fn fmap_defers_until_initialized() {
+            let calls = AtomicUsize::new(0);
+            let lock = OnceLock::new();
+            let mapped = lock.fmap(|x: &i32| {
+                calls.fetch_add(1, Ordering::SeqCst);
+                square(*x)
+            });
+            assert_eq!(mapped.get(), None);
+
+            lock.set(4).unwrap();
+            assert_eq!(mapped.get(), Some(&16));
+            assert_eq!(mapped.get(), Some(&16));
+            assert_eq!(calls.load(Ordering::SeqCst), 1);
+        }
+    }
+}
@@ -1,5 +1,6 @@
 pub mod option_impls {
     use crate::*;
+    use std::ops::ControlFlow;
 
     pub struct OptionKind;
 
@@ -12,11 +13,26 @@ pub mod option_impls {
     }
 
     impl<A> Functor<A> for Option<A> {
+        /// Calls `f` at most once: for `Some`, and never for `None`.
         fn fmap<B, F: FnOnce(A) -> B>(self, f: F) -> Option<B> {
             self.map(f)
         }
     }
 
+    impl Ref1 for OptionKind {
+        fn fmap_ref<'a, A: 'a, B, M: FnMut(&'a A) -> B>(fa: &'a Option<A>, f: M) -> Option<B> {
+            fa.as_ref().map(f)
+        }
+    }
+
+    impl<A> FunctorMut<A> for Option<A> {
+        fn fmap_mut<F: FnMut(&mut A)>(&mut self, mut f: F) {
+            if let Some(a) = self {
+                f(a);
+            }
+        }
+    }
+
     impl<A> Applicative<A> for Option<A> {
         fn pure(b: A) -> Option<A> {
             Some(b)
@@ -34,6 +50,66 @@ pub mod option_impls {
             self.and_then(f)
         }
     }
+
+    impl<A> ShortCircuit<A> for Option<A> {
+        type Residual = ();
+
+        fn branch(self) -> ControlFlow<(), A> {
+            match self {
+                Some(a) => ControlFlow::Continue(a),
+                None => ControlFlow::Break(()),
+            }
+        }
+
+        fn from_residual((): ()) -> Option<A> {
+            None
+        }
+    }
+
+    /// Keeps the first `Some`.
+    impl<A> Alternative<A> for Option<A> {
+        fn nothing() -> Option<A> {
+            None
+        }
+
+        fn alt(self, other: Option<A>) -> Option<A> {
+            self.or(other)
+        }
+    }
+
+    /// Combines the contained values when both sides are `Some`, otherwise
+    /// keeps whichever side is present.
+    impl<A: Semigroup> Semigroup for Option<A> {
+        fn combine(self, other: Self) -> Self {
+            match (self, other) {
+                (Some(a), Some(b)) => Some(a.combine(b)),
+                (Some(a), None) => Some(a),
+                (None, b) => b,
+            }
+        }
+    }
+
+    impl<A: Semigroup> Monoid for Option<A> {
+        fn empty() -> Self {
+            None
+        }
+    }
+
+    impl<A> Foldable<A> for Option<A> {
+        fn fold_left<B, F: FnOnce(B, A) -> B>(self, init: B, f: F) -> B {
+            match self {
+                Some(a) => f(init, a),
+                None => init,
+            }
+        }
+
+        fn fold_right<B, F: FnOnce(A, B) -> B>(self, init: B, f: F) -> B {
+            match self {
+                Some(a) => f(a, init),
+                None => init,
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -55,6 +131,80 @@ mod option_tests {
             let opt2 = opt1.fmap(|x| x + 1);
             assert_eq!(opt2, None);
         }
+
+        #[test]
+        fn fmap_calls_closure_at_most_once() {
+            let mut calls = 0;
+            let some = Some(5).fmap(|x| {
+                calls += 1;
+                x * calls
+            });
+            assert_eq!(some, Some(5));
+            assert_eq!(calls, 1);
+
+            let none: Option<i32> = None.fmap(|x: i32| {
+                calls += 1;
+                x
+            });
+            assert_eq!(none, None);
+            assert_eq!(calls, 1);
+        }
+    }
+
+    mod fmap_mut {
+        use super::*;
+
+        #[test]
+        fn some_mutated_in_place() {
+            let mut opt = Some(to_string(5));
+            let before = opt.as_ref().map(|s| s.as_ptr());
+            opt.fmap_mut(|s| s.push('!'));
+            assert_eq!(opt.as_deref(), Some("5!"));
+            assert_eq!(opt.as_ref().map(|s| s.as_ptr()), before);
+        }
+
+        #[test]
+        fn none_untouched() {
+            let mut opt: Option<i32> = None;
+            opt.fmap_mut(|_| panic!("called on None"));
+            assert_eq!(opt, None);
+        }
+
+        #[test]
+        fn agrees_with_fmap() {
+            let mut opt = Some(7);
+            opt.fmap_mut(|x| *x = square(*x));
+            assert_eq!(opt, Some(7).fmap(square));
+        }
+    }
+
+    mod ref1 {
+        use super::*;
+        use crate::option::option_impls::OptionKind;
+
+        fn describe<F: Ref1>(data: &Apply1<F, i32>) -> Apply1<F, String> {
+            F::fmap_ref(data, |x| to_string(*x))
+        }
+
+        #[test]
+        fn fmap_ref() {
+            let opt = Some(5);
+            assert_eq!(OptionKind::fmap_ref(&opt, |x| x + 1), Some(6));
+            assert_eq!(OptionKind::fmap_ref(&None::<i32>, |x| x + 1), None);
+        }
+
+        #[test]
+        fn generic_over_kind_leaves_original() {
+            let opt = Some(42);
+            assert_eq!(describe::<OptionKind>(&opt), Some(to_string(42)));
+            assert_eq!(opt, Some(42));
+        }
+
+        #[test]
+        fn matches_fmap() {
+            let opt = Some(7);
+            assert_eq!(OptionKind::fmap_ref(&opt, |x| square(*x)), opt.fmap(square));
+        }
     }
 
     mod applicative {
@@ -214,4 +364,103 @@ mod option_tests {
             assert_eq!(result, None);
         }
     }
+
+    mod short_circuit {
+        use super::*;
+        use ::core::ops::ControlFlow;
+
+        #[test]
+        fn branch() {
+            assert_eq!(Some(3).branch(), ControlFlow::Continue(3));
+            assert_eq!(None::<i32>.branch(), ControlFlow::Break(()));
+        }
+
+        #[test]
+        fn round_trip() {
+            assert_eq!(
+                Option::<i32>::from_residual(()).branch(),
+                ControlFlow::Break(())
+            );
+        }
+    }
+
+    mod alternative {
+        use super::*;
+
+        #[test]
+        fn alt_keeps_first_some() {
+            assert_eq!(Some(1).alt(Some(2)), Some(1));
+            assert_eq!(None.alt(Some(2)), Some(2));
+            assert_eq!(Some(1).alt(None), Some(1));
+            assert_eq!(None::<i32>.alt(None), None);
+        }
+
+        #[test]
+        fn identity_laws() {
+            for x in [Some(3), None] {
+                assert_eq!(Option::nothing().alt(x), x);
+                assert_eq!(x.alt(Option::nothing()), x);
+            }
+        }
+
+        #[test]
+        fn associativity_law() {
+            let (x, y, z) = (None, Some(2), Some(3));
+            assert_eq!(x.alt(y).alt(z), x.alt(y.alt(z)));
+        }
+    }
+
+    mod foldable {
+        use super::*;
+
+        #[test]
+        fn fold_left() {
+            assert_eq!(Some(5).fold_left(1, |acc, x| acc + x), 6);
+            assert_eq!(None.fold_left(1, |acc, x: i32| acc + x), 1);
+        }
+
+        #[test]
+        fn fold_right() {
+            assert_eq!(Some(5).fold_right(1, |x, acc| x - acc), 4);
+            assert_eq!(None.fold_right(1, |x: i32, acc| x - acc), 1);
+        }
+
+        #[test]
+        fn find() {
+            assert_eq!(Some(4).find(|x| x % 2 == 0), Some(4));
+            assert_eq!(Some(3).find(|x| x % 2 == 0), None);
+            assert_eq!(None.find(|x: &i32| x % 2 == 0), None);
+        }
+
+        #[test]
+        fn any_and_all() {
+            assert!(Some(4).any(|x| *x > 3));
+            assert!(!Some(2).any(|x| *x > 3));
+            assert!(!None.any(|_: &i32| true));
+
+            assert!(Some(4).all(|x| *x > 3));
+            assert!(!Some(2).all(|x| *x > 3));
+            assert!(None.all(|_: &i32| false));
+        }
+    }
+    mod monoid {
+        use super::*;
+        use crate::monoid::monoid_impls::*;
+
+        #[test]
+        fn combine() {
+            let a = Some(First(Some(1)));
+            let b = Some(First(Some(2)));
+            assert_eq!(a.combine(b), Some(First(Some(1))));
+            assert_eq!(a.combine(None), a);
+            assert_eq!(None.combine(b), b);
+        }
+
+        #[test]
+        fn identity_laws() {
+            let a = Some(Last(Some(1)));
+            assert_eq!(Option::empty().combine(a), a);
+            assert_eq!(a.combine(Option::empty()), a);
+        }
+    }
 }
@@ -0,0 +1,257 @@
+pub mod outcome_impls {
+    use crate::*;
+
+    /// A computation that either succeeds with non-fatal warnings or fails.
+    ///
+    /// A successful `Outcome` carries its value together with the warnings
+    /// gathered so far, which are combined with `Monoid::combine` as steps are
+    /// chained. A failure carries only its error: `bind` stops there, and the
+    /// warnings of the steps before it are discarded along with their values.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::*;
+    /// use crab_fp::outcome::outcome_impls::Outcome;
+    ///
+    /// #[cfg(not(feature = "no_std"))]
+    /// {
+    ///     let port = Outcome::<_, Vec<&str>, &str>::success("80")
+    ///         .bind(|s| Outcome::with_warnings(s.parse::<u16>().unwrap(), vec!["privileged port"]))
+    ///         .bind(|p| Outcome::with_warnings(p, vec!["no TLS"]));
+    ///     assert_eq!(port.into_result(), Ok((80, vec!["privileged port", "no TLS"])));
+    /// }
+    /// ```
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum Outcome<A, W, E> {
+        Success(A, W),
+        Failure(E),
+    }
+
+    impl<A, W, E> Outcome<A, W, E> {
+        /// Creates a success with no warnings.
+        pub fn success(a: A) -> Self
+        where
+            W: Monoid,
+        {
+            Outcome::Success(a, W::empty())
+        }
+
+        /// Creates a success carrying `warnings`.
+        pub fn with_warnings(a: A, warnings: W) -> Self {
+            Outcome::Success(a, warnings)
+        }
+
+        /// Creates a failure.
+        pub fn failure(e: E) -> Self {
+            Outcome::Failure(e)
+        }
+
+        /// Returns `true` if the computation succeeded.
+        pub fn is_success(&self) -> bool {
+            matches!(self, Outcome::Success(..))
+        }
+
+        /// Returns the warnings gathered so far, or `None` on failure.
+        pub fn warnings(&self) -> Option<&W> {
+            match self {
+                Outcome::Success(_, w) => Some(w),
+                Outcome::Failure(_) => None,
+            }
+        }
+
+        /// Converts into a `Result` pairing the value with its warnings.
+        pub fn into_result(self) -> Result<(A, W), E> {
+            match self {
+                Outcome::Success(a, w) => Ok((a, w)),
+                Outcome::Failure(e) => Err(e),
+            }
+        }
+    }
+
+    pub struct OutcomeKind<W, E>(std::marker::PhantomData<(W, E)>);
+
+    impl<W, E> Generic1 for OutcomeKind<W, E> {
+        type Rep1<A> = Outcome<A, W, E>;
+    }
+
+    impl<A, W, E> Kinded1<A> for Outcome<A, W, E> {
+        type Kind1 = OutcomeKind<W, E>;
+    }
+
+    impl<A, W, E> Functor<A> for Outcome<A, W, E> {
+        fn fmap<B, F: FnOnce(A) -> B>(self, f: F) -> Outcome<B, W, E> {
+            match self {
+                Outcome::Success(a, w) => Outcome::Success(f(a), w),
+                Outcome::Failure(e) => Outcome::Failure(e),
+            }
+        }
+    }
+
+    /// Like `Result`, the value's error wins when both sides fail. When both
+    /// succeed, the value's warnings come first.
+    impl<A, W: Monoid, E> Applicative<A> for Outcome<A, W, E> {
+        fn pure(a: A) -> Outcome<A, W, E> {
+            Outcome::success(a)
+        }
+
+        fn apply<B, F: FnOnce(A) -> B>(self, ff: Outcome<F, W, E>) -> Outcome<B, W, E> {
+            match (self, ff) {
+                (Outcome::Success(a, w1), Outcome::Success(f, w2)) => {
+                    Outcome::Success(f(a), w1.combine(w2))
+                }
+                (Outcome::Failure(e), _) | (_, Outcome::Failure(e)) => Outcome::Failure(e),
+            }
+        }
+    }
+
+    impl<A, W: Monoid, E> Monad<A> for Outcome<A, W, E> {
+        fn bind<B, F: FnOnce(A) -> Outcome<B, W, E>>(self, f: F) -> Outcome<B, W, E> {
+            match self {
+                Outcome::Success(a, w1) => match f(a) {
+                    Outcome::Success(b, w2) => Outcome::Success(b, w1.combine(w2)),
+                    Outcome::Failure(e) => Outcome::Failure(e),
+                },
+                Outcome::Failure(e) => Outcome::Failure(e),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(feature = "no_std"))]
+mod outcome_tests {
+    use super::outcome_impls::*;
+    use crate::*;
+
+    type Validated<A> = Outcome<A, Vec<String>, String>;
+
+    fn check_range(x: i32) -> Validated<i32> {
+        match x {
+            ..0 => Outcome::failure(format!("{x} is negative")),
+            0..=100 => Outcome::success(x),
+            _ => Outcome::with_warnings(100, vec![format!("{x} clamped to 100")]),
+        }
+    }
+
+    fn warn_odd(x: i32) -> Validated<i32> {
+        if x % 2 == 1 {
+            Outcome::with_warnings(x, vec![format!("{x} is odd")])
+        } else {
+            Outcome::success(x)
+        }
+    }
+
+    #[test]
+    fn accessors() {
+        let ok = warn_odd(3);
+        assert!(ok.is_success());
+        assert_eq!(ok.warnings(), Some(&vec!["3 is odd".to_string()]));
+        let failed = check_range(-1);
+        assert!(!failed.is_success());
+        assert_eq!(failed.warnings(), None);
+        assert_eq!(failed.into_result(), Err("-1 is negative".to_string()));
+    }
+
+    mod functor {
+        use super::*;
+
+        #[test]
+        fn fmap_keeps_warnings() {
+            assert_eq!(
+                warn_odd(3).fmap(add_one),
+                Outcome::with_warnings(4, vec!["3 is odd".to_string()])
+            );
+            assert_eq!(check_range(-1).fmap(add_one), check_range(-1));
+        }
+
+        #[test]
+        fn identity_law() {
+            assert_eq!(warn_odd(3).fmap(identity), warn_odd(3));
+        }
+
+        #[test]
+        fn composition_law() {
+            let lhs = warn_odd(3).fmap(|x| multiply_by_two(add_one(x)));
+            let rhs = warn_odd(3).fmap(add_one).fmap(multiply_by_two);
+            assert_eq!(lhs, rhs);
+        }
+    }
+
+    mod applicative {
+        use super::*;
+
+        #[test]
+        fn apply_combines_warnings() {
+            let ff = Outcome::with_warnings(add_one as fn(i32) -> i32, vec!["f".to_string()]);
+            let result = warn_odd(3).apply(ff);
+            assert_eq!(
+                result.into_result(),
+                Ok((4, vec!["3 is odd".to_string(), "f".to_string()]))
+            );
+        }
+
+        #[test]
+        fn value_error_wins() {
+            let ff: Validated<fn(i32) -> i32> = Outcome::failure("function".to_string());
+            let value: Validated<i32> = Outcome::failure("value".to_string());
+            assert_eq!(value.apply(ff), Outcome::failure("value".to_string()));
+        }
+
+        #[test]
+        fn identity_law() {
+            let v = warn_odd(3);
+            assert_eq!(v.clone().apply(Outcome::pure(identity)), v);
+        }
+
+        #[test]
+        fn homomorphism_law() {
+            let lhs: Validated<i32> = Outcome::pure(5).apply(Outcome::pure(add_one));
+            assert_eq!(lhs, Outcome::pure(add_one(5)));
+        }
+    }
+
+    mod monad {
+        use super::*;
+
+        #[test]
+        fn warnings_accumulate_across_binds() {
+            let result = check_range(205).bind(warn_odd).bind(|x| warn_odd(x + 1));
+            assert_eq!(
+                result.into_result(),
+                Ok((
+                    101,
+                    vec!["205 clamped to 100".to_string(), "101 is odd".to_string()]
+                ))
+            );
+        }
+
+        #[test]
+        fn error_discards_later_steps() {
+            let mut later_ran = false;
+            let result = warn_odd(-3).bind(check_range).bind(|x| {
+                later_ran = true;
+                warn_odd(x)
+            });
+            assert_eq!(result, Outcome::failure("-3 is negative".to_string()));
+            assert!(!later_ran);
+        }
+
+        #[test]
+        fn left_identity_law() {
+            assert_eq!(Validated::pure(205).bind(check_range), check_range(205));
+        }
+
+        #[test]
+        fn right_identity_law() {
+            assert_eq!(warn_odd(3).bind(Outcome::pure), warn_odd(3));
+        }
+
+        #[test]
+        fn associativity_law() {
+            let m = check_range(205);
+            let lhs = m.clone().bind(warn_odd).bind(check_range);
+            let rhs = m.bind(|x| warn_odd(x).bind(check_range));
+            assert_eq!(lhs, rhs);
+        }
+    }
+}
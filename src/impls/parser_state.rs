@@ -0,0 +1,213 @@
+pub mod parser_state_impls {
+    use std::marker::PhantomData;
+
+    /// A parser threading a borrowed `&str` input, with no allocation.
+    ///
+    /// `ParserState` is a state monad over the remaining input: running it on
+    /// a slice yields the parsed value and the unconsumed rest, or `None` if
+    /// it fails. Every combinator returns a new parser wrapping the old ones
+    /// by value, so composing lexers needs neither `alloc` nor boxing and
+    /// works in `no_std` builds.
+    ///
+    /// `ParserState` has no `Functor`/`Monad` instance because its parser
+    /// function `P` is part of the type: `fmap` and `bind` return a parser
+    /// over a new closure, not the same `P`, and boxing `P` to hide it would
+    /// reintroduce the allocation this type is meant to avoid.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::parser_state::parser_state_impls::*;
+    ///
+    /// let digit = satisfy(|c| c.is_ascii_digit()).fmap(|c| c as u32 - '0' as u32);
+    /// let pair = digit.bind(|a| tag(",").bind(move |_| satisfy(|c| c.is_ascii_digit()).fmap(move |b| (a, b as u32 - '0' as u32))));
+    /// assert_eq!(pair.run("1,2 rest"), Some(((1, 2), " rest")));
+    /// ```
+    pub struct ParserState<'a, A, P: Fn(&'a str) -> Option<(A, &'a str)>> {
+        parse: P,
+        _marker: PhantomData<fn(&'a str) -> A>,
+    }
+
+    impl<'a, A, P: Fn(&'a str) -> Option<(A, &'a str)>> ParserState<'a, A, P> {
+        /// Wraps a function from the input to a value and the rest of the
+        /// input.
+        pub fn new(parse: P) -> Self {
+            ParserState {
+                parse,
+                _marker: PhantomData,
+            }
+        }
+
+        /// Runs the parser on `input`, returning the value and the unconsumed
+        /// rest, or `None` if it fails.
+        pub fn run(&self, input: &'a str) -> Option<(A, &'a str)> {
+            (self.parse)(input)
+        }
+
+        /// Maps a function over the parsed value.
+        pub fn fmap<B, F: Fn(A) -> B>(
+            self,
+            f: F,
+        ) -> ParserState<'a, B, impl Fn(&'a str) -> Option<(B, &'a str)>> {
+            ParserState::new(move |input| self.run(input).map(|(a, rest)| (f(a), rest)))
+        }
+
+        /// Runs the parser produced by `f` on the input this parser left
+        /// behind.
+        pub fn bind<B, Q, F>(
+            self,
+            f: F,
+        ) -> ParserState<'a, B, impl Fn(&'a str) -> Option<(B, &'a str)>>
+        where
+            Q: Fn(&'a str) -> Option<(B, &'a str)>,
+            F: Fn(A) -> ParserState<'a, B, Q>,
+        {
+            ParserState::new(move |input| {
+                let (a, rest) = self.run(input)?;
+                f(a).run(rest)
+            })
+        }
+
+        /// Tries this parser, falling back to `other` on the same input if it
+        /// fails.
+        pub fn alt<Q: Fn(&'a str) -> Option<(A, &'a str)>>(
+            self,
+            other: ParserState<'a, A, Q>,
+        ) -> ParserState<'a, A, impl Fn(&'a str) -> Option<(A, &'a str)>> {
+            ParserState::new(move |input| self.run(input).or_else(|| other.run(input)))
+        }
+    }
+
+    /// A parser that consumes nothing and always yields `a`.
+    pub fn succeed<'a, A: Clone>(
+        a: A,
+    ) -> ParserState<'a, A, impl Fn(&'a str) -> Option<(A, &'a str)>> {
+        ParserState::new(move |input| Some((a.clone(), input)))
+    }
+
+    /// A parser that consumes one character matching `pred`.
+    pub fn satisfy<'a, F: Fn(char) -> bool>(
+        pred: F,
+    ) -> ParserState<'a, char, impl Fn(&'a str) -> Option<(char, &'a str)>> {
+        ParserState::new(move |input: &'a str| {
+            let c = input.chars().next().filter(|&c| pred(c))?;
+            Some((c, &input[c.len_utf8()..]))
+        })
+    }
+
+    /// A parser that consumes `expected` exactly and yields it.
+    pub fn tag<'a>(
+        expected: &'static str,
+    ) -> ParserState<'a, &'a str, impl Fn(&'a str) -> Option<(&'a str, &'a str)>> {
+        ParserState::new(move |input: &'a str| {
+            let rest = input.strip_prefix(expected)?;
+            Some((&input[..expected.len()], rest))
+        })
+    }
+
+    /// A parser that consumes the longest prefix whose characters all match
+    /// `pred`, failing if that prefix is empty.
+    pub fn take_while1<'a, F: Fn(char) -> bool>(
+        pred: F,
+    ) -> ParserState<'a, &'a str, impl Fn(&'a str) -> Option<(&'a str, &'a str)>> {
+        ParserState::new(move |input: &'a str| {
+            let end = input.find(|c| !pred(c)).unwrap_or(input.len());
+            (end > 0).then(|| input.split_at(end))
+        })
+    }
+}
+
+#[cfg(test)]
+mod parser_state_tests {
+    use super::parser_state_impls::*;
+
+    fn digit<'a>() -> ParserState<'a, u32, impl Fn(&'a str) -> Option<(u32, &'a str)>> {
+        satisfy(|c| c.is_ascii_digit()).fmap(|c| c as u32 - '0' as u32)
+    }
+
+    fn number<'a>() -> ParserState<'a, u32, impl Fn(&'a str) -> Option<(u32, &'a str)>> {
+        take_while1(|c| c.is_ascii_digit()).fmap(|s: &str| s.parse().unwrap())
+    }
+
+    #[test]
+    fn primitives() {
+        assert_eq!(satisfy(|c| c == 'a').run("abc"), Some(('a', "bc")));
+        assert_eq!(satisfy(|c| c == 'a').run("xbc"), None);
+        assert_eq!(satisfy(|_| true).run(""), None);
+        assert_eq!(tag("let").run("let x"), Some(("let", " x")));
+        assert_eq!(tag("let").run("le"), None);
+        assert_eq!(
+            take_while1(|c| c.is_alphabetic()).run("ab12"),
+            Some(("ab", "12"))
+        );
+        assert_eq!(take_while1(|c| c.is_alphabetic()).run("12"), None);
+        assert_eq!(succeed(7).run("rest"), Some((7, "rest")));
+    }
+
+    #[test]
+    fn multibyte_input() {
+        assert_eq!(satisfy(|c| c == 'λ').run("λx"), Some(('λ', "x")));
+    }
+
+    mod functor {
+        use super::*;
+
+        #[test]
+        fn fmap() {
+            assert_eq!(digit().run("7up"), Some((7, "up")));
+            assert_eq!(digit().run("up"), None);
+        }
+
+        #[test]
+        fn identity_law() {
+            let p = take_while1(|c| c.is_ascii_digit());
+            assert_eq!(p.fmap(|s| s).run("42!"), Some(("42", "!")));
+        }
+    }
+
+    mod monad {
+        use super::*;
+
+        #[test]
+        fn bind_threads_input() {
+            let sum = number().bind(|a| tag("+").bind(move |_| number().fmap(move |b| a + b)));
+            assert_eq!(sum.run("12+30;"), Some((42, ";")));
+            assert_eq!(sum.run("12-30;"), None);
+        }
+
+        #[test]
+        fn left_identity_law() {
+            let input = "5x";
+            let lhs = succeed(3).bind(|a| digit().fmap(move |d| a + d));
+            let rhs = digit().fmap(|d| 3 + d);
+            assert_eq!(lhs.run(input), rhs.run(input));
+        }
+
+        #[test]
+        fn right_identity_law() {
+            let input = "5x";
+            assert_eq!(digit().bind(succeed).run(input), digit().run(input));
+        }
+    }
+
+    mod alt {
+        use super::*;
+
+        #[test]
+        fn falls_back_on_same_input() {
+            let keyword = tag("let").alt(tag("fn"));
+            assert_eq!(keyword.run("fn main"), Some(("fn", " main")));
+            assert_eq!(keyword.run("let x"), Some(("let", " x")));
+            assert_eq!(keyword.run("if"), None);
+        }
+
+        #[test]
+        fn composes_with_bind() {
+            let token = tag("let")
+                .alt(take_while1(|c| c.is_ascii_alphabetic()))
+                .bind(|word| take_while1(|c| c == ' ').fmap(move |_| word));
+            let pair = token.bind(|a| number().fmap(move |n| (a, n)));
+            assert_eq!(pair.run("let 5"), Some((("let", 5), "")));
+            assert_eq!(pair.run("x 12;"), Some((("x", 12), ";")));
+        }
+    }
+}
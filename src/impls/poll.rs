@@ -0,0 +1,214 @@
+pub mod poll_impls {
+    use crate::*;
+    use std::task::Poll;
+
+    /// The kind of `Poll<Option<A>>`, the shape returned by
+    /// `Stream::poll_next`.
+    ///
+    /// The instances transform only the item in `Ready(Some(_))`.
+    /// `Ready(None)`, the end of the stream, and `Pending` pass through
+    /// unchanged, so item transforms compose without touching the stream's
+    /// readiness.
+    ///
+    /// # Example
+    /// ```
+    /// use core::task::Poll;
+    /// use crab_fp::*;
+    ///
+    /// assert_eq!(Poll::Ready(Some(4)).fmap(|x| x * 2), Poll::Ready(Some(8)));
+    /// assert_eq!(Poll::<Option<i32>>::Pending.fmap(|x| x * 2), Poll::Pending);
+    /// ```
+    pub struct PollOptionKind;
+
+    impl Generic1 for PollOptionKind {
+        type Rep1<A> = Poll<Option<A>>;
+    }
+
+    impl<A> Kinded1<A> for Poll<Option<A>> {
+        type Kind1 = PollOptionKind;
+    }
+
+    impl<A> Functor<A> for Poll<Option<A>> {
+        fn fmap<B, F: FnOnce(A) -> B>(self, f: F) -> Poll<Option<B>> {
+            match self {
+                Poll::Ready(Some(a)) => Poll::Ready(Some(f(a))),
+                Poll::Ready(None) => Poll::Ready(None),
+                Poll::Pending => Poll::Pending,
+            }
+        }
+    }
+
+    /// The state of `ff` is checked first: if it is `Pending` or has ended,
+    /// so does the result, whatever the state of `self`.
+    impl<A> Applicative<A> for Poll<Option<A>> {
+        fn pure(a: A) -> Poll<Option<A>> {
+            Poll::Ready(Some(a))
+        }
+
+        fn apply<B, F: FnOnce(A) -> B>(self, ff: Poll<Option<F>>) -> Poll<Option<B>> {
+            match (ff, self) {
+                (Poll::Ready(Some(f)), Poll::Ready(Some(a))) => Poll::Ready(Some(f(a))),
+                (Poll::Pending, _) | (Poll::Ready(Some(_)), Poll::Pending) => Poll::Pending,
+                (Poll::Ready(None), _) | (Poll::Ready(Some(_)), Poll::Ready(None)) => {
+                    Poll::Ready(None)
+                }
+            }
+        }
+    }
+
+    impl<A> Monad<A> for Poll<Option<A>> {
+        fn bind<B, F: FnOnce(A) -> Poll<Option<B>>>(self, f: F) -> Poll<Option<B>> {
+            match self {
+                Poll::Ready(Some(a)) => f(a),
+                Poll::Ready(None) => Poll::Ready(None),
+                Poll::Pending => Poll::Pending,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod poll_tests {
+    use crate::*;
+    use ::core::task::Poll;
+
+    mod functor {
+        use super::*;
+
+        #[test]
+        fn fmap_transforms_item() {
+            assert_eq!(Poll::Ready(Some(5)).fmap(add_one), Poll::Ready(Some(6)));
+        }
+
+        #[test]
+        fn fmap_passes_through_end_of_stream() {
+            let ended: Poll<Option<i32>> = Poll::Ready(None);
+            assert_eq!(
+                ended.fmap(|_| -> i32 { panic!("mapped a missing item") }),
+                Poll::Ready(None)
+            );
+        }
+
+        #[test]
+        fn fmap_passes_through_pending() {
+            let pending: Poll<Option<i32>> = Poll::Pending;
+            assert_eq!(
+                pending.fmap(|_| -> i32 { panic!("mapped a pending item") }),
+                Poll::Pending
+            );
+        }
+
+        #[test]
+        fn identity_law() {
+            for p in [Poll::Ready(Some(3)), Poll::Ready(None), Poll::Pending] {
+                assert_eq!(p.fmap(identity), p);
+            }
+        }
+
+        #[test]
+        fn composition_law() {
+            for p in [Poll::Ready(Some(3)), Poll::Ready(None), Poll::Pending] {
+                assert_eq!(
+                    p.fmap(add_one).fmap(to_string),
+                    p.fmap(|x| to_string(add_one(x)))
+                );
+            }
+        }
+    }
+
+    mod applicative {
+        use super::*;
+
+        #[test]
+        fn pure() {
+            assert_eq!(Poll::<Option<i32>>::pure(7), Poll::Ready(Some(7)));
+        }
+
+        #[test]
+        fn apply() {
+            assert_eq!(
+                Poll::Ready(Some(5)).apply(Poll::Ready(Some(multiply_by_two))),
+                Poll::Ready(Some(10))
+            );
+            assert_eq!(
+                Poll::Pending.apply(Poll::Ready(Some(multiply_by_two))),
+                Poll::Pending
+            );
+            assert_eq!(
+                Poll::Ready(Some(5)).apply(Poll::<Option<fn(i32) -> i32>>::Ready(None)),
+                Poll::Ready(None)
+            );
+            assert_eq!(
+                Poll::Ready(None).apply(Poll::<Option<fn(i32) -> i32>>::Pending),
+                Poll::Pending
+            );
+        }
+
+        #[test]
+        fn identity_law() {
+            for v in [Poll::Ready(Some(3)), Poll::Ready(None), Poll::Pending] {
+                assert_eq!(v.apply(Poll::pure(identity)), v);
+            }
+        }
+
+        #[test]
+        fn homomorphism_law() {
+            let lhs = Poll::<Option<i32>>::pure(4).apply(Poll::pure(square));
+            assert_eq!(lhs, Poll::<Option<i32>>::pure(square(4)));
+        }
+    }
+
+    mod monad {
+        use super::*;
+
+        fn positive(x: i32) -> Poll<Option<i32>> {
+            if x > 0 {
+                Poll::Ready(Some(x))
+            } else {
+                Poll::Ready(None)
+            }
+        }
+
+        fn halve(x: i32) -> Poll<Option<i32>> {
+            if x % 2 == 0 {
+                Poll::Ready(Some(x / 2))
+            } else {
+                Poll::Pending
+            }
+        }
+
+        #[test]
+        fn bind() {
+            assert_eq!(Poll::Ready(Some(4)).bind(halve), Poll::Ready(Some(2)));
+            assert_eq!(Poll::Ready(Some(3)).bind(halve), Poll::Pending);
+            assert_eq!(Poll::Ready(None).bind(halve), Poll::Ready(None));
+            assert_eq!(Poll::Pending.bind(positive), Poll::Pending);
+        }
+
+        #[test]
+        fn left_identity_law() {
+            assert_eq!(Poll::pure(4).bind(halve), halve(4));
+        }
+
+        #[test]
+        fn right_identity_law() {
+            for m in [Poll::Ready(Some(3)), Poll::Ready(None), Poll::Pending] {
+                assert_eq!(m.bind(Poll::pure), m);
+            }
+        }
+
+        #[test]
+        fn associativity_law() {
+            for m in [
+                Poll::Ready(Some(8)),
+                Poll::Ready(Some(-2)),
+                Poll::Ready(None),
+                Poll::Pending,
+            ] {
+                let lhs = m.bind(positive).bind(halve);
+                let rhs = m.bind(|x| positive(x).bind(halve));
+                assert_eq!(lhs, rhs);
+            }
+        }
+    }
+}
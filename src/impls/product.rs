@@ -0,0 +1,172 @@
+pub mod product_impls {
+    use crate::*;
+
+    /// The product of two functors, `(F(A), G(A))`.
+    ///
+    /// `Product` runs two functorial computations over the same value type
+    /// side by side: `fmap` maps both halves with the same function, and
+    /// `apply` applies each half's functions to the matching half's values.
+    ///
+    /// # Type Parameters
+    /// * `F` - The kind of the first functor
+    /// * `G` - The kind of the second functor
+    /// * `A` - The type of the values in both functors
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::*;
+    /// use crab_fp::product::product_impls::Product;
+    /// use crab_fp::option::option_impls::OptionKind;
+    /// use crab_fp::result::result_impls::ResultKind;
+    ///
+    /// let p: Product<OptionKind, ResultKind<&str>, i32> = Product(Some(1), Ok(2));
+    /// let mapped = p.fmap(|x| x * 10);
+    /// assert_eq!((mapped.0, mapped.1), (Some(10), Ok(20)));
+    /// ```
+    pub struct Product<F: Generic1, G: Generic1, A>(pub Apply1<F, A>, pub Apply1<G, A>);
+
+    impl<F: Generic1, G: Generic1, A> Product<F, G, A> {
+        /// Unwraps the product, returning both functors.
+        pub fn into_inner(self) -> (Apply1<F, A>, Apply1<G, A>) {
+            (self.0, self.1)
+        }
+    }
+
+    pub struct ProductKind<F, G>(std::marker::PhantomData<(F, G)>);
+
+    impl<F: Generic1, G: Generic1> Generic1 for ProductKind<F, G> {
+        type Rep1<A> = Product<F, G, A>;
+    }
+
+    impl<F: Generic1, G: Generic1, A> Kinded1<A> for Product<F, G, A> {
+        type Kind1 = ProductKind<F, G>;
+    }
+
+    impl<F: Generic1, G: Generic1, A> Functor<A> for Product<F, G, A>
+    where
+        Apply1<F, A>: Functor<A, Kind1 = F>,
+        Apply1<G, A>: Functor<A, Kind1 = G>,
+    {
+        fn fmap<B, M: FnMut(A) -> B>(self, mut f: M) -> Product<F, G, B> {
+            Product(self.0.fmap(&mut f), self.1.fmap(&mut f))
+        }
+    }
+
+    /// `pure` lifts a clone of the value into each half.
+    impl<F: Generic1, G: Generic1, A: Clone> Applicative<A> for Product<F, G, A>
+    where
+        Apply1<F, A>: Applicative<A, Kind1 = F>,
+        Apply1<G, A>: Applicative<A, Kind1 = G>,
+    {
+        fn pure(b: A) -> Product<F, G, A> {
+            Product(
+                <Apply1<F, A> as Applicative<A>>::pure(b.clone()),
+                <Apply1<G, A> as Applicative<A>>::pure(b),
+            )
+        }
+
+        fn apply<B, H: FnMut(A) -> B>(self, ff: Product<F, G, H>) -> Product<F, G, B> {
+            Product(self.0.apply(ff.0), self.1.apply(ff.1))
+        }
+    }
+}
+
+#[cfg(test)]
+mod product_tests {
+    use super::product_impls::*;
+    use crate::option::option_impls::OptionKind;
+    use crate::result::result_impls::ResultKind;
+    use crate::*;
+
+    type OptionResult<A> = Product<OptionKind, ResultKind<&'static str>, A>;
+
+    mod functor {
+        use super::*;
+
+        #[test]
+        fn fmap_transforms_both_halves() {
+            let p: OptionResult<i32> = Product(Some(2), Err("bad"));
+            assert_eq!(p.fmap(square).into_inner(), (Some(4), Err("bad")));
+        }
+
+        #[cfg(not(feature = "no_std"))]
+        #[test]
+        fn option_and_vec() {
+            use crate::vec::vec_impls::VecKind;
+
+            let p: Product<OptionKind, VecKind, i32> = Product(Some(1), vec![2, 3]);
+            assert_eq!(
+                p.fmap(to_string).into_inner(),
+                (
+                    Some("1".to_string()),
+                    vec!["2".to_string(), "3".to_string()]
+                )
+            );
+        }
+
+        // Identity: fmap id = id
+        #[test]
+        fn identity_law() {
+            let p: OptionResult<i32> = Product(Some(1), Ok(2));
+            assert_eq!(p.fmap(identity).into_inner(), (Some(1), Ok(2)));
+        }
+
+        // Composition: fmap (f . g) = fmap f . fmap g
+        #[test]
+        fn composition_law() {
+            let lhs = Product::<OptionKind, ResultKind<&str>, i32>(Some(1), Ok(2))
+                .fmap(|x| multiply_by_two(add_one(x)));
+            let rhs = Product::<OptionKind, ResultKind<&str>, i32>(Some(1), Ok(2))
+                .fmap(add_one)
+                .fmap(multiply_by_two);
+            assert_eq!(lhs.into_inner(), rhs.into_inner());
+        }
+    }
+
+    mod applicative {
+        use super::*;
+
+        #[test]
+        fn pure() {
+            assert_eq!(OptionResult::pure(7).into_inner(), (Some(7), Ok(7)));
+        }
+
+        #[test]
+        fn apply_in_lockstep() {
+            let p: OptionResult<i32> = Product(Some(3), Ok(4));
+            let ff: OptionResult<fn(i32) -> i32> =
+                Product(Some(fn_ptr!(add_one)), Ok(fn_ptr!(square)));
+            assert_eq!(p.apply(ff).into_inner(), (Some(4), Ok(16)));
+        }
+
+        #[cfg(not(feature = "no_std"))]
+        #[test]
+        fn option_and_vec() {
+            use crate::vec::vec_impls::VecKind;
+
+            let p: Product<OptionKind, VecKind, i32> = Product(Some(1), vec![1, 2]);
+            let ff: Product<OptionKind, VecKind, fn(i32) -> i32> = Product(
+                Some(fn_ptr!(add_one)),
+                vec![fn_ptr!(add_one), fn_ptr!(multiply_by_two)],
+            );
+            assert_eq!(p.apply(ff).into_inner(), (Some(2), vec![2, 3, 2, 4]));
+        }
+
+        // Identity: pure id <*> v = v
+        #[test]
+        fn identity_law() {
+            let p: OptionResult<i32> = Product(Some(1), Err("bad"));
+            let id: OptionResult<fn(i32) -> i32> =
+                Product(Some(fn_ptr!(identity)), Ok(fn_ptr!(identity)));
+            assert_eq!(p.apply(id).into_inner(), (Some(1), Err("bad")));
+        }
+
+        // Homomorphism: pure f <*> pure x = pure (f x)
+        #[test]
+        fn homomorphism_law() {
+            let f: fn(i32) -> i32 = square;
+            let lhs = OptionResult::pure(6).apply(OptionResult::pure(f));
+            assert_eq!(lhs.into_inner(), OptionResult::pure(square(6)).into_inner());
+        }
+    }
+}
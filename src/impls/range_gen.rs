@@ -0,0 +1,173 @@
+pub mod range_gen_impls {
+    use std::iter::{FlatMap, Map, Once};
+    use std::ops::Range;
+
+    /// A lazy, allocation-free list monad over an iterator.
+    ///
+    /// `RangeGen` treats a generator such as `0..n` as a nondeterministic
+    /// value, like the `Vec` monad, but `fmap` and `bind` build iterator
+    /// adapters instead of collecting: nested generators are flattened one item
+    /// at a time, and nothing runs until the result is iterated. `RangeGen` is
+    /// itself an `Iterator`, so it can be collected or chained as usual.
+    ///
+    /// `RangeGen` has no `Functor`/`Monad` instance: `fmap` returns a
+    /// `RangeGen<Map<I, F>>`, whose type names the closure, whereas
+    /// `Functor::fmap` has to return the same type constructor applied to the
+    /// new item type.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::range_gen::range_gen_impls::RangeGen;
+    ///
+    /// let pairs: Vec<_> = RangeGen::range(3)
+    ///     .bind(|i| RangeGen::new(i + 1..3).fmap(move |j| (i, j)))
+    ///     .collect();
+    /// assert_eq!(pairs, vec![(0, 1), (0, 2), (1, 2)]);
+    /// ```
+    #[derive(Debug, Clone)]
+    pub struct RangeGen<I>(I);
+
+    impl RangeGen<Range<usize>> {
+        /// Generates every index in `0..n`.
+        pub fn range(n: usize) -> Self {
+            RangeGen(0..n)
+        }
+    }
+
+    impl<A> RangeGen<Once<A>> {
+        /// Generates exactly one value.
+        pub fn pure(a: A) -> Self {
+            RangeGen(std::iter::once(a))
+        }
+    }
+
+    impl<I: Iterator> RangeGen<I> {
+        /// Wraps any iterable as a generator.
+        pub fn new<T: IntoIterator<IntoIter = I>>(iter: T) -> Self {
+            RangeGen(iter.into_iter())
+        }
+
+        /// Lazily maps a function over every generated value.
+        pub fn fmap<B, F: FnMut(I::Item) -> B>(self, f: F) -> RangeGen<Map<I, F>> {
+            RangeGen(self.0.map(f))
+        }
+
+        /// Runs a generator for every generated value, lazily flattening the
+        /// results in order.
+        pub fn bind<J: Iterator, F: FnMut(I::Item) -> RangeGen<J>>(
+            self,
+            f: F,
+        ) -> RangeGen<FlatMap<I, RangeGen<J>, F>> {
+            RangeGen(self.0.flat_map(f))
+        }
+    }
+
+    impl<I: Iterator> Iterator for RangeGen<I> {
+        type Item = I::Item;
+
+        fn next(&mut self) -> Option<I::Item> {
+            self.0.next()
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            self.0.size_hint()
+        }
+    }
+}
+
+#[cfg(test)]
+mod range_gen_tests {
+    use super::range_gen_impls::*;
+    use crate::*;
+
+    mod functor {
+        use super::*;
+
+        #[test]
+        fn fmap() {
+            let mut g = RangeGen::range(3).fmap(|i| i * 10);
+            assert_eq!(g.next(), Some(0));
+            assert_eq!(g.next(), Some(10));
+            assert_eq!(g.next(), Some(20));
+            assert_eq!(g.next(), None);
+        }
+
+        #[test]
+        fn identity_law() {
+            // Identity: fmap id = id
+            assert!(RangeGen::new(1..4).fmap(identity).eq(1..4));
+        }
+
+        #[test]
+        fn composition_law() {
+            // Composition: fmap (g . f) = fmap g . fmap f
+            let lhs = RangeGen::new(1..4).fmap(|x| square(add_one(x)));
+            let rhs = RangeGen::new(1..4).fmap(add_one).fmap(square);
+            assert!(lhs.eq(rhs));
+        }
+    }
+
+    mod monad {
+        use super::*;
+
+        #[test]
+        fn bind_flattens_in_order() {
+            let g = RangeGen::range(3).bind(RangeGen::range);
+            assert!(g.eq([0, 0, 1]));
+        }
+
+        #[test]
+        fn bind_is_lazy() {
+            let mut inner_calls = 0;
+            {
+                let mut g = RangeGen::new(0..).bind(|i| {
+                    inner_calls += 1;
+                    RangeGen::new(i..i + 2)
+                });
+                assert_eq!(g.next(), Some(0));
+                assert_eq!(g.next(), Some(1));
+                assert_eq!(g.next(), Some(1));
+            }
+            assert_eq!(inner_calls, 2);
+        }
+
+        #[test]
+        #[cfg(not(feature = "no_std"))]
+        fn matches_vec_comprehension() {
+            let lazy: Vec<(usize, usize)> = RangeGen::range(4)
+                .bind(|i| RangeGen::range(i).fmap(move |j| (i, j)))
+                .collect();
+            let eager = (0..4)
+                .collect::<Vec<usize>>()
+                .bind(|i| (0..i).collect::<Vec<usize>>().fmap(|j| (i, j)));
+            assert_eq!(lazy, eager);
+        }
+
+        #[test]
+        fn left_identity_law() {
+            // Left identity: return a >>= f = f a
+            let f = |x: usize| RangeGen::range(x).fmap(add_one_usize);
+            assert!(RangeGen::pure(3).bind(f).eq(f(3)));
+        }
+
+        #[test]
+        fn right_identity_law() {
+            // Right identity: m >>= return = m
+            assert!(RangeGen::range(5).bind(RangeGen::pure).eq(0..5));
+        }
+
+        #[test]
+        fn associativity_law() {
+            // Associativity: (m >>= f) >>= g = m >>= (|x| f x >>= g)
+            let f = |x: usize| RangeGen::range(x);
+            let g = |x: usize| RangeGen::new([x, x * 10]);
+            let lhs = RangeGen::range(4).bind(f).bind(g);
+            let rhs = RangeGen::range(4).bind(|x| f(x).bind(g));
+            assert!(lhs.eq(rhs));
+        }
+
+        fn add_one_usize(x: usize) -> usize {
+            x + 1
+        }
+    }
+}
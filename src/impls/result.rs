@@ -1,5 +1,6 @@
 pub mod result_impls {
     use crate::*;
+    use std::ops::ControlFlow;
 
     pub struct ResultKind<E>(std::marker::PhantomData<E>);
 
@@ -22,11 +23,31 @@ pub mod result_impls {
     }
 
     impl<A, E> Functor<A> for Result<A, E> {
+        /// Calls `f` at most once: for `Ok`, and never for `Err`.
         fn fmap<B, F: FnOnce(A) -> B>(self, f: F) -> Result<B, E> {
             self.map(f)
         }
     }
 
+    /// Mapping a borrowed `Err` has to produce an owned one, so the error is
+    /// cloned.
+    impl<E: Clone> Ref1 for ResultKind<E> {
+        fn fmap_ref<'a, A: 'a, B, M: FnMut(&'a A) -> B>(
+            fa: &'a Result<A, E>,
+            f: M,
+        ) -> Result<B, E> {
+            fa.as_ref().map(f).map_err(E::clone)
+        }
+    }
+
+    impl<A, E> FunctorMut<A> for Result<A, E> {
+        fn fmap_mut<F: FnMut(&mut A)>(&mut self, mut f: F) {
+            if let Ok(a) = self {
+                f(a);
+            }
+        }
+    }
+
     impl<A, E> Applicative<A> for Result<A, E> {
         fn pure(b: A) -> Result<A, E> {
             Ok(b)
@@ -47,6 +68,213 @@ pub mod result_impls {
         }
     }
 
+    impl<A, E> ShortCircuit<A> for Result<A, E> {
+        type Residual = E;
+
+        fn branch(self) -> ControlFlow<E, A> {
+            match self {
+                Ok(a) => ControlFlow::Continue(a),
+                Err(e) => ControlFlow::Break(e),
+            }
+        }
+
+        fn from_residual(e: E) -> Result<A, E> {
+            Err(e)
+        }
+    }
+
+    /// Additional combinators for `Result` beyond the typeclass instances.
+    pub trait ResultExt<A, E> {
+        /// Applies a function in a `Result` to this value, accumulating errors.
+        ///
+        /// Behaves like `apply`, except that when both this value and the
+        /// function are `Err` the two errors are combined with
+        /// `Semigroup::combine` (value error first) instead of discarding the
+        /// function's error.
+        ///
+        /// # Parameters
+        /// * `ff` - A `Result` containing a function from `A` to `B`
+        ///
+        /// # Returns
+        /// `Ok` with the applied value, or every error that occurred.
+        ///
+        /// # Example
+        /// ```
+        /// use crab_fp::ResultExt;
+        ///
+        /// #[cfg(not(feature = "no_std"))]
+        /// {
+        ///     let value: Result<i32, Vec<&str>> = Err(vec!["bad value"]);
+        ///     let function: Result<fn(i32) -> i32, _> = Err(vec!["bad function"]);
+        ///     assert_eq!(value.apply_accum(function), Err(vec!["bad value", "bad function"]));
+        /// }
+        /// ```
+        fn apply_accum<B, F: FnOnce(A) -> B>(self, ff: Result<F, E>) -> Result<B, E>
+        where
+            E: Semigroup;
+
+        /// Extracts the value when both channels carry the same type.
+        ///
+        /// The method form of `collapse`; it is only available when `E` is `A`.
+        ///
+        /// # Example
+        /// ```
+        /// use crab_fp::ResultExt;
+        ///
+        /// let r: Result<i32, i32> = Err(-1);
+        /// assert_eq!(r.merge(), -1);
+        /// ```
+        fn merge(self) -> A
+        where
+            Self: Into<Result<A, A>>;
+
+        /// Maps the same function over whichever channel holds the value.
+        ///
+        /// Useful once both channels have been normalised to a common type:
+        /// the function runs on the `Ok` or the `Err` value and the variant is
+        /// kept. It is only available when `E` is `A`.
+        ///
+        /// # Example
+        /// ```
+        /// use crab_fp::ResultExt;
+        ///
+        /// let r: Result<&str, &str> = Err("timeout");
+        /// assert_eq!(r.fmap_both(str::len), Err(7));
+        /// ```
+        fn fmap_both<B, F: FnOnce(A) -> B>(self, f: F) -> Result<B, B>
+        where
+            Self: Into<Result<A, A>>;
+
+        /// Chains a computation whose error type this error converts into.
+        ///
+        /// Like `bind`, but an `Err` in `self` is widened with `Into`, the way
+        /// the `?` operator does, so steps with different error types can be
+        /// chained without a `map_err` between them.
+        ///
+        /// # Example
+        /// ```
+        /// use crab_fp::ResultExt;
+        ///
+        /// #[derive(Debug, PartialEq)]
+        /// enum AppError {
+        ///     Parse,
+        ///     TooLarge,
+        /// }
+        ///
+        /// impl From<std::num::ParseIntError> for AppError {
+        ///     fn from(_: std::num::ParseIntError) -> Self {
+        ///         AppError::Parse
+        ///     }
+        /// }
+        ///
+        /// let check = |n: u32| if n > 100 { Err(AppError::TooLarge) } else { Ok(n) };
+        /// assert_eq!("42".parse::<u32>().bind_from(check), Ok(42));
+        /// assert_eq!("x".parse::<u32>().bind_from(check), Err(AppError::Parse));
+        /// ```
+        fn bind_from<B, E2, F: FnOnce(A) -> Result<B, E2>>(self, f: F) -> Result<B, E2>
+        where
+            E: Into<E2>;
+
+        /// Handles an error by producing a value in its place.
+        ///
+        /// The error-side dual of `fmap`: the handler only runs on `Err`, and
+        /// the result is always a plain value.
+        ///
+        /// # Example
+        /// ```
+        /// use crab_fp::ResultExt;
+        ///
+        /// let r: Result<u16, &str> = Err("missing");
+        /// assert_eq!(r.recover(|_| 8080), 8080);
+        /// ```
+        fn recover<F: FnOnce(E) -> A>(self, f: F) -> A;
+
+        /// Handles an error with a computation that may itself fail.
+        ///
+        /// The error-side dual of `bind`: the handler only runs on `Err`, and
+        /// may recover with `Ok` or re-fail with an error of a new type.
+        ///
+        /// # Example
+        /// ```
+        /// use crab_fp::ResultExt;
+        ///
+        /// let primary: Result<&str, &str> = Err("primary down");
+        /// let fallback = primary.recover_with(|_| Ok::<_, String>("replica"));
+        /// assert_eq!(fallback, Ok("replica"));
+        /// ```
+        fn recover_with<E2, F: FnOnce(E) -> Result<A, E2>>(self, f: F) -> Result<A, E2>;
+    }
+
+    impl<A, E> ResultExt<A, E> for Result<A, E> {
+        fn apply_accum<B, F: FnOnce(A) -> B>(self, ff: Result<F, E>) -> Result<B, E>
+        where
+            E: Semigroup,
+        {
+            match (self, ff) {
+                (Ok(a), Ok(f)) => Ok(f(a)),
+                (Err(e1), Err(e2)) => Err(e1.combine(e2)),
+                (Err(e), _) | (_, Err(e)) => Err(e),
+            }
+        }
+
+        fn merge(self) -> A
+        where
+            Self: Into<Result<A, A>>,
+        {
+            collapse(self.into())
+        }
+
+        fn fmap_both<B, F: FnOnce(A) -> B>(self, f: F) -> Result<B, B>
+        where
+            Self: Into<Result<A, A>>,
+        {
+            match self.into() {
+                Ok(a) => Ok(f(a)),
+                Err(e) => Err(f(e)),
+            }
+        }
+
+        fn bind_from<B, E2, F: FnOnce(A) -> Result<B, E2>>(self, f: F) -> Result<B, E2>
+        where
+            E: Into<E2>,
+        {
+            match self {
+                Ok(a) => f(a),
+                Err(e) => Err(e.into()),
+            }
+        }
+
+        fn recover<F: FnOnce(E) -> A>(self, f: F) -> A {
+            match self {
+                Ok(a) => a,
+                Err(e) => f(e),
+            }
+        }
+
+        fn recover_with<E2, F: FnOnce(E) -> Result<A, E2>>(self, f: F) -> Result<A, E2> {
+            match self {
+                Ok(a) => Ok(a),
+                Err(e) => f(e),
+            }
+        }
+    }
+
+    impl<A, E> Foldable<A> for Result<A, E> {
+        fn fold_left<B, F: FnOnce(B, A) -> B>(self, init: B, f: F) -> B {
+            match self {
+                Ok(a) => f(init, a),
+                Err(_) => init,
+            }
+        }
+
+        fn fold_right<B, F: FnOnce(A, B) -> B>(self, init: B, f: F) -> B {
+            match self {
+                Ok(a) => f(a, init),
+                Err(_) => init,
+            }
+        }
+    }
+
     impl<A, C> Bifunctor<A, C> for Result<A, C> {
         fn bimap<B, D, F: FnMut(A) -> B, G: FnMut(C) -> D>(
             self,
@@ -78,8 +306,6 @@ pub mod result_impls {
 #[cfg(test)]
 mod result_tests {
     use crate::*;
-    #[cfg(feature = "no_std")]
-    use crate::fixed_string::*;
 
     mod functor {
         use super::*;
@@ -94,6 +320,67 @@ mod result_tests {
             let result2 = result1.fmap(|x| x + 1);
             assert_eq!(result2, Err("error"));
         }
+
+        #[test]
+        fn fmap_calls_closure_at_most_once() {
+            let mut calls = 0;
+            let r: Result<i32, &str> = Ok(5);
+            let mapped = r.fmap(|x| {
+                calls += 1;
+                x * calls
+            });
+            assert_eq!(mapped, Ok(5));
+            assert_eq!(calls, 1);
+
+            let r: Result<i32, &str> = Err("error");
+            let mapped = r.fmap(|x| {
+                calls += 1;
+                x
+            });
+            assert_eq!(mapped, Err("error"));
+            assert_eq!(calls, 1);
+        }
+    }
+
+    mod fmap_mut {
+        use super::*;
+
+        #[test]
+        fn ok_mutated_in_place() {
+            let mut r: Result<i32, &str> = Ok(4);
+            r.fmap_mut(|x| *x += 1);
+            assert_eq!(r, Ok(5));
+        }
+
+        #[test]
+        fn err_untouched() {
+            let mut r: Result<i32, &str> = Err("error");
+            r.fmap_mut(|_| panic!("called on Err"));
+            assert_eq!(r, Err("error"));
+        }
+
+        #[test]
+        fn agrees_with_fmap() {
+            let mut r: Result<i32, &str> = Ok(3);
+            r.fmap_mut(|x| *x = multiply_by_two(*x));
+            assert_eq!(r, Ok(3).fmap(multiply_by_two));
+        }
+    }
+
+    mod ref1 {
+        use crate::result::result_impls::ResultKind;
+        use crate::*;
+
+        #[test]
+        fn fmap_ref() {
+            let ok: Result<i32, &str> = Ok(4);
+            assert_eq!(ResultKind::fmap_ref(&ok, |x| x + 1), Ok(5));
+            assert_eq!(ok, Ok(4));
+
+            let err: Result<i32, &str> = Err("bad");
+            assert_eq!(ResultKind::fmap_ref(&err, |x| x + 1), Err("bad"));
+            assert_eq!(err, Err("bad"));
+        }
     }
 
     mod applicative {
@@ -149,10 +436,10 @@ mod result_tests {
         fn homomorphism_law() {
             // Homomorphism: pure f <*> pure x = pure (f x)
             let x = 69;
-            let pure_f = Ok(to_string);
+            let pure_f = Ok(square);
             let pure_x: Result<i32, &str> = Ok(x);
             let lhs = pure_x.apply(pure_f); // pure_f <*> pure_x
-            let rhs = Ok(to_string(x));
+            let rhs = Ok(square(x));
             assert_eq!(lhs, rhs);
         }
 
@@ -161,9 +448,9 @@ mod result_tests {
             // Test composition for Result
             let w: Result<i32, &str> = Ok(5);
             let v = Ok(multiply_by_two);
-            let u = Ok(to_string);
+            let u = Ok(add_one);
 
-            // Apply multiply_two, then to_string_fn
+            // Apply multiply_by_two, then add_one
             let left_side = w.apply(v).apply(u);
 
             let v_applied_to_w = w.apply(v);
@@ -197,6 +484,16 @@ mod result_tests {
             let rhs = f(a);
 
             assert_eq!(lhs, rhs);
+
+            // Check with a failing function
+            let f = |x: i32| {
+                if x > 10 {
+                    Ok(x)
+                } else {
+                    Err::<i32, &str>("too small")
+                }
+            };
+            assert_eq!(Result::pure(a).bind(f), f(a));
         }
 
         #[test]
@@ -219,7 +516,7 @@ mod result_tests {
             // Associativity: (m >>= f) >>= g = m >>= (|x| f x >>= g)
             let m: Result<i32, &str> = Ok(5);
             let f = |x: i32| Ok::<_, &str>(x * 2);
-            let g = |x: i32| Ok::<_, &str>(x.to_string());
+            let g = |x: i32| if x > 5 { Ok(x - 5) } else { Err("too small") };
 
             // Left side: (m >>= f) >>= g
             let lhs = m.bind(f).bind(g);
@@ -234,6 +531,13 @@ mod result_tests {
             let lhs = m.bind(f).bind(g);
             let rhs = m.bind(|x| f(x).bind(g));
             assert_eq!(lhs, rhs);
+
+            // Check with the second step failing
+            let m: Result<i32, &str> = Ok(2);
+            let lhs = m.bind(f).bind(g);
+            let rhs = m.bind(|x| f(x).bind(g));
+            assert_eq!(lhs, Err("too small"));
+            assert_eq!(lhs, rhs);
         }
 
         #[test]
@@ -242,20 +546,249 @@ mod result_tests {
             let result = Ok::<_, &str>(5)
                 .bind(|x| Ok(x * 2))
                 .bind(|x| Ok(x + 3))
-                .bind(|x: i32| Ok(x.to_string()));
+                .bind(|x: i32| Ok(x * 10));
 
-            assert_eq!(result, Ok("13".to_string()));
+            assert_eq!(result, Ok(130));
 
             // Chain that should fail at the middle
             let result = Ok::<_, &str>(5)
                 .bind(|x| Ok(x * 2))
                 .bind(|_| Err("operation failed"))
-                .bind(|x: i32| Ok(x.to_string()));
+                .bind(|x: i32| Ok(x * 10));
 
             assert_eq!(result, Err("operation failed"));
         }
     }
 
+    mod short_circuit {
+        use super::*;
+        use ::core::ops::ControlFlow;
+
+        #[test]
+        fn branch() {
+            assert_eq!(Ok::<_, &str>(3).branch(), ControlFlow::Continue(3));
+            assert_eq!(Err::<i32, _>("bad").branch(), ControlFlow::Break("bad"));
+        }
+
+        #[test]
+        fn round_trip() {
+            assert_eq!(
+                Result::<i32, _>::from_residual("bad").branch(),
+                ControlFlow::Break("bad")
+            );
+        }
+    }
+
+    mod result_ext {
+        use super::*;
+
+        #[test]
+        #[cfg(not(feature = "no_std"))]
+        fn apply_accum_both_err_combines() {
+            let r: Result<i32, Vec<&str>> = Err(vec!["value error"]);
+            let f: Result<fn(i32) -> i32, _> = Err(vec!["function error"]);
+            assert_eq!(r.apply_accum(f), Err(vec!["value error", "function error"]));
+        }
+
+        #[test]
+        fn apply_accum_single_err_passes_through() {
+            use crate::monoid::monoid_impls::First;
+
+            let r: Result<i32, First<&str>> = Err(First(Some("value error")));
+            let f = Ok(add_one);
+            assert_eq!(r.apply_accum(f), Err(First(Some("value error"))));
+
+            let r: Result<i32, First<&str>> = Ok(5);
+            let f: Result<fn(i32) -> i32, _> = Err(First(Some("function error")));
+            assert_eq!(r.apply_accum(f), Err(First(Some("function error"))));
+        }
+
+        #[test]
+        fn apply_accum_both_ok_applies() {
+            use crate::monoid::monoid_impls::First;
+
+            let r: Result<i32, First<&str>> = Ok(5);
+            let f = Ok(multiply_by_two);
+            assert_eq!(r.apply_accum(f), Ok(10));
+        }
+
+        #[test]
+        fn merge() {
+            let ok: Result<i32, i32> = Ok(5);
+            assert_eq!(ok.merge(), 5);
+
+            let err: Result<i32, i32> = Err(-5);
+            assert_eq!(err.merge(), -5);
+        }
+
+        #[test]
+        fn merge_after_bimap() {
+            let r: Result<i32, &str> = Err("failed");
+            assert_eq!(r.bimap(|x| x * 2, |s| s.len() as i32).merge(), 6);
+        }
+
+        #[test]
+        fn fmap_both_ok() {
+            let mut calls = 0;
+            let r: Result<i32, i32> = Ok(4);
+            let mapped = r.fmap_both(|x| {
+                calls += 1;
+                x * 10
+            });
+            assert_eq!(mapped, Ok(40));
+            assert_eq!(calls, 1);
+        }
+
+        #[test]
+        fn fmap_both_err() {
+            let mut calls = 0;
+            let r: Result<i32, i32> = Err(-4);
+            let mapped = r.fmap_both(|x| {
+                calls += 1;
+                x * 10
+            });
+            assert_eq!(mapped, Err(-40));
+            assert_eq!(calls, 1);
+        }
+
+        #[test]
+        fn fmap_both_changes_type() {
+            let r: Result<i32, &str> = Err("bad");
+            let normalised = r.bimap(|x| x.unsigned_abs() as usize, str::len);
+            assert_eq!(normalised.fmap_both(|n| n > 2), Err(true));
+        }
+
+        #[derive(Debug, PartialEq)]
+        enum AppError {
+            Parse(&'static str),
+            Range(i32),
+        }
+
+        impl From<&'static str> for AppError {
+            fn from(e: &'static str) -> Self {
+                AppError::Parse(e)
+            }
+        }
+
+        fn parse(s: &str) -> Result<i32, &'static str> {
+            s.parse().map_err(|_| "not a number")
+        }
+
+        fn in_range(n: i32) -> Result<i32, AppError> {
+            if (0..=100).contains(&n) {
+                Ok(n)
+            } else {
+                Err(AppError::Range(n))
+            }
+        }
+
+        #[test]
+        fn bind_from_widens_first_error() {
+            assert_eq!(
+                parse("x").bind_from(in_range),
+                Err(AppError::Parse("not a number"))
+            );
+            assert_eq!(parse("500").bind_from(in_range), Err(AppError::Range(500)));
+        }
+
+        #[test]
+        fn bind_from_ok_path_matches_bind() {
+            assert_eq!(parse("42").bind_from(in_range), Ok(42));
+
+            let widened: Result<i32, AppError> = parse("42").map_err(AppError::from);
+            assert_eq!(parse("42").bind_from(in_range), widened.bind(in_range));
+        }
+
+        #[test]
+        fn bind_from_same_error_type() {
+            let r: Result<i32, &str> = Ok(5);
+            assert_eq!(r.bind_from(|x| Ok::<_, &str>(x + 1)), Ok(6));
+        }
+
+        #[test]
+        fn recover_ok_skips_handler() {
+            let mut called = false;
+            let r: Result<i32, &str> = Ok(5);
+            assert_eq!(
+                r.recover(|_| {
+                    called = true;
+                    0
+                }),
+                5
+            );
+            assert!(!called);
+        }
+
+        #[test]
+        fn recover_err_runs_handler() {
+            let r: Result<i32, &str> = Err("four");
+            assert_eq!(r.recover(|e| e.len() as i32), 4);
+        }
+
+        #[test]
+        fn recover_with_ok_skips_handler() {
+            let mut called = false;
+            let r: Result<i32, &str> = Ok(5);
+            let recovered = r.recover_with(|_| {
+                called = true;
+                Err::<i32, AppError>(AppError::Range(0))
+            });
+            assert_eq!(recovered, Ok(5));
+            assert!(!called);
+        }
+
+        #[test]
+        fn recover_with_can_recover_or_refail() {
+            let retry = |e: &'static str| match e {
+                "timeout" => Ok(0),
+                other => Err(AppError::Parse(other)),
+            };
+
+            let r: Result<i32, &str> = Err("timeout");
+            assert_eq!(r.recover_with(retry), Ok(0));
+
+            let r: Result<i32, &str> = Err("corrupt");
+            assert_eq!(r.recover_with(retry), Err(AppError::Parse("corrupt")));
+        }
+    }
+
+    mod foldable {
+        use super::*;
+
+        #[test]
+        fn fold_left() {
+            let r: Result<i32, &str> = Ok(5);
+            assert_eq!(r.fold_left(1, |acc, x| acc + x), 6);
+
+            let r: Result<i32, &str> = Err("error");
+            assert_eq!(r.fold_left(1, |acc, x| acc + x), 1);
+        }
+
+        #[test]
+        fn fold_right() {
+            let r: Result<i32, &str> = Ok(5);
+            assert_eq!(r.fold_right(1, |x, acc| x - acc), 4);
+
+            let r: Result<i32, &str> = Err("error");
+            assert_eq!(r.fold_right(1, |x, acc| x - acc), 1);
+        }
+
+        #[test]
+        fn find_any_all() {
+            let ok: Result<i32, &str> = Ok(4);
+            let err: Result<i32, &str> = Err("error");
+
+            assert_eq!(ok.find(|x| *x > 3), Some(4));
+            assert_eq!(err.find(|x| *x > 3), None);
+
+            assert!(ok.any(|x| *x > 3));
+            assert!(!err.any(|_| true));
+
+            assert!(!ok.all(|x| *x > 5));
+            assert!(err.all(|_| false));
+        }
+    }
+
     mod bifunctor {
         use super::*;
 
@@ -263,13 +796,13 @@ mod result_tests {
         fn bimap() {
             // Test Ok case
             let r: Result<i32, &str> = Ok(5);
-            let result = r.bimap(|x| x * 2, |s: &str| s.to_string());
+            let result = r.bimap(|x| x * 2, |s: &str| s.len());
             assert_eq!(result, Ok(10));
 
             // Test Err case
             let r: Result<i32, &str> = Err("failed");
-            let result = r.bimap(|x| x * 2, |s: &str| s.to_string());
-            assert_eq!(result, Err("failed".to_string()));
+            let result = r.bimap(|x| x * 2, |s: &str| s.len());
+            assert_eq!(result, Err(6));
         }
 
         #[test]
@@ -289,13 +822,13 @@ mod result_tests {
         fn second() {
             // Test Ok case
             let r: Result<i32, &str> = Ok(5);
-            let result = r.second(|s: &str| s.to_string());
+            let result = r.second(|s: &str| s.len());
             assert_eq!(result, Ok(5));
 
             // Test Err case
             let r: Result<i32, &str> = Err("failed");
-            let result = r.second(|s: &str| s.to_string());
-            assert_eq!(result, Err("failed".to_string()));
+            let result = r.second(|s: &str| s.len());
+            assert_eq!(result, Err(6));
         }
 
         #[test]
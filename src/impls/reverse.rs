@@ -0,0 +1,105 @@
+pub mod reverse_impls {
+    use crate::*;
+    use std::cmp::Reverse;
+
+    pub struct ReverseKind;
+
+    impl Generic1 for ReverseKind {
+        type Rep1<A> = Reverse<A>;
+    }
+
+    impl<A> Kinded1<A> for Reverse<A> {
+        type Kind1 = ReverseKind;
+    }
+
+    /// Maps the wrapped value while keeping the reversed ordering, so sort
+    /// keys can be transformed without unwrapping them.
+    impl<A> Functor<A> for Reverse<A> {
+        fn fmap<B, F: FnOnce(A) -> B>(self, f: F) -> Reverse<B> {
+            Reverse(f(self.0))
+        }
+    }
+
+    impl<A> Applicative<A> for Reverse<A> {
+        fn pure(a: A) -> Reverse<A> {
+            Reverse(a)
+        }
+
+        fn apply<B, F: FnOnce(A) -> B>(self, ff: Reverse<F>) -> Reverse<B> {
+            Reverse((ff.0)(self.0))
+        }
+    }
+}
+
+#[cfg(test)]
+mod reverse_tests {
+    use crate::*;
+    use std::cmp::Reverse;
+
+    mod functor {
+        use super::*;
+
+        #[test]
+        fn fmap() {
+            assert_eq!(Reverse(5).fmap(add_one), Reverse(6));
+            assert_eq!(Reverse(5).fmap(to_string), Reverse(to_string(5)));
+        }
+
+        #[test]
+        fn ordering_preserved_through_fmap() {
+            let (a, b) = (Reverse(1), Reverse(2));
+            assert!(a > b);
+            assert!(a.fmap(multiply_by_two) > b.fmap(multiply_by_two));
+        }
+
+        #[test]
+        #[cfg(not(feature = "no_std"))]
+        fn sort_by_mapped_key() {
+            let mut words = vec!["bb", "a", "ccc"];
+            words.sort_by_key(|w| Reverse(*w).fmap(str::len));
+            assert_eq!(words, vec!["ccc", "bb", "a"]);
+        }
+
+        #[test]
+        fn identity_law() {
+            // Identity: fmap id = id
+            assert_eq!(Reverse(5).fmap(identity), Reverse(5));
+        }
+
+        #[test]
+        fn composition_law() {
+            // Composition: fmap (g . f) = fmap g . fmap f
+            let lhs = Reverse(5).fmap(|x| multiply_by_two(add_one(x)));
+            let rhs = Reverse(5).fmap(add_one).fmap(multiply_by_two);
+            assert_eq!(lhs, rhs);
+        }
+    }
+
+    mod applicative {
+        use super::*;
+
+        #[test]
+        fn pure() {
+            assert_eq!(Reverse::pure(69), Reverse(69));
+        }
+
+        #[test]
+        fn apply() {
+            assert_eq!(Reverse(5).apply(Reverse(add_one)), Reverse(6));
+        }
+
+        #[test]
+        fn identity_law() {
+            // Identity: pure id <*> v = v
+            let v = Reverse(69);
+            assert_eq!(v.apply(Reverse::pure(identity)), v);
+        }
+
+        #[test]
+        fn homomorphism_law() {
+            // Homomorphism: pure f <*> pure x = pure (f x)
+            let lhs = Reverse::pure(69).apply(Reverse::pure(to_string));
+            assert_eq!(lhs, Reverse::pure(to_string(69)));
+        }
+    }
+}
@@ -0,0 +1,287 @@
+pub mod signed_impls {
+    use crate::*;
+    use std::fmt;
+    use std::marker::PhantomData;
+    use std::ops::{Add, Neg};
+
+    /// The tag of an always-positive quantity.
+    pub struct Positive;
+
+    /// The tag of an always-negative quantity.
+    pub struct Negative;
+
+    /// Sign tags for `Signed`.
+    pub trait Sign {
+        /// The tag of the negated quantity.
+        type Flipped: Sign;
+    }
+
+    impl Sign for Positive {
+        type Flipped = Negative;
+    }
+
+    impl Sign for Negative {
+        type Flipped = Positive;
+    }
+
+    /// A quantity tagged at the type level with its sign.
+    ///
+    /// The tag is a phantom, like the unit of `Measured`: `new` trusts the
+    /// caller, and `try_new` checks the value against zero. Mapping keeps the
+    /// tag, so the function passed to `fmap` is responsible for preserving
+    /// the sign. Negation flips the tag, and quantities with the same tag can
+    /// be added, since the sum of two positive (or two negative) values keeps
+    /// their sign. Quantities with different tags do not unify:
+    ///
+    /// ```compile_fail
+    /// use crab_fp::signed::signed_impls::{Negative, Positive, Signed};
+    ///
+    /// let credit = Signed::<Positive, f64>::new(10.0);
+    /// let debit = Signed::<Negative, f64>::new(-4.0);
+    /// let _ = credit + debit;
+    /// ```
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::*;
+    /// use crab_fp::signed::signed_impls::{Negative, Positive, Signed};
+    ///
+    /// let price = Signed::<Positive, f64>::try_new(2.5).unwrap();
+    /// let doubled = price.fmap(|p| p * 2.0);
+    /// let refund: Signed<Negative, f64> = -doubled;
+    /// assert_eq!(refund.into_value(), -5.0);
+    /// ```
+    pub struct Signed<S, T> {
+        value: T,
+        sign: PhantomData<S>,
+    }
+
+    impl<S, T> Signed<S, T> {
+        /// Tags a value with the sign `S`, without checking it.
+        pub fn new(value: T) -> Self {
+            Signed {
+                value,
+                sign: PhantomData,
+            }
+        }
+
+        /// Returns a reference to the untagged value.
+        pub fn value(&self) -> &T {
+            &self.value
+        }
+
+        /// Discards the sign and returns the value.
+        pub fn into_value(self) -> T {
+            self.value
+        }
+    }
+
+    impl<T: PartialOrd + Default> Signed<Positive, T> {
+        /// Tags a value as positive, or returns it if it is not greater than
+        /// zero (`T::default()`).
+        pub fn try_new(value: T) -> Result<Self, T> {
+            if value > T::default() {
+                Ok(Signed::new(value))
+            } else {
+                Err(value)
+            }
+        }
+    }
+
+    impl<T: PartialOrd + Default> Signed<Negative, T> {
+        /// Tags a value as negative, or returns it if it is not less than
+        /// zero (`T::default()`).
+        pub fn try_new(value: T) -> Result<Self, T> {
+            if value < T::default() {
+                Ok(Signed::new(value))
+            } else {
+                Err(value)
+            }
+        }
+    }
+
+    // Manual impls, since derives would also require the tag `S` to
+    // implement each trait.
+
+    impl<S, T: Clone> Clone for Signed<S, T> {
+        fn clone(&self) -> Self {
+            Signed::new(self.value.clone())
+        }
+    }
+
+    impl<S, T: Copy> Copy for Signed<S, T> {}
+
+    impl<S, T: PartialEq> PartialEq for Signed<S, T> {
+        fn eq(&self, other: &Self) -> bool {
+            self.value == other.value
+        }
+    }
+
+    impl<S, T: PartialOrd> PartialOrd for Signed<S, T> {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            self.value.partial_cmp(&other.value)
+        }
+    }
+
+    impl<S, T: fmt::Debug> fmt::Debug for Signed<S, T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_tuple("Signed")
+                .field(&self.value)
+                .field(&std::any::type_name::<S>())
+                .finish()
+        }
+    }
+
+    impl<S: Sign, T: Neg<Output = T>> Neg for Signed<S, T> {
+        type Output = Signed<S::Flipped, T>;
+
+        fn neg(self) -> Signed<S::Flipped, T> {
+            Signed::new(-self.value)
+        }
+    }
+
+    impl<S, T: Add<Output = T>> Add for Signed<S, T> {
+        type Output = Signed<S, T>;
+
+        fn add(self, other: Self) -> Self {
+            Signed::new(self.value + other.value)
+        }
+    }
+
+    pub struct SignedKind<S>(PhantomData<S>);
+
+    impl<S> Generic1 for SignedKind<S> {
+        type Rep1<A> = Signed<S, A>;
+    }
+
+    impl<S, A> Kinded1<A> for Signed<S, A> {
+        type Kind1 = SignedKind<S>;
+    }
+
+    impl<S, A> Functor<A> for Signed<S, A> {
+        fn fmap<B, F: FnOnce(A) -> B>(self, f: F) -> Signed<S, B> {
+            Signed::new(f(self.value))
+        }
+    }
+
+    impl<S, A> Applicative<A> for Signed<S, A> {
+        fn pure(a: A) -> Signed<S, A> {
+            Signed::new(a)
+        }
+
+        fn apply<B, F: FnOnce(A) -> B>(self, ff: Signed<S, F>) -> Signed<S, B> {
+            Signed::new((ff.value)(self.value))
+        }
+    }
+
+    impl<S, A> Monad<A> for Signed<S, A> {
+        fn bind<B, F: FnOnce(A) -> Signed<S, B>>(self, f: F) -> Signed<S, B> {
+            f(self.value)
+        }
+    }
+}
+
+#[cfg(test)]
+mod signed_tests {
+    use super::signed_impls::*;
+    use crate::*;
+
+    fn pos(x: f64) -> Signed<Positive, f64> {
+        Signed::<Positive, f64>::try_new(x).unwrap()
+    }
+
+    #[test]
+    fn try_new_checks_sign() {
+        assert_eq!(
+            Signed::<Positive, f64>::try_new(1.5).map(Signed::into_value),
+            Ok(1.5)
+        );
+        assert_eq!(Signed::<Positive, f64>::try_new(0.0), Err(0.0));
+        assert_eq!(
+            Signed::<Negative, i32>::try_new(-3).map(Signed::into_value),
+            Ok(-3)
+        );
+        assert_eq!(Signed::<Negative, i32>::try_new(3), Err(3));
+    }
+
+    #[test]
+    fn negation_flips_tag() {
+        let neg: Signed<Negative, f64> = -pos(2.0);
+        assert_eq!(neg.value(), &-2.0);
+        let back: Signed<Positive, f64> = -neg;
+        assert_eq!(back, pos(2.0));
+    }
+
+    #[test]
+    fn addition_keeps_tag() {
+        assert_eq!(pos(1.5) + pos(2.0), pos(3.5));
+    }
+
+    mod functor {
+        use super::*;
+
+        #[test]
+        fn fmap_keeps_positive_tag() {
+            let scaled: Signed<Positive, f64> = pos(2.5).fmap(|x| x * 4.0);
+            assert_eq!(scaled, pos(10.0));
+        }
+
+        #[test]
+        fn fmap_changes_value_type() {
+            let truncated: Signed<Positive, i64> = pos(2.6).fmap(|x| x as i64);
+            assert_eq!(truncated.into_value(), 2);
+        }
+
+        #[test]
+        fn identity_law() {
+            assert_eq!(pos(2.5).fmap(identity), pos(2.5));
+        }
+
+        #[test]
+        fn composition_law() {
+            let s = Signed::<Positive, i32>::new(5);
+            assert_eq!(
+                s.fmap(|x| multiply_by_two(add_one(x))),
+                s.fmap(add_one).fmap(multiply_by_two)
+            );
+        }
+    }
+
+    mod monad {
+        use super::*;
+
+        fn halve(x: i32) -> Signed<Negative, i32> {
+            Signed::new(x / 2)
+        }
+
+        fn decrement(x: i32) -> Signed<Negative, i32> {
+            Signed::new(x - 1)
+        }
+
+        #[test]
+        fn bind_keeps_tag() {
+            let s = Signed::<Negative, i32>::new(-8).bind(halve);
+            assert_eq!(s.into_value(), -4);
+        }
+
+        #[test]
+        fn left_identity_law() {
+            assert_eq!(Signed::pure(-6).bind(halve), halve(-6));
+        }
+
+        #[test]
+        fn right_identity_law() {
+            let m = Signed::<Negative, i32>::new(-3);
+            assert_eq!(m.bind(Signed::pure), m);
+        }
+
+        #[test]
+        fn associativity_law() {
+            let m = Signed::<Negative, i32>::new(-9);
+            assert_eq!(
+                m.bind(halve).bind(decrement),
+                m.bind(|x| halve(x).bind(decrement))
+            );
+        }
+    }
+}
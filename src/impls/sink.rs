@@ -0,0 +1,124 @@
+#[cfg(not(feature = "no_std"))]
+pub mod sink_impls {
+    use crate::*;
+
+    /// A consumer of values.
+    ///
+    /// A `Sink` wraps a callback that is invoked with every value pushed into
+    /// it. It is the consumer-side counterpart to the producer-side functors:
+    /// instead of mapping its output, a sink is adapted with `comap` to accept
+    /// a different input type.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::Contravariant;
+    /// use crab_fp::sink::sink_impls::Sink;
+    ///
+    /// let mut print_len = Sink::new(|s: String| println!("{}", s.len()))
+    ///     .comap(|x: i32| x.to_string());
+    /// print_len.push(1234);
+    /// ```
+    pub struct Sink<A>(Box<dyn FnMut(A)>);
+
+    impl<A> Sink<A> {
+        /// Creates a sink that passes every pushed value to `f`.
+        pub fn new<F: FnMut(A) + 'static>(f: F) -> Self {
+            Sink(Box::new(f))
+        }
+
+        /// Pushes a value into the sink.
+        pub fn push(&mut self, a: A) {
+            (self.0)(a)
+        }
+    }
+
+    pub struct SinkKind;
+
+    impl Generic1 for SinkKind {
+        type Rep1<A> = Sink<A>;
+    }
+
+    impl<A> Kinded1<A> for Sink<A> {
+        type Kind1 = SinkKind;
+    }
+
+    impl<A: 'static> Contravariant<A> for Sink<A> {
+        fn comap<B, F: FnMut(B) -> A + 'static>(mut self, mut f: F) -> Sink<B> {
+            Sink(Box::new(move |b| self.push(f(b))))
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(feature = "no_std"))]
+mod sink_tests {
+    use super::sink_impls::*;
+    use crate::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    fn collecting<A: 'static>() -> (Sink<A>, Rc<RefCell<Vec<A>>>) {
+        let seen = Rc::new(RefCell::new(vec![]));
+        let inner = Rc::clone(&seen);
+        let sink = Sink::new(move |a| inner.borrow_mut().push(a));
+        (sink, seen)
+    }
+
+    #[test]
+    fn push() {
+        let (mut sink, seen) = collecting();
+        sink.push(1);
+        sink.push(2);
+        assert_eq!(*seen.borrow(), vec![1, 2]);
+    }
+
+    mod contravariant {
+        use super::*;
+
+        #[test]
+        fn comap() {
+            let (sink, seen) = collecting::<String>();
+            let mut sink = sink.comap(|x: i32| to_string(x));
+            sink.push(1);
+            sink.push(23);
+            assert_eq!(*seen.borrow(), vec!["1".to_string(), "23".to_string()]);
+        }
+
+        #[test]
+        fn comap_applies_adapter_before_consumption() {
+            let calls = Rc::new(RefCell::new(vec![]));
+            let sink_calls = Rc::clone(&calls);
+            let sink =
+                Sink::new(move |s: String| sink_calls.borrow_mut().push(format!("consume {}", s)));
+            let adapter_calls = Rc::clone(&calls);
+            let mut sink = sink.comap(move |x: i32| {
+                adapter_calls.borrow_mut().push(format!("adapt {}", x));
+                to_string(x)
+            });
+
+            sink.push(7);
+            assert_eq!(*calls.borrow(), vec!["adapt 7", "consume 7"]);
+        }
+
+        #[test]
+        fn identity_law() {
+            let (sink, seen) = collecting();
+            let mut sink = sink.comap(identity);
+            sink.push(5);
+            assert_eq!(*seen.borrow(), vec![5]);
+        }
+
+        #[test]
+        fn composition_law() {
+            let (lhs, lhs_seen) = collecting::<String>();
+            let mut lhs = lhs.comap(to_string).comap(add_one);
+
+            let (rhs, rhs_seen) = collecting::<String>();
+            let mut rhs = rhs.comap(|x| to_string(add_one(x)));
+
+            lhs.push(1);
+            rhs.push(1);
+            assert_eq!(*lhs_seen.borrow(), *rhs_seen.borrow());
+        }
+    }
+}
@@ -0,0 +1,180 @@
+#[cfg(all(feature = "smallvec", not(feature = "no_std")))]
+pub mod small_vec_impls {
+    use crate::*;
+    use smallvec::{SmallVec, smallvec};
+
+    /// The kind of `SmallVec<[A; N]>`, which stores up to `N` values inline.
+    pub struct SmallVecKind<const N: usize>;
+
+    impl<const N: usize> Generic1 for SmallVecKind<N> {
+        type Rep1<A> = SmallVec<[A; N]>;
+    }
+
+    impl<A, const N: usize> Kinded1<A> for SmallVec<[A; N]> {
+        type Kind1 = SmallVecKind<N>;
+    }
+
+    /// Collects the mapped values into a new `SmallVec`, so results that fit
+    /// in `N` stay inline.
+    impl<A, const N: usize> Functor<A> for SmallVec<[A; N]> {
+        fn fmap<B, F: FnMut(A) -> B>(self, f: F) -> SmallVec<[B; N]> {
+            self.into_iter().map(f).collect()
+        }
+    }
+
+    /// The result is allocated once at its final length, so it spills to the
+    /// heap only when that length exceeds the inline capacity `N`.
+    impl<A: Clone, const N: usize> Applicative<A> for SmallVec<[A; N]> {
+        fn pure(b: A) -> SmallVec<[A; N]> {
+            smallvec![b]
+        }
+
+        fn apply<B, F: FnMut(A) -> B>(self, ff: SmallVec<[F; N]>) -> SmallVec<[B; N]> {
+            let mut result = SmallVec::with_capacity(self.len() * ff.len());
+            for mut f in ff {
+                for a in self.iter().cloned() {
+                    result.push(f(a));
+                }
+            }
+            result
+        }
+    }
+
+    impl<A: Clone, const N: usize> Monad<A> for SmallVec<[A; N]> {
+        fn bind<B, F: FnMut(A) -> SmallVec<[B; N]>>(self, f: F) -> SmallVec<[B; N]> {
+            self.into_iter().flat_map(f).collect()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(all(feature = "smallvec", not(feature = "no_std")))]
+mod small_vec_tests {
+    use crate::*;
+    use smallvec::{SmallVec, smallvec};
+
+    type Small<A> = SmallVec<[A; 4]>;
+
+    mod functor {
+        use super::*;
+
+        #[test]
+        fn fmap() {
+            let v: Small<i32> = smallvec![1, 2, 3];
+            let mapped = v.fmap(to_string);
+            assert_eq!(mapped.as_slice(), &["1", "2", "3"]);
+        }
+
+        #[test]
+        fn small_input_stays_inline() {
+            let v: Small<i32> = smallvec![1, 2, 3];
+            let mapped = v.fmap(|x| x as u64 * 2);
+            assert!(!mapped.spilled());
+            assert_eq!(mapped.as_slice(), &[2, 4, 6]);
+        }
+
+        #[test]
+        fn large_input_spills() {
+            let v: Small<i32> = (0..10).collect();
+            let mapped = v.fmap(add_one);
+            assert!(mapped.spilled());
+            assert_eq!(mapped.len(), 10);
+        }
+
+        #[test]
+        fn identity_law() {
+            let v: Small<i32> = smallvec![1, 2, 3];
+            assert_eq!(v.clone().fmap(identity), v);
+        }
+
+        #[test]
+        fn composition_law() {
+            let v: Small<i32> = smallvec![1, 2, 3];
+            let lhs = v.clone().fmap(|x| multiply_by_two(add_one(x)));
+            let rhs = v.fmap(add_one).fmap(multiply_by_two);
+            assert_eq!(lhs, rhs);
+        }
+    }
+
+    mod applicative {
+        use super::*;
+
+        #[test]
+        fn pure() {
+            let v = Small::<i32>::pure(69);
+            assert_eq!(v.as_slice(), &[69]);
+            assert!(!v.spilled());
+        }
+
+        #[test]
+        fn apply() {
+            let v: Small<i32> = smallvec![1, 2];
+            let fs: Small<fn(i32) -> i32> = smallvec![fn_ptr!(add_one), fn_ptr!(multiply_by_two)];
+            assert_eq!(v.apply(fs).as_slice(), &[2, 3, 2, 4]);
+        }
+
+        #[test]
+        fn identity_law() {
+            // Identity: pure id <*> v = v
+            let v: Small<i32> = smallvec![1, 2, 3];
+            let p: Small<fn(i32) -> i32> = smallvec![fn_ptr!(identity)];
+            assert_eq!(v.clone().apply(p), v);
+        }
+
+        #[test]
+        fn homomorphism_law() {
+            // Homomorphism: pure f <*> pure x = pure (f x)
+            let f: Small<fn(i32) -> i32> = smallvec![fn_ptr!(square)];
+            let x: Small<i32> = smallvec![7];
+            assert_eq!(x.apply(f).as_slice(), &[square(7)]);
+        }
+
+        #[test]
+        fn interchange_law() {
+            // Interchange: u <*> pure y = pure ($ y) <*> u
+            let y = 3;
+            let u: Small<fn(i32) -> i32> = smallvec![fn_ptr!(add_one), fn_ptr!(square)];
+            let lhs = Small::pure(y).apply(u.clone());
+            let rhs = u.apply(Small::pure(move |f: fn(i32) -> i32| f(y)));
+            assert_eq!(lhs, rhs);
+        }
+    }
+
+    mod monad {
+        use super::*;
+
+        fn around(x: i32) -> Small<i32> {
+            smallvec![x - 1, x + 1]
+        }
+
+        #[test]
+        fn bind() {
+            let v: Small<i32> = smallvec![10, 20];
+            assert_eq!(v.bind(around).as_slice(), &[9, 11, 19, 21]);
+        }
+
+        #[test]
+        fn left_identity_law() {
+            // Left identity: return a >>= f = f a
+            let m = Small::<i32>::pure(5);
+            assert_eq!(m.bind(around), around(5));
+        }
+
+        #[test]
+        fn right_identity_law() {
+            // Right identity: m >>= return = m
+            let m: Small<i32> = smallvec![1, 2, 3];
+            assert_eq!(m.clone().bind(|x| smallvec![x]), m);
+        }
+
+        #[test]
+        fn associativity_law() {
+            // Associativity: (m >>= f) >>= g = m >>= (\x -> f x >>= g)
+            let m: Small<i32> = smallvec![1, 2];
+            let g = |x: i32| smallvec![x * 10] as Small<i32>;
+            let lhs = m.clone().bind(around).bind(g);
+            let rhs = m.bind(|x| around(x).bind(g));
+            assert_eq!(lhs, rhs);
+        }
+    }
+}
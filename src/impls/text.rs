@@ -0,0 +1,147 @@
+#[cfg(feature = "no_std")]
+pub mod text_impls {
+    use crate::fixed_string::String;
+    use std::fmt;
+
+    pub use crate::fixed_string::CapacityError;
+
+    /// A fixed-capacity UTF-8 string for `no_std` builds.
+    ///
+    /// `Text` stores up to 256 bytes inline, without allocating. Operations
+    /// that would grow it past that capacity return a `CapacityError` instead
+    /// of panicking, and leave the existing text untouched.
+    ///
+    /// `Text` is not generic, so it has no `Functor` instance; `map_chars`
+    /// plays that role for the characters it contains.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::text::text_impls::Text;
+    ///
+    /// let text = Text::try_from("hello").unwrap();
+    /// let upper = text.map_chars(|c| c.to_ascii_uppercase()).unwrap();
+    /// assert_eq!(upper.as_str(), "HELLO");
+    /// ```
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct Text(String);
+
+    impl Text {
+        /// Creates an empty text.
+        pub const fn new() -> Self {
+            Text(String::new())
+        }
+
+        /// Returns the length in bytes.
+        pub const fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        /// Returns `true` if the text is empty.
+        pub const fn is_empty(&self) -> bool {
+            self.0.len() == 0
+        }
+
+        /// Returns the text as a string slice.
+        pub fn as_str(&self) -> &str {
+            self.0.as_str()
+        }
+
+        /// Appends a char, or fails if it does not fit.
+        pub fn push(&mut self, c: char) -> Result<(), CapacityError> {
+            self.0.try_push(c)
+        }
+
+        /// Appends a string slice, or fails if it does not fit.
+        pub fn push_str(&mut self, s: &str) -> Result<(), CapacityError> {
+            self.0.try_push_str(s)
+        }
+
+        /// Maps a function over every char, building a new text.
+        ///
+        /// A char may encode to more bytes than the one it replaces, so the
+        /// result can outgrow the capacity even when the input fits; that case
+        /// is reported as a `CapacityError`.
+        pub fn map_chars<F: FnMut(char) -> char>(&self, f: F) -> Result<Text, CapacityError> {
+            let mut mapped = Text::new();
+            for c in self.as_str().chars().map(f) {
+                mapped.push(c)?;
+            }
+            Ok(mapped)
+        }
+    }
+
+    impl Default for Text {
+        fn default() -> Self {
+            Text::new()
+        }
+    }
+
+    impl TryFrom<&str> for Text {
+        type Error = CapacityError;
+
+        fn try_from(s: &str) -> Result<Self, CapacityError> {
+            let mut text = Text::new();
+            text.push_str(s)?;
+            Ok(text)
+        }
+    }
+
+    impl fmt::Debug for Text {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt::Debug::fmt(self.as_str(), f)
+        }
+    }
+
+    impl fmt::Display for Text {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "no_std")]
+mod text_tests {
+    use super::text_impls::*;
+
+    #[test]
+    fn map_chars_to_uppercase() {
+        let text = Text::try_from("crab fp").unwrap();
+        let upper = text.map_chars(|c| c.to_ascii_uppercase()).unwrap();
+        assert_eq!(upper.as_str(), "CRAB FP");
+        assert_eq!(text.as_str(), "crab fp");
+    }
+
+    #[test]
+    fn map_chars_identity() {
+        let text = Text::try_from("unchanged").unwrap();
+        assert_eq!(text.map_chars(|c| c), Ok(text.clone()));
+    }
+
+    #[test]
+    fn map_chars_overflow_is_an_error() {
+        let mut text = Text::new();
+        for _ in 0..200 {
+            text.push('a').unwrap();
+        }
+        // Each 'é' takes two bytes, so 200 of them exceed the 256 byte capacity
+        assert_eq!(text.map_chars(|_| 'é'), Err(CapacityError));
+    }
+
+    #[test]
+    fn push_past_capacity_is_an_error() {
+        let mut text = Text::new();
+        for _ in 0..256 {
+            text.push('x').unwrap();
+        }
+        assert_eq!(text.push('x'), Err(CapacityError));
+        assert_eq!(text.len(), 256);
+    }
+
+    #[test]
+    fn try_from_too_long_is_an_error() {
+        let long = [b'z'; 300];
+        let long = core::str::from_utf8(&long).unwrap();
+        assert_eq!(Text::try_from(long), Err(CapacityError));
+    }
+}
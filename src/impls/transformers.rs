@@ -0,0 +1,423 @@
+pub mod transformers_impls {
+    use crate::*;
+
+    /// The `Result` monad transformer.
+    ///
+    /// `ResultT<K, A, E>` wraps a computation in the monad of kind `K` whose
+    /// value is a `Result<A, E>`, and lets the two layers be bound as one:
+    /// `bind` runs the outer monad and continues only with `Ok` values, while
+    /// an `Err` short-circuits the rest of the computation without leaving the
+    /// outer monad. With `K = VecKind` this models nondeterministic fallible
+    /// computations; with `K = OptionKind` both the `Option` and the `Result`
+    /// layers can fail independently.
+    ///
+    /// `ResultT` has no `Functor`/`Monad` instance: `bind` re-wraps an `Err`
+    /// in the outer monad, which needs `Apply1<K, Result<B, E>>` to be
+    /// `Applicative` for the caller's choice of `B`, and `Monad::bind` cannot
+    /// add bounds on `B`.
+    ///
+    /// # Type Parameters
+    /// * `K` - The kind of the outer monad
+    /// * `A` - The type of the successful value
+    /// * `E` - The type of the error
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::option::option_impls::OptionKind;
+    /// use crab_fp::transformers::transformers_impls::ResultT;
+    ///
+    /// let checked: ResultT<OptionKind, i32, &str> = ResultT::lift(Some(4))
+    ///     .bind(|x| ResultT::new(if x > 0 { Some(Ok(x * 10)) } else { Some(Err("negative")) }));
+    /// assert_eq!(checked.run_result_t(), Some(Ok(40)));
+    /// ```
+    pub struct ResultT<K: Generic1, A, E>(pub Apply1<K, Result<A, E>>);
+
+    impl<K: Generic1, A, E> ResultT<K, A, E> {
+        /// Wraps a monadic computation producing a `Result`.
+        pub fn new(inner: Apply1<K, Result<A, E>>) -> Self {
+            ResultT(inner)
+        }
+
+        /// Unwraps the transformer, returning the outer monad.
+        pub fn run_result_t(self) -> Apply1<K, Result<A, E>> {
+            self.0
+        }
+
+        /// Lifts a successful value into the transformer.
+        pub fn pure(a: A) -> Self
+        where
+            Apply1<K, Result<A, E>>: Applicative<Result<A, E>, Kind1 = K>,
+        {
+            ResultT(<Apply1<K, Result<A, E>> as Applicative<Result<A, E>>>::pure(Ok(a)))
+        }
+
+        /// Lifts a computation in the outer monad into the transformer,
+        /// treating its value as a success.
+        pub fn lift(m: Apply1<K, A>) -> Self
+        where
+            Apply1<K, A>: Functor<A, Kind1 = K>,
+        {
+            ResultT(m.fmap(Ok))
+        }
+
+        /// Maps a function over the successful value.
+        pub fn fmap<B, F: FnMut(A) -> B>(self, mut f: F) -> ResultT<K, B, E>
+        where
+            Apply1<K, Result<A, E>>: Functor<Result<A, E>, Kind1 = K>,
+        {
+            ResultT(self.0.fmap(|r| r.map(&mut f)))
+        }
+
+        /// Sequences a computation that depends on the successful value.
+        ///
+        /// An `Err` is passed through the outer monad unchanged and `f` is not
+        /// called for it.
+        pub fn bind<B, F: FnMut(A) -> ResultT<K, B, E>>(self, mut f: F) -> ResultT<K, B, E>
+        where
+            Apply1<K, Result<A, E>>: Monad<Result<A, E>, Kind1 = K>,
+            Apply1<K, Result<B, E>>: Applicative<Result<B, E>, Kind1 = K>,
+        {
+            ResultT(self.0.bind(|r| match r {
+                Ok(a) => f(a).0,
+                Err(e) => <Apply1<K, Result<B, E>> as Applicative<Result<B, E>>>::pure(Err(e)),
+            }))
+        }
+    }
+
+    /// The `Option` monad transformer.
+    ///
+    /// `OptionT<K, A>` wraps a computation in the monad of kind `K` whose
+    /// value is an `Option<A>`. `bind` continues only with `Some` values, and
+    /// a `None` ends that branch of the computation without leaving the outer
+    /// monad. With `K = VecKind` this models nondeterministic computations
+    /// whose branches can be pruned.
+    ///
+    /// `OptionT` is not a `Monad` instance either: to prune a branch `bind`
+    /// has to build `None` in the outer monad at the caller's result type,
+    /// so each call needs `Apply1<K, Option<B>>: Applicative`, which a trait
+    /// impl would have to promise for every `B` up front.
+    ///
+    /// # Type Parameters
+    /// * `K` - The kind of the outer monad
+    /// * `A` - The type of the value
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::option::option_impls::OptionKind;
+    /// use crab_fp::transformers::transformers_impls::OptionT;
+    ///
+    /// let halve = |x: i32| OptionT::new(Some(if x % 2 == 0 { Some(x / 2) } else { None }));
+    /// let even: OptionT<OptionKind, i32> = OptionT::lift(Some(8)).bind(halve);
+    /// assert_eq!(even.run_option_t(), Some(Some(4)));
+    ///
+    /// let odd: OptionT<OptionKind, i32> = OptionT::lift(Some(7)).bind(halve);
+    /// assert_eq!(odd.run_option_t(), Some(None));
+    /// ```
+    pub struct OptionT<K: Generic1, A>(pub Apply1<K, Option<A>>);
+
+    impl<K: Generic1, A> OptionT<K, A> {
+        /// Wraps a monadic computation producing an `Option`.
+        pub fn new(inner: Apply1<K, Option<A>>) -> Self {
+            OptionT(inner)
+        }
+
+        /// Unwraps the transformer, returning the outer monad.
+        pub fn run_option_t(self) -> Apply1<K, Option<A>> {
+            self.0
+        }
+
+        /// Lifts a value into the transformer.
+        pub fn pure(a: A) -> Self
+        where
+            Apply1<K, Option<A>>: Applicative<Option<A>, Kind1 = K>,
+        {
+            OptionT(<Apply1<K, Option<A>> as Applicative<Option<A>>>::pure(
+                Some(a),
+            ))
+        }
+
+        /// Lifts a computation in the outer monad into the transformer,
+        /// treating its value as present.
+        pub fn lift(m: Apply1<K, A>) -> Self
+        where
+            Apply1<K, A>: Functor<A, Kind1 = K>,
+        {
+            OptionT(m.fmap(Some))
+        }
+
+        /// Maps a function over the present value.
+        pub fn fmap<B, F: FnMut(A) -> B>(self, mut f: F) -> OptionT<K, B>
+        where
+            Apply1<K, Option<A>>: Functor<Option<A>, Kind1 = K>,
+        {
+            OptionT(self.0.fmap(|o| o.map(&mut f)))
+        }
+
+        /// Sequences a computation that depends on the present value.
+        ///
+        /// A `None` is passed through the outer monad unchanged and `f` is not
+        /// called for it.
+        pub fn bind<B, F: FnMut(A) -> OptionT<K, B>>(self, mut f: F) -> OptionT<K, B>
+        where
+            Apply1<K, Option<A>>: Monad<Option<A>, Kind1 = K>,
+            Apply1<K, Option<B>>: Applicative<Option<B>, Kind1 = K>,
+        {
+            OptionT(self.0.bind(|o| match o {
+                Some(a) => f(a).0,
+                None => <Apply1<K, Option<B>> as Applicative<Option<B>>>::pure(None),
+            }))
+        }
+    }
+
+    /// Monad transformers whose base monad can be lifted into them.
+    ///
+    /// `lift` embeds an action of the inner monad into the transformer
+    /// without adding any effect of its own, so code generic over the
+    /// transformer can reuse base-monad actions uniformly.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::MonadTrans;
+    /// use crab_fp::option::option_impls::OptionKind;
+    /// use crab_fp::transformers::transformers_impls::{OptionT, ResultT};
+    ///
+    /// fn lift_some<T: MonadTrans<i32, Inner = OptionKind>>(x: i32) -> T {
+    ///     T::lift(Some(x))
+    /// }
+    ///
+    /// let r: ResultT<OptionKind, i32, ()> = lift_some(3);
+    /// let o: OptionT<OptionKind, i32> = lift_some(3);
+    /// assert_eq!(r.run_result_t(), Some(Ok(3)));
+    /// assert_eq!(o.run_option_t(), Some(Some(3)));
+    /// ```
+    pub trait MonadTrans<A> {
+        /// The kind of the inner monad.
+        type Inner: Generic1;
+
+        /// Lifts an action of the inner monad into the transformer.
+        fn lift(m: Apply1<Self::Inner, A>) -> Self;
+    }
+
+    impl<K: Generic1, A, E> MonadTrans<A> for ResultT<K, A, E>
+    where
+        Apply1<K, A>: Functor<A, Kind1 = K>,
+    {
+        type Inner = K;
+
+        fn lift(m: Apply1<K, A>) -> Self {
+            ResultT::lift(m)
+        }
+    }
+
+    impl<K: Generic1, A> MonadTrans<A> for OptionT<K, A>
+    where
+        Apply1<K, A>: Functor<A, Kind1 = K>,
+    {
+        type Inner = K;
+
+        fn lift(m: Apply1<K, A>) -> Self {
+            OptionT::lift(m)
+        }
+    }
+}
+
+#[cfg(test)]
+mod transformers_tests {
+    mod result_t {
+        use super::super::transformers_impls::*;
+        use crate::option::option_impls::OptionKind;
+
+        type OptResult<A> = ResultT<OptionKind, A, &'static str>;
+
+        fn parse(s: &'static str) -> OptResult<i32> {
+            ResultT::new(if s.is_empty() {
+                None
+            } else {
+                Some(s.parse::<i32>().map_err(|_| "not a number"))
+            })
+        }
+
+        fn halve(x: i32) -> OptResult<i32> {
+            ResultT::new(Some(if x % 2 == 0 { Ok(x / 2) } else { Err("odd") }))
+        }
+
+        #[test]
+        fn pure() {
+            assert_eq!(OptResult::pure(5).run_result_t(), Some(Ok(5)));
+        }
+
+        #[test]
+        fn lift() {
+            assert_eq!(OptResult::lift(Some(5)).run_result_t(), Some(Ok(5)));
+            assert_eq!(OptResult::<i32>::lift(None).run_result_t(), None);
+        }
+
+        #[test]
+        fn fmap() {
+            assert_eq!(parse("21").fmap(|x| x * 2).run_result_t(), Some(Ok(42)));
+            assert_eq!(
+                parse("x").fmap(|x| x * 2).run_result_t(),
+                Some(Err("not a number"))
+            );
+        }
+
+        #[test]
+        fn bind_success() {
+            assert_eq!(
+                parse("8").bind(halve).bind(halve).run_result_t(),
+                Some(Ok(2))
+            );
+        }
+
+        #[test]
+        fn bind_result_layer_fails() {
+            let mut calls = 0;
+            let result = parse("6").bind(halve).bind(|x| {
+                calls += 1;
+                halve(x)
+            });
+            assert_eq!(result.run_result_t(), Some(Err("odd")));
+            assert_eq!(calls, 1);
+
+            let result = parse("x").bind(halve);
+            assert_eq!(result.run_result_t(), Some(Err("not a number")));
+        }
+
+        #[test]
+        fn bind_option_layer_fails() {
+            assert_eq!(parse("").bind(halve).run_result_t(), None);
+            assert_eq!(
+                parse("4")
+                    .bind(|_| OptResult::<i32>::new(None))
+                    .run_result_t(),
+                None
+            );
+        }
+
+        // Left identity: return a >>= f = f a
+        #[test]
+        fn left_identity() {
+            assert_eq!(
+                OptResult::pure(4).bind(halve).run_result_t(),
+                halve(4).run_result_t()
+            );
+        }
+
+        // Right identity: m >>= return = m
+        #[test]
+        fn right_identity() {
+            for s in ["4", "x", ""] {
+                assert_eq!(
+                    parse(s).bind(OptResult::pure).run_result_t(),
+                    parse(s).run_result_t()
+                );
+            }
+        }
+
+        // Associativity: (m >>= f) >>= g = m >>= (\x -> f x >>= g)
+        #[test]
+        fn associativity() {
+            for s in ["8", "6", "x", ""] {
+                let lhs = parse(s).bind(halve).bind(halve);
+                let rhs = parse(s).bind(|x| halve(x).bind(halve));
+                assert_eq!(lhs.run_result_t(), rhs.run_result_t());
+            }
+        }
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    mod result_t_vec {
+        use super::super::transformers_impls::*;
+        use crate::vec::vec_impls::VecKind;
+
+        #[test]
+        fn nondeterministic_fallible() {
+            let choices: ResultT<VecKind, i32, String> = ResultT::lift(vec![1, 2, 3]);
+            let result = choices.bind(|x| {
+                ResultT::new(vec![
+                    if x == 2 {
+                        Err(format!("{x} rejected"))
+                    } else {
+                        Ok(x)
+                    },
+                    Ok(x * 10),
+                ])
+            });
+            assert_eq!(
+                result.run_result_t(),
+                vec![
+                    Ok(1),
+                    Ok(10),
+                    Err("2 rejected".to_string()),
+                    Ok(20),
+                    Ok(3),
+                    Ok(30)
+                ]
+            );
+        }
+
+        #[test]
+        fn err_branch_is_not_continued() {
+            let start: ResultT<VecKind, i32, &str> = ResultT::new(vec![Ok(1), Err("bad")]);
+            let result = start.bind(|x| ResultT::new(vec![Ok(x), Ok(-x)]));
+            assert_eq!(result.run_result_t(), vec![Ok(1), Ok(-1), Err("bad")]);
+        }
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    mod option_t_vec {
+        use super::super::transformers_impls::*;
+        use crate::vec::vec_impls::VecKind;
+
+        type Branches<A> = OptionT<VecKind, A>;
+
+        fn positive(x: i32) -> Branches<i32> {
+            OptionT::new(vec![if x > 0 { Some(x) } else { None }])
+        }
+
+        fn lift_generic<T: MonadTrans<i32, Inner = VecKind>>(v: Vec<i32>) -> T {
+            T::lift(v)
+        }
+
+        #[test]
+        fn lift_keeps_every_branch() {
+            let lifted: Branches<i32> = MonadTrans::lift(vec![1, 2, 3]);
+            assert_eq!(lifted.run_option_t(), vec![Some(1), Some(2), Some(3)]);
+        }
+
+        #[test]
+        fn lifted_action_binds_per_branch() {
+            let result = Branches::lift(vec![-1, 2, 0, 3])
+                .bind(positive)
+                .fmap(|x| x * 10);
+            assert_eq!(result.run_option_t(), vec![None, Some(20), None, Some(30)]);
+        }
+
+        #[test]
+        fn none_branch_is_not_continued() {
+            let mut calls = 0;
+            let start: Branches<i32> = OptionT::new(vec![Some(1), None]);
+            let result = start.bind(|x| {
+                calls += 1;
+                OptionT::new(vec![Some(x), Some(-x)])
+            });
+            assert_eq!(result.run_option_t(), vec![Some(1), Some(-1), None]);
+            assert_eq!(calls, 1);
+        }
+
+        #[test]
+        fn lift_is_uniform_across_transformers() {
+            let o: Branches<i32> = lift_generic(vec![1, 2]);
+            let r: ResultT<VecKind, i32, &str> = lift_generic(vec![1, 2]);
+            assert_eq!(o.run_option_t(), vec![Some(1), Some(2)]);
+            assert_eq!(r.run_result_t(), vec![Ok(1), Ok(2)]);
+        }
+
+        // lift (return a) = return a
+        #[test]
+        fn lift_preserves_return() {
+            let lifted: Branches<i32> = MonadTrans::lift(vec![7]);
+            assert_eq!(lifted.run_option_t(), Branches::pure(7).run_option_t());
+        }
+    }
+}
@@ -0,0 +1,153 @@
+#[cfg(not(feature = "no_std"))]
+pub mod transpose_impls {
+    /// Swaps the two layers of a nested container.
+    ///
+    /// Covers the common two-layer nestings of `Vec` with `Option` and
+    /// `Result`, in both directions. Going from `Vec` outward is `sequence`:
+    /// the first `None` or `Err` wins. Going from `Vec` inward distributes the
+    /// outer layer over the elements, and a missing vector becomes a single
+    /// `None` or `Err`, as in Haskell's `sequenceA`.
+    ///
+    /// `Option<Result<A, E>>` and `Result<Option<A>, E>` are left to the
+    /// standard library's inherent `transpose`.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::Transpose;
+    ///
+    /// assert_eq!(vec![Some(1), Some(2)].transpose(), Some(vec![1, 2]));
+    /// assert_eq!(Some(vec![1, 2]).transpose(), vec![Some(1), Some(2)]);
+    /// ```
+    pub trait Transpose {
+        /// The nesting with the layers swapped.
+        type Output;
+
+        /// Swaps the outer and inner layers.
+        fn transpose(self) -> Self::Output;
+    }
+
+    impl<A> Transpose for Vec<Option<A>> {
+        type Output = Option<Vec<A>>;
+
+        fn transpose(self) -> Option<Vec<A>> {
+            self.into_iter().collect()
+        }
+    }
+
+    impl<A> Transpose for Option<Vec<A>> {
+        type Output = Vec<Option<A>>;
+
+        fn transpose(self) -> Vec<Option<A>> {
+            match self {
+                Some(v) => v.into_iter().map(Some).collect(),
+                None => vec![None],
+            }
+        }
+    }
+
+    impl<A, E> Transpose for Vec<Result<A, E>> {
+        type Output = Result<Vec<A>, E>;
+
+        fn transpose(self) -> Result<Vec<A>, E> {
+            self.into_iter().collect()
+        }
+    }
+
+    impl<A, E> Transpose for Result<Vec<A>, E> {
+        type Output = Vec<Result<A, E>>;
+
+        fn transpose(self) -> Vec<Result<A, E>> {
+            match self {
+                Ok(v) => v.into_iter().map(Ok).collect(),
+                Err(e) => vec![Err(e)],
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(feature = "no_std"))]
+mod transpose_tests {
+    use crate::*;
+
+    mod vec_option {
+        use super::*;
+
+        #[test]
+        fn all_some() {
+            assert_eq!(
+                vec![Some(1), Some(2), Some(3)].transpose(),
+                Some(vec![1, 2, 3])
+            );
+        }
+
+        #[test]
+        fn any_none() {
+            assert_eq!(vec![Some(1), None, Some(3)].transpose(), None);
+        }
+
+        #[test]
+        fn empty() {
+            assert_eq!(Vec::<Option<i32>>::new().transpose(), Some(vec![]));
+        }
+    }
+
+    mod option_vec {
+        use super::*;
+
+        #[test]
+        fn some() {
+            assert_eq!(Some(vec![1, 2]).transpose(), vec![Some(1), Some(2)]);
+            assert_eq!(Some(Vec::<i32>::new()).transpose(), vec![]);
+        }
+
+        #[test]
+        fn none() {
+            assert_eq!(None::<Vec<i32>>.transpose(), vec![None]);
+        }
+    }
+
+    mod vec_result {
+        use super::*;
+
+        #[test]
+        fn all_ok() {
+            let v: Vec<Result<i32, &str>> = vec![Ok(1), Ok(2)];
+            assert_eq!(v.transpose(), Ok(vec![1, 2]));
+        }
+
+        #[test]
+        fn first_err_wins() {
+            let v = vec![Ok(1), Err("second"), Err("third")];
+            assert_eq!(v.transpose(), Err("second"));
+        }
+
+        #[test]
+        fn empty() {
+            let v: Vec<Result<i32, &str>> = vec![];
+            assert_eq!(v.transpose(), Ok(vec![]));
+        }
+    }
+
+    mod result_vec {
+        use super::*;
+
+        #[test]
+        fn ok() {
+            let r: Result<Vec<i32>, &str> = Ok(vec![1, 2]);
+            assert_eq!(r.transpose(), vec![Ok(1), Ok(2)]);
+        }
+
+        #[test]
+        fn err() {
+            let r: Result<Vec<i32>, &str> = Err("bad");
+            assert_eq!(r.transpose(), vec![Err("bad")]);
+        }
+
+        #[test]
+        fn round_trip() {
+            let r: Result<Vec<i32>, &str> = Ok(vec![4, 5]);
+            assert_eq!(r.clone().transpose().transpose(), r);
+        }
+    }
+}
@@ -0,0 +1,192 @@
+#[cfg(not(feature = "no_std"))]
+pub mod tree_impls {
+    use crate::*;
+
+    /// A rose tree: a value with any number of child trees.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::*;
+    /// use crab_fp::tree::tree_impls::Tree;
+    ///
+    /// let tree = Tree::node(1, vec![Tree::leaf(2), Tree::leaf(3)]);
+    /// assert_eq!(tree.fmap(|x| x * 10), Tree::node(10, vec![Tree::leaf(20), Tree::leaf(30)]));
+    /// ```
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Tree<A> {
+        pub value: A,
+        pub children: Vec<Tree<A>>,
+    }
+
+    impl<A> Tree<A> {
+        /// Creates a tree with no children.
+        pub fn leaf(value: A) -> Self {
+            Tree {
+                value,
+                children: vec![],
+            }
+        }
+
+        /// Creates a tree with the given children.
+        pub fn node(value: A, children: Vec<Tree<A>>) -> Self {
+            Tree { value, children }
+        }
+    }
+
+    pub struct TreeKind;
+
+    impl Generic1 for TreeKind {
+        type Rep1<A> = Tree<A>;
+    }
+
+    impl<A> Kinded1<A> for Tree<A> {
+        type Kind1 = TreeKind;
+    }
+
+    fn map_tree<A, B, F: FnMut(A) -> B>(tree: Tree<A>, f: &mut F) -> Tree<B> {
+        Tree {
+            value: f(tree.value),
+            children: tree.children.into_iter().map(|c| map_tree(c, f)).collect(),
+        }
+    }
+
+    /// Replaces every node with the tree `f` produces for its value, keeping the
+    /// produced tree's own children first and grafting the bound children
+    /// after them.
+    fn bind_tree<A, B, F: FnMut(A) -> Tree<B>>(tree: Tree<A>, f: &mut F) -> Tree<B> {
+        let Tree { value, children } = f(tree.value);
+        let mut grafted = children;
+        grafted.extend(tree.children.into_iter().map(|c| bind_tree(c, f)));
+        Tree {
+            value,
+            children: grafted,
+        }
+    }
+
+    impl<A> Functor<A> for Tree<A> {
+        fn fmap<B, F: FnMut(A) -> B>(self, mut f: F) -> Tree<B> {
+            map_tree(self, &mut f)
+        }
+    }
+
+    impl<A: Clone> Applicative<A> for Tree<A> {
+        fn pure(b: A) -> Tree<A> {
+            Tree::leaf(b)
+        }
+
+        /// Maps every function in `ff` over a copy of this tree, grafting the
+        /// copies into the shape of `ff`.
+        fn apply<B, F: FnMut(A) -> B>(self, ff: Tree<F>) -> Tree<B> {
+            bind_tree(ff, &mut |mut f| map_tree(self.clone(), &mut f))
+        }
+    }
+
+    impl<A: Clone> Monad<A> for Tree<A> {
+        fn bind<B, F: FnMut(A) -> Tree<B>>(self, mut f: F) -> Tree<B> {
+            bind_tree(self, &mut f)
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(feature = "no_std"))]
+mod tree_tests {
+    use super::tree_impls::*;
+    use crate::*;
+
+    fn sample() -> Tree<i32> {
+        Tree::node(
+            1,
+            vec![
+                Tree::node(2, vec![Tree::leaf(4), Tree::leaf(5)]),
+                Tree::leaf(3),
+            ],
+        )
+    }
+
+    mod functor {
+        use super::*;
+
+        #[test]
+        fn fmap() {
+            let expected = Tree::node(
+                2,
+                vec![
+                    Tree::node(4, vec![Tree::leaf(8), Tree::leaf(10)]),
+                    Tree::leaf(6),
+                ],
+            );
+            assert_eq!(sample().fmap(multiply_by_two), expected);
+        }
+
+        #[test]
+        fn identity_law() {
+            assert_eq!(sample().fmap(identity), sample());
+        }
+
+        #[test]
+        fn composition_law() {
+            let lhs = sample().fmap(add_one).fmap(to_string);
+            let rhs = sample().fmap(|x| to_string(add_one(x)));
+            assert_eq!(lhs, rhs);
+        }
+    }
+
+    mod applicative {
+        use super::*;
+
+        #[test]
+        fn pure() {
+            assert_eq!(Tree::pure(69), Tree::leaf(69));
+        }
+
+        #[test]
+        fn ap() {
+            let fs = Tree::node(add_one as fn(i32) -> i32, vec![Tree::leaf(square)]);
+            let values = Tree::node(2, vec![Tree::leaf(3)]);
+            let expected = Tree::node(3, vec![Tree::leaf(4), Tree::node(4, vec![Tree::leaf(9)])]);
+            assert_eq!(values.apply(fs), expected);
+        }
+
+        #[test]
+        fn identity_law() {
+            // Identity: pure id <*> v = v
+            assert_eq!(sample().apply(Tree::pure(identity)), sample());
+        }
+    }
+
+    mod monad {
+        use super::*;
+
+        #[test]
+        fn bind_grafts() {
+            let tree = Tree::node(1, vec![Tree::leaf(2)]);
+            let result = tree.bind(|x| Tree::node(x, vec![Tree::leaf(x * 10)]));
+            let expected = Tree::node(1, vec![Tree::leaf(10), Tree::node(2, vec![Tree::leaf(20)])]);
+            assert_eq!(result, expected);
+        }
+
+        #[test]
+        fn left_identity_law() {
+            // Left identity: return a >>= f = f a
+            let f = |x: i32| Tree::node(x, vec![Tree::leaf(x + 1)]);
+            assert_eq!(Tree::pure(5).bind(f), f(5));
+        }
+
+        #[test]
+        fn right_identity_law() {
+            // Right identity: m >>= return = m
+            assert_eq!(sample().bind(Tree::pure), sample());
+        }
+
+        #[test]
+        fn associativity_law() {
+            // Associativity: (m >>= f) >>= g = m >>= (|x| f x >>= g)
+            let f = |x: i32| Tree::node(x, vec![Tree::leaf(x * 2)]);
+            let g = |x: i32| Tree::node(to_string(x), vec![Tree::leaf(to_string(-x))]);
+            let lhs = sample().bind(f).bind(g);
+            let rhs = sample().bind(|x| f(x).bind(g));
+            assert_eq!(lhs, rhs);
+        }
+    }
+}
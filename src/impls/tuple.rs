@@ -0,0 +1,86 @@
+pub mod tuple_impls {
+    use crate::*;
+
+    pub struct PairKind2;
+
+    impl Generic2 for PairKind2 {
+        type Rep2<A, B> = (A, B);
+    }
+
+    impl<A, B> Kinded2<A, B> for (A, B) {
+        type Kind2 = PairKind2;
+    }
+
+    impl<A, C> Bifunctor<A, C> for (A, C) {
+        fn bimap<B, D, F: FnOnce(A) -> B, G: FnOnce(C) -> D>(self, f: F, g: G) -> (B, D) {
+            (f(self.0), g(self.1))
+        }
+
+        fn first<B, F: FnOnce(A) -> B>(self, f: F) -> (B, C) {
+            (f(self.0), self.1)
+        }
+
+        fn second<D, G: FnOnce(C) -> D>(self, g: G) -> (A, D) {
+            (self.0, g(self.1))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tuple_tests {
+    #[cfg(feature = "no_std")]
+    use crate::fixed_string::*;
+    use crate::*;
+
+    mod bifunctor {
+        use super::*;
+
+        fn pair() -> (i32, String) {
+            (5, to_string(42))
+        }
+
+        #[test]
+        fn bimap() {
+            let result = pair().bimap(multiply_by_two, |s| s.len());
+            assert_eq!(result, (10, 2));
+        }
+
+        #[test]
+        fn first() {
+            let result = pair().first(add_one);
+            assert_eq!(result, (6, to_string(42)));
+        }
+
+        #[test]
+        fn second() {
+            let result = pair().second(|s| s.len());
+            assert_eq!(result, (5, 2));
+        }
+
+        #[test]
+        fn identity_law() {
+            // Identity law: bimap id id = id
+            assert_eq!(pair().bimap(identity, identity), pair());
+        }
+
+        #[test]
+        fn composition_law() {
+            // Composition law: bimap (f . g) (h . i) = bimap f h . bimap g i
+            let f = to_string;
+            let g = add_one;
+            let h = |n: usize| n * 3;
+            let i = |s: String| s.len();
+
+            let left = pair().bimap(|x| f(g(x)), |s| h(i(s)));
+            let right = pair().bimap(g, i).bimap(f, h);
+            assert_eq!(left, right);
+        }
+
+        #[test]
+        fn first_and_second_agree_with_bimap() {
+            let lhs = pair().first(add_one).second(|s| s.len());
+            let rhs = pair().bimap(add_one, |s| s.len());
+            assert_eq!(lhs, rhs);
+        }
+    }
+}
@@ -0,0 +1,230 @@
+#[cfg(not(feature = "no_std"))]
+pub mod validate_impls {
+    use crate::*;
+
+    /// Tuples of results that can be validated together, collecting every
+    /// error rather than stopping at the first.
+    ///
+    /// This is applicative validation over `Result`: the fields are
+    /// independent, so a failure in one does not prevent the others from
+    /// being checked, and their errors are combined with the `Vec` semigroup
+    /// in field order. Implemented for tuples of one to eight results sharing
+    /// an error type.
+    pub trait ValidateFields<E> {
+        /// The tuple of the successful field values.
+        type Values;
+
+        /// Returns every field value, or every error in field order.
+        fn validate(self) -> Result<Self::Values, Vec<E>>;
+    }
+
+    macro_rules! impl_validate_fields {
+        ($($a:ident $v:ident),+) => {
+            impl<E, $($a),+> ValidateFields<E> for ($(Result<$a, E>,)+) {
+                type Values = ($($a,)+);
+
+                fn validate(self) -> Result<($($a,)+), Vec<E>> {
+                    let ($($v,)+) = self;
+                    let mut errors = Vec::new();
+                    $(
+                        let $v = match $v {
+                            Ok(a) => Some(a),
+                            Err(e) => {
+                                errors = errors.combine(vec![e]);
+                                None
+                            }
+                        };
+                    )+
+                    match ($($v,)+) {
+                        ($(Some($v),)+) => Ok(($($v,)+)),
+                        _ => Err(errors),
+                    }
+                }
+            }
+        };
+    }
+
+    impl_validate_fields!(A a);
+    impl_validate_fields!(A a, B b);
+    impl_validate_fields!(A a, B b, C c);
+    impl_validate_fields!(A a, B b, C c, D d);
+    impl_validate_fields!(A a, B b, C c, D d, F f);
+    impl_validate_fields!(A a, B b, C c, D d, F f, G g);
+    impl_validate_fields!(A a, B b, C c, D d, F f, G g, H h);
+    impl_validate_fields!(A a, B b, C c, D d, F f, G g, H h, I i);
+
+    /// Builds a value from several independently validated fields.
+    ///
+    /// Every field result is checked. If all of them succeed, `build`
+    /// receives the field values as a tuple; otherwise it is not called and
+    /// the errors of every failing field are returned in field order. See
+    /// [`validate_struct!`](crate::validate_struct) for assembling a struct
+    /// by field name.
+    ///
+    /// # Type Parameters
+    /// * `S` - The type being built
+    /// * `E` - The error type shared by the fields
+    /// * `T` - The tuple of field results
+    ///
+    /// # Returns
+    /// The built value, or every field error.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::build_with;
+    ///
+    /// let width: Result<u32, &str> = Err("width is missing");
+    /// let height: Result<u32, &str> = Err("height is zero");
+    /// let area = build_with((width, height), |(w, h)| w * h);
+    /// assert_eq!(area, Err(vec!["width is missing", "height is zero"]));
+    /// ```
+    pub fn build_with<S, E, T: ValidateFields<E>>(
+        fields: T,
+        build: impl FnOnce(T::Values) -> S,
+    ) -> Result<S, Vec<E>> {
+        fields.validate().map(build)
+    }
+
+    /// Assembles a struct from fallible field expressions, collecting every
+    /// error.
+    ///
+    /// Each `field: expr` pair gives a `Result` for that field, and all of
+    /// them must share an error type. The struct is built only if every field
+    /// succeeds; otherwise the result holds every error in field order. It
+    /// expands to [`build_with`] over the field results, so up to eight
+    /// fields are supported.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::validate_struct;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Point {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// let parse = |s: &str| s.parse::<i32>().map_err(|_| format!("{s:?} is not a number"));
+    /// assert_eq!(validate_struct!(Point { x: parse("3"), y: parse("4") }), Ok(Point { x: 3, y: 4 }));
+    /// assert_eq!(
+    ///     validate_struct!(Point { x: parse("a"), y: parse("b") }),
+    ///     Err(vec![r#""a" is not a number"#.to_string(), r#""b" is not a number"#.to_string()])
+    /// );
+    /// ```
+    #[macro_export]
+    macro_rules! validate_struct {
+        ($($s:ident)::+ { $($field:ident : $e:expr),+ $(,)? }) => {
+            $crate::build_with(($($e,)+), |($($field,)+)| $($s)::+ { $($field),+ })
+        };
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(feature = "no_std"))]
+mod validate_tests {
+    use crate::*;
+
+    #[derive(Debug, PartialEq)]
+    struct User {
+        name: String,
+        age: u8,
+        email: String,
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum FieldError {
+        EmptyName,
+        AgeOutOfRange(i32),
+        InvalidEmail(String),
+    }
+
+    fn parse_name(s: &str) -> Result<String, FieldError> {
+        let name = s.trim();
+        if name.is_empty() {
+            Err(FieldError::EmptyName)
+        } else {
+            Ok(name.to_string())
+        }
+    }
+
+    fn parse_age(n: i32) -> Result<u8, FieldError> {
+        u8::try_from(n)
+            .ok()
+            .filter(|age| *age <= 150)
+            .ok_or(FieldError::AgeOutOfRange(n))
+    }
+
+    fn parse_email(s: &str) -> Result<String, FieldError> {
+        match s.split_once('@') {
+            Some((user, domain)) if !user.is_empty() && domain.contains('.') => Ok(s.to_string()),
+            _ => Err(FieldError::InvalidEmail(s.to_string())),
+        }
+    }
+
+    fn user(name: &str, age: i32, email: &str) -> Result<User, Vec<FieldError>> {
+        validate_struct!(User {
+            name: parse_name(name),
+            age: parse_age(age),
+            email: parse_email(email),
+        })
+    }
+
+    #[test]
+    fn all_fields_valid() {
+        assert_eq!(
+            user(" Ada ", 36, "ada@example.com"),
+            Ok(User {
+                name: "Ada".to_string(),
+                age: 36,
+                email: "ada@example.com".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn every_field_error_accumulates() {
+        assert_eq!(
+            user("", 200, "nobody"),
+            Err(vec![
+                FieldError::EmptyName,
+                FieldError::AgeOutOfRange(200),
+                FieldError::InvalidEmail("nobody".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn errors_keep_field_order() {
+        assert_eq!(
+            user("Ada", -1, "@example.com"),
+            Err(vec![
+                FieldError::AgeOutOfRange(-1),
+                FieldError::InvalidEmail("@example.com".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn build_with_skips_build_on_error() {
+        let mut built = false;
+        let result = build_with((parse_name(""), parse_age(3)), |(name, age)| {
+            built = true;
+            (name, age)
+        });
+        assert_eq!(result, Err(vec![FieldError::EmptyName]));
+        assert!(!built);
+    }
+
+    #[test]
+    fn build_with_single_field() {
+        let result: Result<u8, Vec<FieldError>> = build_with((parse_age(7),), |(age,)| age);
+        assert_eq!(result, Ok(7));
+    }
+
+    #[test]
+    fn matches_tuple_accum() {
+        let pair = (parse_name(""), parse_age(300));
+        let expected = sequence_result_tuple2_accum((parse_name(""), parse_age(300)));
+        assert_eq!(build_with(pair, identity), expected);
+    }
+}
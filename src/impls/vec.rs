@@ -13,8 +13,93 @@ pub mod vec_impls {
     }
 
     impl<A> Functor<A> for Vec<A> {
+        /// Maps over the vector, reusing its allocation when `A` and `B` have
+        /// the same size and alignment so the result keeps the original
+        /// capacity. Other mappings collect into a freshly allocated vector.
+        ///
+        /// Either way, `f` is called exactly once per element, strictly from
+        /// first to last, so a closure carrying mutable state sees the
+        /// elements in order.
         fn fmap<B, F: FnMut(A) -> B>(self, f: F) -> Vec<B> {
-            self.into_iter().map(f).collect()
+            let same_layout =
+                size_of::<A>() == size_of::<B>() && align_of::<A>() == align_of::<B>();
+            if same_layout && size_of::<A>() != 0 {
+                fmap_in_place(self, f)
+            } else {
+                self.into_iter().map(f).collect()
+            }
+        }
+    }
+
+    /// Drops whatever is left of a vector that is being mapped in place if the
+    /// mapping function panics: the elements before `mapped` have already been
+    /// turned into `B`s, the element at `mapped` was moved into the function,
+    /// and the rest are still `A`s.
+    struct InPlaceGuard<A, B> {
+        ptr: *mut A,
+        len: usize,
+        cap: usize,
+        mapped: usize,
+        _marker: std::marker::PhantomData<B>,
+    }
+
+    impl<A, B> Drop for InPlaceGuard<A, B> {
+        fn drop(&mut self) {
+            unsafe {
+                let done = std::ptr::slice_from_raw_parts_mut(self.ptr as *mut B, self.mapped);
+                std::ptr::drop_in_place(done);
+
+                let rest_start = (self.mapped + 1).min(self.len);
+                let rest = std::ptr::slice_from_raw_parts_mut(
+                    self.ptr.add(rest_start),
+                    self.len - rest_start,
+                );
+                std::ptr::drop_in_place(rest);
+
+                // Free the allocation without dropping anything else
+                drop(Vec::from_raw_parts(self.ptr, 0, self.cap));
+            }
+        }
+    }
+
+    /// Maps each element of `v` in place, writing each `B` over the `A` it
+    /// was produced from. Callers must ensure `A` and `B` have the same,
+    /// non-zero size and the same alignment.
+    fn fmap_in_place<A, B, F: FnMut(A) -> B>(v: Vec<A>, mut f: F) -> Vec<B> {
+        let mut v = std::mem::ManuallyDrop::new(v);
+        let mut guard = InPlaceGuard::<A, B> {
+            ptr: v.as_mut_ptr(),
+            len: v.len(),
+            cap: v.capacity(),
+            mapped: 0,
+            _marker: std::marker::PhantomData,
+        };
+
+        while guard.mapped < guard.len {
+            unsafe {
+                let slot = guard.ptr.add(guard.mapped);
+                let b = f(std::ptr::read(slot));
+                std::ptr::write(slot as *mut B, b);
+            }
+            guard.mapped += 1;
+        }
+
+        let (ptr, len, cap) = (guard.ptr as *mut B, guard.len, guard.cap);
+        std::mem::forget(guard);
+        // Safety: every slot now holds an initialized `B`, and the allocation
+        // layout for `cap` `A`s is identical to the one for `cap` `B`s
+        unsafe { Vec::from_raw_parts(ptr, len, cap) }
+    }
+
+    impl Ref1 for VecKind {
+        fn fmap_ref<'a, A: 'a, B, M: FnMut(&'a A) -> B>(fa: &'a Vec<A>, f: M) -> Vec<B> {
+            fa.iter().map(f).collect()
+        }
+    }
+
+    impl<A> FunctorMut<A> for Vec<A> {
+        fn fmap_mut<F: FnMut(&mut A)>(&mut self, f: F) {
+            self.iter_mut().for_each(f);
         }
     }
 
@@ -62,6 +147,385 @@ pub mod vec_impls {
             self.into_iter().flat_map(f).collect()
         }
     }
+
+    /// Concatenates, like the `Semigroup` instance.
+    impl<A> Alternative<A> for Vec<A> {
+        fn nothing() -> Vec<A> {
+            Vec::new()
+        }
+
+        fn alt(mut self, mut other: Vec<A>) -> Vec<A> {
+            self.append(&mut other);
+            self
+        }
+    }
+
+    /// Combines two vectors by appending the second to the first.
+    impl<A> Semigroup for Vec<A> {
+        fn combine(mut self, mut other: Self) -> Self {
+            self.append(&mut other);
+            self
+        }
+    }
+
+    impl<A> Monoid for Vec<A> {
+        fn empty() -> Self {
+            Vec::new()
+        }
+    }
+
+    impl<A> Foldable<A> for Vec<A> {
+        fn fold_left<B, F: FnMut(B, A) -> B>(self, init: B, f: F) -> B {
+            self.into_iter().fold(init, f)
+        }
+
+        /// Folds from the right by iterating the vector in reverse with an
+        /// accumulator, so large vectors can be folded without recursion.
+        fn fold_right<B, F: FnMut(A, B) -> B>(self, init: B, mut f: F) -> B {
+            self.into_iter().rev().fold(init, |acc, a| f(a, acc))
+        }
+
+        fn find<F: FnMut(&A) -> bool>(self, f: F) -> Option<A> {
+            self.into_iter().find(f)
+        }
+
+        fn any<F: FnMut(&A) -> bool>(self, mut f: F) -> bool {
+            self.into_iter().any(|a| f(&a))
+        }
+
+        fn all<F: FnMut(&A) -> bool>(self, mut f: F) -> bool {
+            self.into_iter().all(|a| f(&a))
+        }
+    }
+
+    /// Additional combinators for `Vec` beyond the typeclass instances.
+    pub trait VecExt<A> {
+        /// Pairs every element with its index, preserving order.
+        ///
+        /// # Example
+        /// ```
+        /// use crab_fp::VecExt;
+        ///
+        /// assert_eq!(vec!['a', 'b'].zip_with_index(), vec![(0, 'a'), (1, 'b')]);
+        /// ```
+        fn zip_with_index(self) -> Vec<(usize, A)>;
+
+        /// Maps a function over the vector, passing each element's index
+        /// alongside the element.
+        ///
+        /// # Example
+        /// ```
+        /// use crab_fp::VecExt;
+        ///
+        /// let labels = vec!["x", "y"].fmap_with_index(|i, s| format!("{i}:{s}"));
+        /// assert_eq!(labels, vec!["0:x", "1:y"]);
+        /// ```
+        fn fmap_with_index<B, F: FnMut(usize, A) -> B>(self, f: F) -> Vec<B>;
+
+        /// Lazily maps a function over borrowed elements, without cloning the
+        /// vector first.
+        ///
+        /// The returned adapter reports an exact `size_hint`, so collecting it
+        /// allocates exactly once.
+        ///
+        /// # Example
+        /// ```
+        /// use crab_fp::VecExt;
+        ///
+        /// let names = vec![String::from("ada"), String::from("grace")];
+        /// let lengths: Vec<usize> = names.fmap_ref(|s| s.len()).collect();
+        /// assert_eq!(lengths, vec![3, 5]);
+        /// assert_eq!(names.len(), 2);
+        /// ```
+        fn fmap_ref<'a, B, F: FnMut(&'a A) -> B>(&'a self, f: F) -> Mapped<'a, A, F>;
+
+        /// Removes duplicate elements, keeping the first occurrence of each.
+        ///
+        /// Unlike `Vec::dedup`, duplicates need not be adjacent.
+        ///
+        /// # Example
+        /// ```
+        /// use crab_fp::VecExt;
+        ///
+        /// assert_eq!(vec![3, 1, 3, 2, 1].unique(), vec![3, 1, 2]);
+        /// ```
+        fn unique(self) -> Vec<A>
+        where
+            A: Eq + std::hash::Hash;
+
+        /// Removes elements whose key has already been seen, keeping the first
+        /// element for each key.
+        ///
+        /// Unlike `Vec::dedup_by_key`, duplicates need not be adjacent.
+        ///
+        /// # Example
+        /// ```
+        /// use crab_fp::VecExt;
+        ///
+        /// let words = vec!["apple", "avocado", "banana", "blueberry", "cherry"];
+        /// let by_letter = words.unique_by_key(|w| w.chars().next());
+        /// assert_eq!(by_letter, vec!["apple", "banana", "cherry"]);
+        /// ```
+        fn unique_by_key<K: Eq + std::hash::Hash, F: FnMut(&A) -> K>(self, f: F) -> Vec<A>;
+
+        /// Splits the vector into consecutive owned chunks of `n` elements.
+        ///
+        /// The last chunk holds the remainder when the length is not a
+        /// multiple of `n`. Elements are moved, never cloned.
+        ///
+        /// # Panics
+        /// Panics if `n` is zero, like `slice::chunks`.
+        ///
+        /// # Example
+        /// ```
+        /// use crab_fp::VecExt;
+        ///
+        /// assert_eq!(vec![1, 2, 3, 4, 5].chunk(2), vec![vec![1, 2], vec![3, 4], vec![5]]);
+        /// ```
+        fn chunk(self, n: usize) -> Vec<Vec<A>>;
+
+        /// Returns every contiguous window of `n` elements as an owned vector.
+        ///
+        /// Windows overlap, so the elements are cloned. A vector shorter than
+        /// `n` has no windows.
+        ///
+        /// # Panics
+        /// Panics if `n` is zero, like `slice::windows`.
+        ///
+        /// # Example
+        /// ```
+        /// use crab_fp::VecExt;
+        ///
+        /// assert_eq!(vec![1, 2, 3].windows_owned(2), vec![vec![1, 2], vec![2, 3]]);
+        /// ```
+        fn windows_owned(&self, n: usize) -> Vec<Vec<A>>
+        where
+            A: Clone;
+
+        /// Lazily applies every function to every element.
+        ///
+        /// Yields the same sequence as `apply` (each function in turn, applied
+        /// to every element in order), but without materialising the whole
+        /// product, so it can be consumed partially or streamed. Each element
+        /// is cloned as it is handed to a function.
+        ///
+        /// # Example
+        /// ```
+        /// use crab_fp::VecExt;
+        ///
+        /// let fs: Vec<fn(i32) -> i32> = vec![|x| x + 1, |x| x * 10];
+        /// let products: Vec<i32> = vec![1, 2].apply_iter(fs).collect();
+        /// assert_eq!(products, vec![2, 3, 10, 20]);
+        /// ```
+        fn apply_iter<F>(self, ff: Vec<F>) -> ApplyIter<A, F>
+        where
+            A: Clone;
+
+        /// Groups the elements by a key function.
+        ///
+        /// Each group keeps its elements in their original order. Elements are
+        /// moved into the groups, never cloned.
+        ///
+        /// # Example
+        /// ```
+        /// use crab_fp::VecExt;
+        ///
+        /// let groups = vec![1, 2, 3, 4, 5].group_by(|x| x % 2 == 0);
+        /// assert_eq!(groups[&true], vec![2, 4]);
+        /// assert_eq!(groups[&false], vec![1, 3, 5]);
+        /// ```
+        fn group_by<K: Eq + std::hash::Hash, F: FnMut(&A) -> K>(
+            self,
+            f: F,
+        ) -> std::collections::HashMap<K, Vec<A>>;
+
+        /// Maps a fallible function over every element, keeping all successes
+        /// and all failures.
+        ///
+        /// Unlike `traverse`, this never stops early: `f` runs on every
+        /// element, and the successes and the errors are each returned in
+        /// input order.
+        ///
+        /// # Example
+        /// ```
+        /// use crab_fp::VecExt;
+        ///
+        /// let (ports, errors) = vec!["80", "x", "443"].fmap_collecting_errors(|s| s.parse::<u16>());
+        /// assert_eq!(ports, vec![80, 443]);
+        /// assert_eq!(errors.len(), 1);
+        /// ```
+        fn fmap_collecting_errors<B, E, F: FnMut(A) -> Result<B, E>>(
+            self,
+            f: F,
+        ) -> (Vec<B>, Vec<E>);
+    }
+
+    /// A lazy adapter mapping a function over borrowed vector elements.
+    ///
+    /// Created by `VecExt::fmap_ref`.
+    pub struct Mapped<'a, A, F> {
+        iter: std::slice::Iter<'a, A>,
+        f: F,
+    }
+
+    impl<'a, A, B, F: FnMut(&'a A) -> B> Iterator for Mapped<'a, A, F> {
+        type Item = B;
+
+        fn next(&mut self) -> Option<B> {
+            self.iter.next().map(&mut self.f)
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            self.iter.size_hint()
+        }
+    }
+
+    impl<'a, A, B, F: FnMut(&'a A) -> B> DoubleEndedIterator for Mapped<'a, A, F> {
+        fn next_back(&mut self) -> Option<B> {
+            self.iter.next_back().map(&mut self.f)
+        }
+    }
+
+    impl<'a, A, B, F: FnMut(&'a A) -> B> ExactSizeIterator for Mapped<'a, A, F> {}
+
+    /// A lazy cartesian product of functions and elements.
+    ///
+    /// Created by `VecExt::apply_iter`.
+    pub struct ApplyIter<A, F> {
+        values: Vec<A>,
+        fs: std::vec::IntoIter<F>,
+        current: Option<F>,
+        index: usize,
+    }
+
+    impl<A: Clone, B, F: FnMut(A) -> B> Iterator for ApplyIter<A, F> {
+        type Item = B;
+
+        fn next(&mut self) -> Option<B> {
+            if self.values.is_empty() {
+                return None;
+            }
+            if self.index == self.values.len() {
+                self.current = None;
+            }
+            if self.current.is_none() {
+                self.current = Some(self.fs.next()?);
+                self.index = 0;
+            }
+            let f = self.current.as_mut()?;
+            let b = f(self.values[self.index].clone());
+            self.index += 1;
+            Some(b)
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            let len = self.values.len();
+            let in_current = match self.current {
+                Some(_) => len - self.index,
+                None => 0,
+            };
+            let remaining = self
+                .fs
+                .len()
+                .checked_mul(len)
+                .and_then(|n| n.checked_add(in_current));
+            (remaining.unwrap_or(usize::MAX), remaining)
+        }
+    }
+
+    impl<A> VecExt<A> for Vec<A> {
+        fn zip_with_index(self) -> Vec<(usize, A)> {
+            self.into_iter().enumerate().collect()
+        }
+
+        fn fmap_with_index<B, F: FnMut(usize, A) -> B>(self, mut f: F) -> Vec<B> {
+            self.into_iter().enumerate().map(|(i, a)| f(i, a)).collect()
+        }
+
+        fn fmap_ref<'a, B, F: FnMut(&'a A) -> B>(&'a self, f: F) -> Mapped<'a, A, F> {
+            Mapped {
+                iter: self.iter(),
+                f,
+            }
+        }
+
+        fn unique(self) -> Vec<A>
+        where
+            A: Eq + std::hash::Hash,
+        {
+            // Mark the first occurrences while borrowing, so the elements
+            // themselves never need to be cloned into the set.
+            let mut seen = std::collections::HashSet::new();
+            let keep: Vec<bool> = self.iter().map(|a| seen.insert(a)).collect();
+            self.into_iter()
+                .zip(keep)
+                .filter_map(|(a, first)| first.then_some(a))
+                .collect()
+        }
+
+        fn unique_by_key<K: Eq + std::hash::Hash, F: FnMut(&A) -> K>(self, mut f: F) -> Vec<A> {
+            let mut seen = std::collections::HashSet::new();
+            self.into_iter().filter(|a| seen.insert(f(a))).collect()
+        }
+
+        fn chunk(self, n: usize) -> Vec<Vec<A>> {
+            assert!(n != 0, "chunk size must be non-zero");
+            let mut chunks = Vec::with_capacity(self.len().div_ceil(n));
+            let mut iter = self.into_iter();
+            loop {
+                let chunk: Vec<A> = iter.by_ref().take(n).collect();
+                if chunk.is_empty() {
+                    break chunks;
+                }
+                chunks.push(chunk);
+            }
+        }
+
+        fn windows_owned(&self, n: usize) -> Vec<Vec<A>>
+        where
+            A: Clone,
+        {
+            self.windows(n).map(<[A]>::to_vec).collect()
+        }
+
+        fn apply_iter<F>(self, ff: Vec<F>) -> ApplyIter<A, F>
+        where
+            A: Clone,
+        {
+            ApplyIter {
+                values: self,
+                fs: ff.into_iter(),
+                current: None,
+                index: 0,
+            }
+        }
+
+        fn group_by<K: Eq + std::hash::Hash, F: FnMut(&A) -> K>(
+            self,
+            mut f: F,
+        ) -> std::collections::HashMap<K, Vec<A>> {
+            let mut groups = std::collections::HashMap::new();
+            for a in self {
+                groups.entry(f(&a)).or_insert_with(Vec::new).push(a);
+            }
+            groups
+        }
+
+        fn fmap_collecting_errors<B, E, F: FnMut(A) -> Result<B, E>>(
+            self,
+            mut f: F,
+        ) -> (Vec<B>, Vec<E>) {
+            let mut oks = Vec::new();
+            let mut errs = Vec::new();
+            for a in self {
+                match f(a) {
+                    Ok(b) => oks.push(b),
+                    Err(e) => errs.push(e),
+                }
+            }
+            (oks, errs)
+        }
+    }
 }
 
 #[cfg(test)]
@@ -76,6 +540,128 @@ mod vec_tests {
             let mapped = v.fmap(multiply_by_two);
             assert_eq!(mapped, vec![2, 4, 6]);
         }
+
+        #[test]
+        fn fmap_same_size_reuses_allocation() {
+            let mut v: Vec<i32> = Vec::with_capacity(16);
+            v.extend([-1, 0, 1]);
+            let ptr = v.as_ptr() as usize;
+
+            let mapped: Vec<u32> = v.fmap(|x| x.unsigned_abs() + 1);
+            assert_eq!(mapped, vec![2, 1, 2]);
+            assert_eq!(mapped.capacity(), 16);
+            assert_eq!(mapped.as_ptr() as usize, ptr);
+        }
+
+        #[test]
+        fn fmap_different_size() {
+            let v = vec![1, 2, 3];
+            let mapped = v.fmap(to_string);
+            assert_eq!(mapped, vec!["1", "2", "3"]);
+
+            let v: Vec<u8> = vec![1, 2];
+            let mapped = v.fmap(|x| x as u64 * 1000);
+            assert_eq!(mapped, vec![1000, 2000]);
+        }
+
+        #[test]
+        fn fmap_stateful_closure_sees_elements_in_order() {
+            // Same layout, mapped in place
+            let mut seen = vec![];
+            let running: Vec<i32> = vec![3, 1, 4, 1, 5].fmap(|x| {
+                seen.push(x);
+                seen.iter().sum()
+            });
+            assert_eq!(seen, vec![3, 1, 4, 1, 5]);
+            assert_eq!(running, vec![3, 4, 8, 9, 14]);
+
+            // Different layout, collected into a new vector
+            let mut index = 0;
+            let labelled = vec![b'a', b'b', b'c'].fmap(|c| {
+                index += 1;
+                (index, c as char)
+            });
+            assert_eq!(labelled, vec![(1, 'a'), (2, 'b'), (3, 'c')]);
+        }
+
+        #[test]
+        fn fmap_in_place_drops_on_panic() {
+            use std::panic::{AssertUnwindSafe, catch_unwind};
+            use std::rc::Rc;
+
+            let tracker = Rc::new(());
+            let v = vec![
+                Rc::clone(&tracker),
+                Rc::clone(&tracker),
+                Rc::clone(&tracker),
+            ];
+            let mut calls = 0;
+            let result = catch_unwind(AssertUnwindSafe(|| {
+                v.fmap(|rc| {
+                    calls += 1;
+                    if calls == 2 {
+                        panic!("boom");
+                    }
+                    Some(rc)
+                })
+            }));
+            assert!(result.is_err());
+            assert_eq!(Rc::strong_count(&tracker), 1);
+        }
+    }
+
+    mod fmap_mut {
+        use crate::*;
+
+        #[test]
+        fn mutates_in_place() {
+            let mut v = vec![1, 2, 3];
+            let (ptr, cap) = (v.as_ptr(), v.capacity());
+            v.fmap_mut(|x| *x *= 10);
+            assert_eq!(v, vec![10, 20, 30]);
+            assert_eq!((v.as_ptr(), v.capacity()), (ptr, cap));
+        }
+
+        #[test]
+        fn visits_in_order() {
+            let mut v = vec![0; 4];
+            let mut next = 0;
+            v.fmap_mut(|x| {
+                *x = next;
+                next += 1;
+            });
+            assert_eq!(v, vec![0, 1, 2, 3]);
+        }
+
+        #[test]
+        fn agrees_with_fmap() {
+            let mut v = vec![1, 2, 3];
+            v.fmap_mut(|x| *x = add_one(*x));
+            assert_eq!(v, vec![1, 2, 3].fmap(add_one));
+        }
+    }
+
+    mod ref1 {
+        use crate::vec::vec_impls::VecKind;
+        use crate::*;
+
+        fn lengths<F: Ref1>(data: &Apply1<F, String>) -> Apply1<F, usize> {
+            F::fmap_ref(data, |s| s.len())
+        }
+
+        #[test]
+        fn fmap_ref() {
+            let v = vec![1, 2, 3];
+            assert_eq!(VecKind::fmap_ref(&v, |x| x * 10), vec![10, 20, 30]);
+            assert_eq!(v, vec![1, 2, 3]);
+        }
+
+        #[test]
+        fn generic_over_kind_leaves_original() {
+            let words = vec![String::from("ab"), String::from("cde")];
+            assert_eq!(lengths::<VecKind>(&words), vec![2, 3]);
+            assert_eq!(words, vec!["ab", "cde"]);
+        }
     }
 
     mod applicative {
@@ -240,4 +826,370 @@ mod vec_tests {
             assert_eq!(result, Vec::<String>::new());
         }
     }
+
+    mod alternative {
+        use crate::*;
+
+        #[test]
+        fn alt_concatenates() {
+            assert_eq!(vec![1, 2].alt(vec![3]), vec![1, 2, 3]);
+        }
+
+        #[test]
+        fn identity_laws() {
+            let x = vec![1, 2];
+            assert_eq!(Vec::nothing().alt(x.clone()), x);
+            assert_eq!(x.clone().alt(Vec::nothing()), x);
+        }
+
+        #[test]
+        fn associativity_law() {
+            let (x, y, z) = (vec![1], vec![2, 3], vec![4]);
+            assert_eq!(x.clone().alt(y.clone()).alt(z.clone()), x.alt(y.alt(z)));
+        }
+    }
+
+    mod foldable {
+        use crate::*;
+
+        #[test]
+        fn fold_left() {
+            let v = vec![1, 2, 3];
+            assert_eq!(v.fold_left(0, |acc, x| acc - x), -6);
+
+            let v: Vec<i32> = vec![];
+            assert_eq!(v.fold_left(0, |acc, x| acc - x), 0);
+        }
+
+        #[test]
+        fn fold_map() {
+            use crate::monoid::monoid_impls::{Max, Min};
+
+            let v = vec![3, 9, -2, 5];
+            assert_eq!(v.clone().fold_map(Max), Max(9));
+            assert_eq!(v.fold_map(Min), Min(-2));
+            assert_eq!(Vec::<i32>::new().fold_map(Max), Max(i32::MIN));
+
+            let words = vec!["a", "b", "c"];
+            assert_eq!(
+                words.fold_map(|w| vec![w, w]),
+                vec!["a", "a", "b", "b", "c", "c"]
+            );
+        }
+
+        #[test]
+        fn fold_right() {
+            // 1 - (2 - (3 - 0))
+            let v = vec![1, 2, 3];
+            assert_eq!(v.fold_right(0, |x, acc| x - acc), 2);
+
+            let v: Vec<i32> = vec![];
+            assert_eq!(v.fold_right(0, |x, acc| x - acc), 0);
+        }
+
+        #[test]
+        fn fold_right_visits_last_element_first() {
+            let v = vec![1, 2, 3];
+            let visited = v.fold_right(vec![], |x, mut acc| {
+                acc.push(x);
+                acc
+            });
+            assert_eq!(visited, vec![3, 2, 1]);
+        }
+
+        #[test]
+        fn find() {
+            let v = vec![1, 4, 6, 8];
+            assert_eq!(v.find(|x| x % 2 == 0), Some(4));
+
+            let v = vec![1, 3];
+            assert_eq!(v.find(|x| x % 2 == 0), None);
+        }
+
+        #[test]
+        fn any_short_circuits() {
+            let mut calls = 0;
+            let found = vec![1, 2, 3, 4].any(|x| {
+                calls += 1;
+                *x == 2
+            });
+            assert!(found);
+            assert_eq!(calls, 2);
+
+            assert!(!Vec::<i32>::new().any(|_| true));
+        }
+
+        #[test]
+        fn all_short_circuits() {
+            let mut calls = 0;
+            let all_small = vec![1, 5, 2, 3].all(|x| {
+                calls += 1;
+                *x < 4
+            });
+            assert!(!all_small);
+            assert_eq!(calls, 2);
+
+            assert!(Vec::<i32>::new().all(|_| false));
+        }
+
+        #[test]
+        fn fold_right_large_vec() {
+            // A recursive fold_right would overflow the stack here
+            let n: u64 = 1_000_000;
+            let v: Vec<u64> = (1..=n).collect();
+            let sum = v.clone().fold_right(0, |x, acc| x + acc);
+            assert_eq!(sum, n * (n + 1) / 2);
+            assert_eq!(sum, v.fold_left(0, |acc, x| acc + x));
+        }
+    }
+    mod monoid {
+        use crate::*;
+
+        #[test]
+        fn combine() {
+            assert_eq!(vec![1, 2].combine(vec![3]), vec![1, 2, 3]);
+        }
+
+        #[test]
+        fn associativity_law() {
+            let (a, b, c) = (vec![1], vec![2, 3], vec![4]);
+            let lhs = a.clone().combine(b.clone()).combine(c.clone());
+            let rhs = a.combine(b.combine(c));
+            assert_eq!(lhs, rhs);
+        }
+
+        #[test]
+        fn identity_laws() {
+            let a = vec![1, 2];
+            assert_eq!(Vec::empty().combine(a.clone()), a);
+            assert_eq!(a.clone().combine(Vec::empty()), a);
+        }
+    }
+
+    mod vec_ext {
+        use crate::*;
+
+        #[test]
+        fn zip_with_index() {
+            let v = vec!["a", "b", "c"];
+            assert_eq!(v.zip_with_index(), vec![(0, "a"), (1, "b"), (2, "c")]);
+        }
+
+        #[test]
+        fn zip_with_index_empty() {
+            assert_eq!(Vec::<i32>::new().zip_with_index(), vec![]);
+        }
+
+        #[test]
+        fn fmap_with_index() {
+            let v = vec![10, 20, 30];
+            assert_eq!(v.fmap_with_index(|i, x| x + i), vec![10, 21, 32]);
+        }
+
+        #[test]
+        fn fmap_with_index_ignoring_index_is_fmap() {
+            let v = vec![1, 2, 3];
+            assert_eq!(
+                v.clone().fmap_with_index(|_, x| add_one(x)),
+                v.fmap(add_one)
+            );
+        }
+
+        #[test]
+        fn fmap_ref_size_hint_is_exact() {
+            let v: Vec<i32> = (0..1000).collect();
+            let mut mapped = v.fmap_ref(|x| x * 2);
+            assert_eq!(mapped.size_hint(), (1000, Some(1000)));
+            assert_eq!(mapped.len(), 1000);
+
+            mapped.next();
+            mapped.next_back();
+            assert_eq!(mapped.size_hint(), (998, Some(998)));
+        }
+
+        #[test]
+        fn fmap_ref_matches_fmap() {
+            let v = vec![String::from("a"), String::from("bb"), String::from("ccc")];
+            let borrowed: Vec<usize> = v.fmap_ref(|s| s.len()).collect();
+            assert_eq!(borrowed, v.clone().fmap(|s| s.len()));
+        }
+
+        #[test]
+        fn fmap_ref_collect_allocates_exactly() {
+            let v: Vec<u64> = (0..100).collect();
+            let collected: Vec<u64> = v.fmap_ref(|x| x + 1).collect();
+            assert_eq!(collected.capacity(), 100);
+        }
+
+        #[test]
+        fn unique_preserves_first_occurrence_order() {
+            let v = vec!["b", "a", "b", "c", "a", "d"];
+            assert_eq!(v.unique(), vec!["b", "a", "c", "d"]);
+        }
+
+        #[test]
+        fn unique_all_unique_unchanged() {
+            let v = vec![5, 3, 9, 1];
+            assert_eq!(v.clone().unique(), v);
+        }
+
+        #[test]
+        fn unique_after_fmap() {
+            let v = vec![-2, -1, 0, 1, 2];
+            assert_eq!(v.fmap(|x: i32| x.abs()).unique(), vec![2, 1, 0]);
+        }
+
+        #[test]
+        fn unique_by_key_keeps_first_per_key() {
+            let v = vec![(1, 'a'), (2, 'b'), (1, 'c'), (3, 'd'), (2, 'e')];
+            assert_eq!(v.unique_by_key(|p| p.0), vec![(1, 'a'), (2, 'b'), (3, 'd')]);
+        }
+
+        #[test]
+        fn unique_by_key_non_hashable_elements() {
+            let v = vec![1.5, 1.9, 2.1, 3.7, 2.0];
+            assert_eq!(v.unique_by_key(|x: &f64| *x as i64), vec![1.5, 2.1, 3.7]);
+        }
+
+        #[test]
+        fn chunk_exact() {
+            let v = vec![1, 2, 3, 4, 5, 6];
+            assert_eq!(v.chunk(3), vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        }
+
+        #[test]
+        fn chunk_ragged() {
+            let v = vec![1, 2, 3, 4, 5, 6, 7];
+            assert_eq!(v.chunk(3), vec![vec![1, 2, 3], vec![4, 5, 6], vec![7]]);
+            assert_eq!(vec![1, 2].chunk(5), vec![vec![1, 2]]);
+            assert_eq!(Vec::<i32>::new().chunk(2), Vec::<Vec<i32>>::new());
+        }
+
+        #[test]
+        fn chunk_then_fmap_over_batches() {
+            let sums = (1..=7)
+                .collect::<Vec<i32>>()
+                .chunk(3)
+                .fmap(|b: Vec<i32>| b.iter().sum::<i32>());
+            assert_eq!(sums, vec![6, 15, 7]);
+        }
+
+        #[test]
+        #[should_panic(expected = "chunk size must be non-zero")]
+        fn chunk_zero_panics() {
+            vec![1, 2, 3].chunk(0);
+        }
+
+        #[test]
+        fn windows_owned() {
+            let v = vec!['a', 'b', 'c', 'd'];
+            assert_eq!(
+                v.windows_owned(3),
+                vec![vec!['a', 'b', 'c'], vec!['b', 'c', 'd']]
+            );
+            assert_eq!(v.windows_owned(5), Vec::<Vec<char>>::new());
+        }
+
+        #[test]
+        #[should_panic]
+        fn windows_owned_zero_panics() {
+            vec![1, 2, 3].windows_owned(0);
+        }
+
+        #[test]
+        fn apply_iter_matches_apply() {
+            let fs: Vec<fn(i32) -> i32> = vec![add_one, multiply_by_two, square];
+            let v = vec![1, 2, 3];
+            let lazy: Vec<i32> = v.clone().apply_iter(fs.clone()).collect();
+            assert_eq!(lazy, v.apply(fs));
+        }
+
+        #[test]
+        fn apply_iter_empty() {
+            let fs: Vec<fn(i32) -> i32> = vec![add_one];
+            assert_eq!(Vec::<i32>::new().apply_iter(fs).count(), 0);
+            let fs: Vec<fn(i32) -> i32> = vec![];
+            assert_eq!(vec![1, 2].apply_iter(fs).count(), 0);
+        }
+
+        #[test]
+        fn apply_iter_size_hint() {
+            let fs: Vec<fn(i32) -> i32> = vec![add_one, square];
+            let mut iter = vec![1, 2, 3].apply_iter(fs);
+            assert_eq!(iter.size_hint(), (6, Some(6)));
+            iter.next();
+            assert_eq!(iter.size_hint(), (5, Some(5)));
+            iter.by_ref().take(3).for_each(drop);
+            assert_eq!(iter.size_hint(), (2, Some(2)));
+        }
+
+        #[test]
+        fn apply_iter_large_product_is_lazy() {
+            // 10^10 results would never fit in memory if materialised
+            let values: Vec<u64> = (0..100_000).collect();
+            let fs: Vec<_> = (0..100_000u64).map(|k| move |x: u64| x + k).collect();
+            let mut iter = values.apply_iter(fs);
+            assert_eq!(iter.size_hint().1, Some(10_000_000_000));
+            assert_eq!(iter.by_ref().take(3).collect::<Vec<_>>(), vec![0, 1, 2]);
+            // Position 100_003 is the second function applied to the fourth value
+            assert_eq!(iter.nth(100_000), Some(4));
+        }
+
+        #[test]
+        fn group_by() {
+            let words = vec!["apple", "bob", "avocado", "cat", "banana", "axe"];
+            let groups = words.group_by(|w| w.chars().next().unwrap());
+            assert_eq!(groups.len(), 3);
+            assert_eq!(groups[&'a'], vec!["apple", "avocado", "axe"]);
+            assert_eq!(groups[&'b'], vec!["bob", "banana"]);
+            assert_eq!(groups[&'c'], vec!["cat"]);
+        }
+
+        #[test]
+        fn group_by_after_fmap() {
+            let groups = vec![1, 2, 3, 4, 5, 6].fmap(square).group_by(|x| x % 3);
+            assert_eq!(groups[&0], vec![9, 36]);
+            assert_eq!(groups[&1], vec![1, 4, 16, 25]);
+            assert!(!groups.contains_key(&2));
+        }
+
+        #[test]
+        fn group_by_empty() {
+            let groups = Vec::<i32>::new().group_by(|x| *x);
+            assert!(groups.is_empty());
+        }
+
+        #[test]
+        fn fmap_collecting_errors_interleaved() {
+            let mut visited = vec![];
+            let (oks, errs) = vec![1, -2, 3, -4, -5, 6].fmap_collecting_errors(|x| {
+                visited.push(x);
+                if x > 0 {
+                    Ok(x * 10)
+                } else {
+                    Err(format!("{x} is negative"))
+                }
+            });
+            assert_eq!(oks, vec![10, 30, 60]);
+            assert_eq!(
+                errs,
+                vec!["-2 is negative", "-4 is negative", "-5 is negative"]
+            );
+            assert_eq!(visited, vec![1, -2, 3, -4, -5, 6]);
+        }
+
+        #[test]
+        fn fmap_collecting_errors_all_one_side() {
+            let (oks, errs) = vec![1, 2].fmap_collecting_errors(Ok::<_, ()>);
+            assert_eq!((oks, errs), (vec![1, 2], vec![]));
+            let (oks, errs) = vec![1, 2].fmap_collecting_errors(Err::<(), _>);
+            assert_eq!((oks, errs), (vec![], vec![1, 2]));
+        }
+
+        #[test]
+        fn fmap_collecting_errors_empty() {
+            let (oks, errs) = Vec::<i32>::new().fmap_collecting_errors(Ok::<_, ()>);
+            assert!(oks.is_empty() && errs.is_empty());
+        }
+    }
 }
@@ -0,0 +1,224 @@
+#[cfg(not(feature = "no_std"))]
+pub mod weighted_impls {
+    use crate::*;
+
+    /// A discrete probability distribution.
+    ///
+    /// Each outcome is paired with its weight. `pure` is a certain outcome of
+    /// weight `1.0`, and `bind` runs the next step from every outcome,
+    /// multiplying the weights along each path, so chaining distributions
+    /// yields their joint distribution. Equal outcomes reached by different
+    /// paths are kept as separate entries; `probability` sums them.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::*;
+    /// use crab_fp::weighted::weighted_impls::Weighted;
+    ///
+    /// let coin = || Weighted::uniform(vec![0, 1]);
+    /// let heads = coin().bind(|a| coin().fmap(move |b| a + b));
+    /// assert_eq!(heads.probability(|&n| n == 1), 0.5);
+    /// ```
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Weighted<A> {
+        outcomes: Vec<(A, f64)>,
+    }
+
+    impl<A> Weighted<A> {
+        /// Creates a distribution from outcomes and their weights.
+        pub fn new(outcomes: Vec<(A, f64)>) -> Self {
+            Weighted { outcomes }
+        }
+
+        /// Creates a distribution giving every value the same weight.
+        pub fn uniform(values: Vec<A>) -> Self {
+            let weight = 1.0 / values.len() as f64;
+            Weighted::new(values.into_iter().map(|a| (a, weight)).collect())
+        }
+
+        /// Returns the outcomes and their weights.
+        pub fn outcomes(&self) -> &[(A, f64)] {
+            &self.outcomes
+        }
+
+        /// Returns the sum of all weights, `1.0` for a normalised distribution
+        /// up to rounding.
+        pub fn total_weight(&self) -> f64 {
+            self.outcomes.iter().map(|(_, w)| w).sum()
+        }
+
+        /// Returns the total weight of the outcomes matching `pred`.
+        pub fn probability<F: FnMut(&A) -> bool>(&self, mut pred: F) -> f64 {
+            self.outcomes
+                .iter()
+                .filter(|(a, _)| pred(a))
+                .map(|(_, w)| w)
+                .sum()
+        }
+    }
+
+    pub struct WeightedKind;
+
+    impl Generic1 for WeightedKind {
+        type Rep1<A> = Weighted<A>;
+    }
+
+    impl<A> Kinded1<A> for Weighted<A> {
+        type Kind1 = WeightedKind;
+    }
+
+    impl<A> Functor<A> for Weighted<A> {
+        fn fmap<B, F: FnMut(A) -> B>(self, mut f: F) -> Weighted<B> {
+            Weighted::new(self.outcomes.into_iter().map(|(a, w)| (f(a), w)).collect())
+        }
+    }
+
+    /// Each pairing of a function and a value is weighted by the product of
+    /// their weights, as for independent events.
+    impl<A: Clone> Applicative<A> for Weighted<A> {
+        fn pure(b: A) -> Weighted<A> {
+            Weighted::new(vec![(b, 1.0)])
+        }
+
+        fn apply<B, F: FnMut(A) -> B>(self, ff: Weighted<F>) -> Weighted<B> {
+            let mut outcomes = Vec::with_capacity(self.outcomes.len() * ff.outcomes.len());
+            for (mut f, wf) in ff.outcomes {
+                for (a, wa) in self.outcomes.iter().cloned() {
+                    outcomes.push((f(a), wf * wa));
+                }
+            }
+            Weighted::new(outcomes)
+        }
+    }
+
+    impl<A: Clone> Monad<A> for Weighted<A> {
+        fn bind<B, F: FnMut(A) -> Weighted<B>>(self, mut f: F) -> Weighted<B> {
+            let outcomes = self
+                .outcomes
+                .into_iter()
+                .flat_map(|(a, wa)| f(a).outcomes.into_iter().map(move |(b, wb)| (b, wa * wb)))
+                .collect();
+            Weighted::new(outcomes)
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(feature = "no_std"))]
+mod weighted_tests {
+    use super::weighted_impls::*;
+    use crate::*;
+
+    const EPSILON: f64 = 1e-12;
+
+    fn die() -> Weighted<u32> {
+        Weighted::uniform((1..=6).collect())
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < EPSILON,
+            "{actual} is not within {EPSILON} of {expected}"
+        );
+    }
+
+    #[test]
+    fn uniform() {
+        let d = die();
+        assert_eq!(d.outcomes().len(), 6);
+        assert_close(d.total_weight(), 1.0);
+        assert_close(d.probability(|&n| n > 4), 1.0 / 3.0);
+    }
+
+    mod functor {
+        use super::*;
+
+        #[test]
+        fn fmap_keeps_weights() {
+            let parity = die().fmap(|n| n % 2 == 0);
+            assert_close(parity.probability(|&even| even), 0.5);
+            assert_close(parity.total_weight(), 1.0);
+        }
+
+        #[test]
+        fn identity_law() {
+            assert_eq!(die().fmap(identity), die());
+        }
+
+        #[test]
+        fn composition_law() {
+            let lhs = die().fmap(|x| multiply_by_two(add_one(x as i32)));
+            let rhs = die().fmap(|x| add_one(x as i32)).fmap(multiply_by_two);
+            assert_eq!(lhs, rhs);
+        }
+    }
+
+    mod applicative {
+        use super::*;
+
+        #[test]
+        fn apply_multiplies_weights() {
+            let ff = Weighted::new(vec![(add_one as fn(i32) -> i32, 0.25), (square, 0.75)]);
+            let v = Weighted::new(vec![(2, 0.5), (3, 0.5)]);
+            assert_eq!(
+                v.apply(ff).outcomes(),
+                &[(3, 0.125), (4, 0.125), (4, 0.375), (9, 0.375)]
+            );
+        }
+
+        #[test]
+        fn identity_law() {
+            assert_eq!(die().apply(Weighted::pure(identity)), die());
+        }
+
+        #[test]
+        fn homomorphism_law() {
+            let lhs = Weighted::pure(5).apply(Weighted::pure(add_one));
+            assert_eq!(lhs, Weighted::pure(add_one(5)));
+        }
+    }
+
+    mod monad {
+        use super::*;
+
+        #[test]
+        fn two_dice() {
+            let sum = die().bind(|a| die().fmap(move |b| a + b));
+            assert_eq!(sum.outcomes().len(), 36);
+            assert_close(sum.total_weight(), 1.0);
+            assert_close(sum.probability(|&n| n == 7), 1.0 / 6.0);
+            assert_close(sum.probability(|&n| n == 2), 1.0 / 36.0);
+            assert_close(sum.probability(|&n| n == 12), 1.0 / 36.0);
+        }
+
+        #[test]
+        fn dependent_step() {
+            // Roll a die, then flip that many coins only if it shows 1 or 2
+            let coins =
+                |n: u32| Weighted::uniform((0..=1).collect::<Vec<u32>>()).fmap(move |h| h * n);
+            let result = die().bind(|n| if n <= 2 { coins(n) } else { Weighted::pure(0) });
+            assert_close(result.total_weight(), 1.0);
+            assert_close(result.probability(|&x| x == 0), 2.0 / 3.0 + 1.0 / 6.0);
+        }
+
+        #[test]
+        fn left_identity_law() {
+            let f = |x: u32| Weighted::uniform(vec![x, x + 1]);
+            assert_eq!(Weighted::pure(3).bind(f), f(3));
+        }
+
+        #[test]
+        fn right_identity_law() {
+            assert_eq!(die().bind(Weighted::pure), die());
+        }
+
+        #[test]
+        fn associativity_law() {
+            let f = |x: u32| Weighted::uniform(vec![x, x * 2]);
+            let g = |x: u32| Weighted::new(vec![(x + 1, 0.25), (x, 0.75)]);
+            let lhs = die().bind(f).bind(g);
+            let rhs = die().bind(|x| f(x).bind(g));
+            assert_eq!(lhs, rhs);
+        }
+    }
+}
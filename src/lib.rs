@@ -39,6 +39,9 @@ pub use core::*;
 mod impls;
 pub use impls::*;
 
+#[cfg(feature = "derive")]
+pub use crab_fp_derive::Bifunctor;
+
 mod util;
 pub use util::utilities::*;
 
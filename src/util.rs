@@ -1,5 +1,6 @@
 pub mod utilities {
     use crate::*;
+    use std::ops::ControlFlow;
 
     /// Identity trait
     ///
@@ -113,6 +114,125 @@ pub mod utilities {
         }
     }
 
+    /// The function monad, where every computation reads a shared argument.
+    ///
+    /// Any `Fn(R) -> A` is a computation that produces an `A` once given an
+    /// environment `R`. `fmap_fn` post-composes a function onto the result,
+    /// `apply_fn` and `bind_fn` run two computations against the same
+    /// environment, and `constant` lifts a value that ignores it. Unlike a
+    /// boxed `Reader`, nothing is allocated: each combinator returns a new
+    /// closure.
+    ///
+    /// `FunctionMonad` is implemented for every `Fn(R) -> A` instead of
+    /// going through `Functor`: a `Generic1` kind would need `Rep1<A>` to name
+    /// the closure `fmap_fn` returns, and closure types cannot be named.
+    ///
+    /// # Example
+    /// ```rust
+    /// use crab_fp::FunctionMonad;
+    ///
+    /// struct Config { base: i32, scale: i32 }
+    ///
+    /// let base = |c: &Config| c.base;
+    /// let scaled = base.bind_fn(|b| move |c: &Config| b * c.scale);
+    /// assert_eq!(scaled(&Config { base: 3, scale: 4 }), 12);
+    /// ```
+    pub trait FunctionMonad<R, A>: Fn(R) -> A + Sized {
+        /// Maps a function over the result, post-composing it.
+        fn fmap_fn<B, G: Fn(A) -> B>(self, g: G) -> impl Fn(R) -> B {
+            move |r| g(self(r))
+        }
+
+        /// Applies the function produced by `ff` to the value produced by
+        /// this computation, both reading the same environment.
+        fn apply_fn<B, F: FnOnce(A) -> B, FF: Fn(R) -> F>(self, ff: FF) -> impl Fn(R) -> B
+        where
+            R: Clone,
+        {
+            move |r: R| ff(r.clone())(self(r))
+        }
+
+        /// Sequences a computation that depends on this computation's result,
+        /// passing the same environment to both.
+        fn bind_fn<B, K: Fn(R) -> B, G: Fn(A) -> K>(self, g: G) -> impl Fn(R) -> B
+        where
+            R: Clone,
+        {
+            move |r: R| g(self(r.clone()))(r)
+        }
+    }
+
+    impl<R, A, Func: Fn(R) -> A> FunctionMonad<R, A> for Func {}
+
+    /// Lifts a value into the function monad, ignoring the environment.
+    ///
+    /// # Example
+    /// ```rust
+    /// use crab_fp::constant;
+    ///
+    /// let always_five = constant(5);
+    /// assert_eq!(always_five("ignored"), 5);
+    /// ```
+    pub fn constant<R, A: Clone>(a: A) -> impl Fn(R) -> A {
+        move |_| a.clone()
+    }
+
+    #[cfg(test)]
+    mod function_monad_tests {
+        use super::*;
+
+        #[test]
+        fn fmap_fn() {
+            let f = add_one.fmap_fn(multiply_by_two);
+            assert_eq!(f(4), 10);
+        }
+
+        #[test]
+        fn apply_fn() {
+            let f = square.apply_fn(|r: i32| move |a: i32| a - r);
+            assert_eq!(f(3), 6);
+        }
+
+        #[test]
+        fn functions_share_environment() {
+            let width = |dims: (i32, i32)| dims.0;
+            let area = width.bind_fn(|w| move |dims: (i32, i32)| w * dims.1);
+            assert_eq!(area((3, 4)), 12);
+        }
+
+        // Left identity: return a >>= f = f a
+        #[test]
+        fn left_identity() {
+            let f = |a: i32| move |r: i32| a * r;
+            let lhs = constant(3).bind_fn(f);
+            let rhs = f(3);
+            for r in -2..3 {
+                assert_eq!(lhs(r), rhs(r));
+            }
+        }
+
+        // Right identity: m >>= return = m
+        #[test]
+        fn right_identity() {
+            let m = square.bind_fn(constant);
+            for r in -2..3 {
+                assert_eq!(m(r), square(r));
+            }
+        }
+
+        // Associativity: (m >>= f) >>= g = m >>= (\x -> f x >>= g)
+        #[test]
+        fn associativity() {
+            let f = |a: i32| move |r: i32| a + r;
+            let g = |b: i32| move |r: i32| b * r;
+            let lhs = square.bind_fn(f).bind_fn(g);
+            let rhs = square.bind_fn(move |x| f(x).bind_fn(g));
+            for r in -2..3 {
+                assert_eq!(lhs(r), rhs(r));
+            }
+        }
+    }
+
     /// Compose two functions, left to right
     ///
     /// # Example
@@ -143,272 +263,3485 @@ pub mod utilities {
         move |a| g(f(a))
     }
 
-    /// Flip the arguments of a function
+    /// Pipe two functions that both read a shared environment
+    ///
+    /// Like `pipe`, but each stage also receives an environment `R`, such as
+    /// a `&Config`, which is passed to the composed function once and handed
+    /// to both stages. This is the Reader pattern without a wrapper type.
     ///
     /// # Example
     /// ```rust
-    /// use crab_fp::flip;
+    /// use crab_fp::pipe_reader;
     ///
-    /// let divide = |a: i32, b: i32| a / b;
-    /// let divide_flipped = flip(divide);
-    /// assert_eq!(divide_flipped(2, 6), 3);
+    /// struct Config {
+    ///     tax: f64,
+    ///     discount: f64,
+    /// }
+    ///
+    /// let config = Config { tax: 0.5, discount: 2.0 };
+    /// let price = pipe_reader(
+    ///     |c: &Config, net: f64| net * (1.0 + c.tax),
+    ///     |c: &Config, gross: f64| gross - c.discount,
+    /// );
+    /// assert_eq!(price(&config, 10.0), 13.0);
     /// ```
-    pub fn flip<A, B, C, F: Fn(A, B) -> C>(f: F) -> impl Fn(B, A) -> C {
-        move |b, a| f(a, b)
+    pub fn pipe_reader<A, B, C, R, F, G>(f: F, g: G) -> impl Fn(R, A) -> C
+    where
+        R: Clone,
+        F: Fn(R, A) -> B,
+        G: Fn(R, B) -> C,
+    {
+        move |r, a| g(r.clone(), f(r, a))
     }
 
-    /// Curry a function of two arguments, returning a function of one argument that returns a function of the other argument
+    #[cfg(test)]
+    mod pipe_reader_tests {
+        use super::*;
+
+        struct Config {
+            offset: i32,
+            scale: i32,
+        }
+
+        #[test]
+        fn environment_reaches_both_stages() {
+            let config = Config {
+                offset: 1,
+                scale: 10,
+            };
+            let other = Config {
+                offset: -4,
+                scale: 3,
+            };
+            let f = pipe_reader(
+                |c: &Config, x: i32| x + c.offset,
+                |c: &Config, x: i32| x * c.scale,
+            );
+            assert_eq!(f(&config, 4), 50);
+            assert_eq!(f(&other, 4), 0);
+        }
+
+        #[test]
+        #[cfg(not(feature = "no_std"))]
+        fn owned_environment_is_cloned() {
+            let greet = pipe_reader(
+                |name: &'static str, n: usize| name.repeat(n),
+                |name: &'static str, s: String| s.len() + name.len(),
+            );
+            assert_eq!(greet("ab", 3), 8);
+        }
+
+        #[test]
+        fn ignoring_environment_is_pipe() {
+            let with_env = pipe_reader(|_: (), x| add_one(x), |_: (), x| multiply_by_two(x));
+            let without = pipe(add_one, multiply_by_two);
+            assert_eq!(with_env((), 5), without(5));
+        }
+    }
+
+    /// Pipe two `Option`-returning functions, stopping at the first `None`
+    ///
+    /// Like `pipe`, but each stage may fail: `g` only runs when `f` returns
+    /// `Some`. This is composition in the `Option` monad, the point-free form
+    /// of `f(a).and_then(g)`.
     ///
     /// # Example
     /// ```rust
-    /// use crab_fp::curry;
+    /// use crab_fp::pipe_opt;
     ///
-    /// fn add(a: i32, b: i32) -> i32 {
-    ///     a + b
-    /// }
-    ///
-    /// let add_curried = curry(add);
-    /// let add_one = add_curried(1);
-    /// assert_eq!(add_one(2), 3);
+    /// let parse_port = pipe_opt(
+    ///     |s: &str| s.strip_prefix("port="),
+    ///     |digits: &str| digits.parse::<u16>().ok(),
+    /// );
+    /// assert_eq!(parse_port("port=8080"), Some(8080));
+    /// assert_eq!(parse_port("host=8080"), None);
+    /// assert_eq!(parse_port("port=http"), None);
     /// ```
-    #[cfg(not(feature = "no_std"))]
-    pub fn curry<A, B, C>(f: fn(A, B) -> C) -> impl Fn(A) -> Box<dyn Fn(B) -> C>
+    pub fn pipe_opt<A, B, C, F, G>(f: F, g: G) -> impl Fn(A) -> Option<C>
     where
-        A: Clone + 'static, // we need to be able to clone so that we can use the same value multiple times
-        B: 'static,
-        C: 'static,
+        F: Fn(A) -> Option<B>,
+        G: Fn(B) -> Option<C>,
     {
-        move |a: A| Box::new(move |b: B| f(a.clone(), b))
+        move |a| f(a).and_then(&g)
     }
 
     #[cfg(test)]
-    #[cfg(not(feature = "no_std"))]
-    mod curry_tests {
+    mod pipe_opt_tests {
         use super::*;
 
+        fn half(x: i32) -> Option<i32> {
+            (x % 2 == 0).then_some(x / 2)
+        }
+
         #[test]
-        fn basic() {
-            let f = curry(|a, b| a + b);
-            let g = f(1);
-            let h = g(2);
-            assert_eq!(h, 3);
+        fn both_stages_succeed() {
+            let quarter = pipe_opt(half, half);
+            assert_eq!(quarter(12), Some(3));
         }
 
         #[test]
-        fn multiple_calls() {
-            let f = curry(|a, b| a + b);
-            let g = f(1);
-            let h1 = g(2);
-            let h2 = g(3);
-            assert_eq!(h1, 3);
-            assert_eq!(h2, 4);
+        fn first_stage_none_skips_second() {
+            let second_ran = ::core::cell::Cell::new(false);
+            let f = pipe_opt(half, |x: i32| {
+                second_ran.set(true);
+                Some(x)
+            });
+            assert_eq!(f(3), None);
+            assert!(!second_ran.get());
         }
 
         #[test]
-        fn non_copy() {
-            let f = curry(|a: String, b: &str| a + b);
-            let g = f("hello".to_string());
-            let h1 = g(", world");
-            let h2 = g("!");
-            assert_eq!(h1, "hello, world");
-            assert_eq!(h2, "hello!");
+        fn second_stage_none() {
+            let quarter = pipe_opt(half, half);
+            assert_eq!(quarter(6), None);
         }
 
         #[test]
-        fn large_struct() {
-            #[derive(Debug, PartialEq, Clone)]
-            struct LargeStruct {
-                data: [u8; 1024],
-                value: i32,
-            }
+        fn changes_types() {
+            let f = pipe_opt(|c: char| c.to_digit(10), |d: u32| u8::try_from(d * 30).ok());
+            assert_eq!(f('7'), Some(210));
+            assert_eq!(f('9'), None);
+            assert_eq!(f('x'), None);
+        }
 
-            let f = curry(|a: LargeStruct, b: i32| a.value + b);
-            let large = LargeStruct {
-                data: [0; 1024],
-                value: 42,
-            };
-            let g = f(large);
-            let h1 = g(8);
-            let h2 = g(58);
-            assert_eq!(h1, 50);
-            assert_eq!(h2, 100);
+        #[test]
+        fn lifted_pipe_matches_pipe() {
+            let lifted = pipe_opt(|x| Some(add_one(x)), |x| Some(multiply_by_two(x)));
+            assert_eq!(lifted(5), Some(pipe(add_one, multiply_by_two)(5)));
         }
     }
 
-    /// Uncurry a function of one argument that returns a function of another argument
+    /// A unary function that can be called through a shared reference
+    ///
+    /// Every `Fn(A) -> B` is `Callable`, as are the named composition types
+    /// [`Compose`] and [`Pipe`]. Stable Rust does not allow user types to
+    /// implement the `Fn` traits, so this trait is what lets named
+    /// compositions be called, nested, and turned back into closures.
     ///
     /// # Example
     /// ```rust
-    /// use crab_fp::{curry, uncurry};
+    /// use crab_fp::Callable;
     ///
-    /// let add = curry(|a, b| a + b);
-    /// let add_uncurried = uncurry(add);
-    /// assert_eq!(add_uncurried(1, 2), 3);
+    /// let add_one = |x: i32| x + 1;
+    /// assert_eq!(add_one.call(1), 2);
     /// ```
-    #[cfg(not(feature = "no_std"))]
-    pub fn uncurry<A: 'static, B: 'static, C: 'static>(
-        f: impl Fn(A) -> Box<dyn Fn(B) -> C> + 'static,
-    ) -> impl Fn(A, B) -> C {
-        move |a, b| (f(a))(b)
+    pub trait Callable<A> {
+        type Output;
+
+        fn call(&self, a: A) -> Self::Output;
+
+        /// Convert into a closure, e.g. to pass to `fmap`
+        fn into_fn(self) -> impl Fn(A) -> Self::Output
+        where
+            Self: Sized,
+        {
+            move |a| self.call(a)
+        }
     }
 
-    /// Convert a value of type Option<T> to Result<T, E> with a default error
-    pub fn option_to_result<T, E>(opt: Option<T>, err: E) -> Result<T, E> {
-        match opt {
-            Some(v) => Ok(v),
-            None => Err(err),
+    impl<A, B, F: Fn(A) -> B> Callable<A> for F {
+        type Output = B;
+
+        fn call(&self, a: A) -> B {
+            self(a)
         }
     }
 
-    /// A function that applies a function to a functor.
-    ///
-    /// This function takes a functor and a function, and applies the function to
-    /// each element of the functor.
-    ///
-    /// # Type Parameters
-    /// * `A` - The type of values contained in the functor
-    /// * `B` - The type of values returned by the function
-    /// * `FA` - The type of the functor
-    /// * `F` - The type of the function
+    /// A named right-to-left composition of two functions
     ///
-    /// # Returns
-    /// A new functor of the same kind containing the transformed values.
+    /// Unlike the opaque closure returned by [`compose`], a `Compose` has a
+    /// nameable type, so it can be stored in struct fields and shows up as
+    /// `Compose<F, G>` in type errors.
     ///
     /// # Example
-    /// ```
-    /// use crab_fp::fmap;
+    /// ```rust
+    /// use crab_fp::{Callable, Compose};
     ///
-    /// let x = Some(5);
-    /// let f = |x| x * 2;
-    /// let y = fmap(x, f);
-    /// assert_eq!(y, Some(10));
+    /// fn add_one(x: i32) -> i32 {
+    ///     x + 1
+    /// }
     ///
-    /// #[cfg(not(feature = "no_std"))]
-    /// {
-    ///     let x = vec![1, 2, 3];
-    ///     let f = |x| x * 2;
-    ///     let y = fmap(x, f);
-    ///     assert_eq!(y, vec![2, 4, 6]);
+    /// fn multiply_by_two(x: i32) -> i32 {
+    ///     x * 2
     /// }
     ///
-    /// let x = Result::<i32, &str>::Ok(5);
-    /// let f = |x| x * 2;
-    /// let y = fmap(x, f);
-    /// assert_eq!(y, Ok(10));
+    /// let add_one_then_multiply_by_two = Compose::new(multiply_by_two, add_one);
+    /// assert_eq!(add_one_then_multiply_by_two.call(5), 12);
     /// ```
-    pub fn fmap<A, B, FA: Functor<A>, F: FnMut(A) -> B>(f: FA, g: F) -> Apply1<FA::Kind1, B> {
-        f.fmap(g)
+    #[derive(Debug, Clone, Copy)]
+    pub struct Compose<F, G> {
+        f: F,
+        g: G,
     }
 
-    /// A function that lifts a value into an applicative context.
-    ///
-    /// This function takes a value and an applicative functor, and lifts the value into the
-    /// applicative context of the functor.
-    ///
-    /// # Type Parameters
-    /// * `A` - The type of the value to lift
-    /// * `FA` - The type of the applicative functor
-    ///
-    /// # Returns
-    /// A new applicative functor containing the lifted value.
-    ///
-    /// # Example
-    /// ```
-    /// use crab_fp::pure;
-    ///
-    /// let y = pure::<i32, Option<_>>(5);
-    /// assert_eq!(y, Some(5));
-    /// ```
-    pub fn pure<A, FA: Applicative<A>>(a: A) -> Apply1<FA::Kind1, A> {
-        FA::pure(a)
+    impl<F, G> Compose<F, G> {
+        /// Compose `f` after `g`, so calling the result with `a` computes `f(g(a))`
+        pub fn new(f: F, g: G) -> Self {
+            Compose { f, g }
+        }
     }
 
-    /// Applies functions contained in an applicative context to values in another applicative context.
-    ///
-    /// This function takes an applicative functor containing values and another applicative functor
-    /// containing functions, and applies each function to each value, collecting the results in a
-    /// new applicative functor.
-    ///
-    /// # Type Parameters
-    /// * `A` - The type of values contained in the first applicative
-    /// * `B` - The type of values in the resulting applicative
-    /// * `FA` - The type of the applicative containing values
-    /// * `F` - The type of function that transforms A into B
+    impl<A, F, G> Callable<A> for Compose<F, G>
+    where
+        G: Callable<A>,
+        F: Callable<G::Output>,
+    {
+        type Output = F::Output;
+
+        fn call(&self, a: A) -> Self::Output {
+            self.f.call(self.g.call(a))
+        }
+    }
+
+    /// A named left-to-right composition of two functions
     ///
-    /// # Returns
-    /// A new applicative functor containing the results of applying the functions to the values.
+    /// The nameable counterpart to [`pipe`], see [`Compose`].
     ///
     /// # Example
-    /// ```
-    /// use crab_fp::ap;
+    /// ```rust
+    /// use crab_fp::{Callable, Pipe};
     ///
-    /// fn add_one(x: i32) -> i32 { x + 1 }
+    /// fn add_one(x: i32) -> i32 {
+    ///     x + 1
+    /// }
     ///
-    /// let x = Some(5);
-    /// let f = Some(add_one);
-    /// let y = ap(x, f);
-    /// assert_eq!(y, Some(6));
+    /// fn multiply_by_two(x: i32) -> i32 {
+    ///     x * 2
+    /// }
+    ///
+    /// let add_one_then_multiply_by_two = Pipe::new(add_one, multiply_by_two);
+    /// assert_eq!(add_one_then_multiply_by_two.call(5), 12);
     /// ```
-    pub fn ap<A, B, F, FA>(x: FA, fs: Apply1<FA::Kind1, F>) -> Apply1<FA::Kind1, B>
+    #[derive(Debug, Clone, Copy)]
+    pub struct Pipe<F, G> {
+        f: F,
+        g: G,
+    }
+
+    impl<F, G> Pipe<F, G> {
+        /// Pipe `f` into `g`, so calling the result with `a` computes `g(f(a))`
+        pub fn new(f: F, g: G) -> Self {
+            Pipe { f, g }
+        }
+    }
+
+    impl<A, F, G> Callable<A> for Pipe<F, G>
     where
-        F: FnMut(A) -> B,
-        FA: Applicative<A>,
+        F: Callable<A>,
+        G: Callable<F::Output>,
     {
-        x.apply::<B, F>(fs)
-    }
+        type Output = G::Output;
+
+        fn call(&self, a: A) -> Self::Output {
+            self.g.call(self.f.call(a))
+        }
+    }
+
+    #[cfg(test)]
+    mod named_composition_tests {
+        use super::*;
+
+        fn add_one(x: i32) -> i32 {
+            x + 1
+        }
+
+        fn multiply_by_two(x: i32) -> i32 {
+            x * 2
+        }
+
+        fn square(x: i32) -> i32 {
+            x * x
+        }
+
+        type Step = fn(i32) -> i32;
+
+        struct Pricing {
+            adjust: Compose<Step, Step>,
+        }
+
+        #[test]
+        fn stored_in_struct_field() {
+            let pricing = Pricing {
+                adjust: Compose::new(multiply_by_two, add_one),
+            };
+            assert_eq!(pricing.adjust.call(5), 12);
+        }
+
+        #[test]
+        fn compose_matches_compose_fn() {
+            let named = Compose::new(multiply_by_two, add_one);
+            let opaque = compose(multiply_by_two, add_one);
+            assert_eq!(named.call(5), opaque(5));
+        }
+
+        #[test]
+        fn pipe_matches_pipe_fn() {
+            let named = Pipe::new(add_one, multiply_by_two);
+            let opaque = pipe(add_one, multiply_by_two);
+            assert_eq!(named.call(5), opaque(5));
+        }
+
+        #[test]
+        fn nested_three_levels() {
+            // square(multiply_by_two(add_one(x)))
+            let composed = Compose::new(square, Compose::new(multiply_by_two, add_one));
+            assert_eq!(composed.call(2), 36);
+
+            let piped = Pipe::new(Pipe::new(add_one, multiply_by_two), square);
+            assert_eq!(piped.call(2), 36);
+
+            let mixed = Compose::new(Pipe::new(multiply_by_two, square), add_one);
+            assert_eq!(mixed.call(2), 36);
+        }
+
+        #[test]
+        fn changes_type() {
+            let describe = Pipe::new(add_one, |x: i32| if x > 0 { "positive" } else { "other" });
+            assert_eq!(describe.call(0), "positive");
+        }
+
+        #[test]
+        fn into_fn() {
+            let f = Compose::new(multiply_by_two, add_one).into_fn();
+            assert_eq!(Some(5).fmap(f), Some(12));
+        }
+    }
+
+    /// Flip the arguments of a function
+    ///
+    /// # Example
+    /// ```rust
+    /// use crab_fp::flip;
+    ///
+    /// let divide = |a: i32, b: i32| a / b;
+    /// let divide_flipped = flip(divide);
+    /// assert_eq!(divide_flipped(2, 6), 3);
+    /// ```
+    pub fn flip<A, B, C, F: Fn(A, B) -> C>(f: F) -> impl Fn(B, A) -> C {
+        move |b, a| f(a, b)
+    }
+
+    /// Convert a function of two arguments into a function taking a single tuple argument
+    ///
+    /// # Example
+    /// ```rust
+    /// use crab_fp::tupled;
+    ///
+    /// let add = |a: i32, b: i32| a + b;
+    /// let add_tupled = tupled(add);
+    /// assert_eq!(add_tupled((1, 2)), 3);
+    /// ```
+    pub fn tupled<A, B, C, F: Fn(A, B) -> C>(f: F) -> impl Fn((A, B)) -> C {
+        move |(a, b)| f(a, b)
+    }
+
+    /// Convert a function taking a single tuple argument into a function of two arguments
+    ///
+    /// # Example
+    /// ```rust
+    /// use crab_fp::untupled;
+    ///
+    /// let add = |(a, b): (i32, i32)| a + b;
+    /// let add_untupled = untupled(add);
+    /// assert_eq!(add_untupled(1, 2), 3);
+    /// ```
+    pub fn untupled<A, B, C, F: Fn((A, B)) -> C>(f: F) -> impl Fn(A, B) -> C {
+        move |a, b| f((a, b))
+    }
+
+    #[cfg(test)]
+    mod tupled_tests {
+        use super::*;
+
+        fn add(a: i32, b: i32) -> i32 {
+            a + b
+        }
+
+        #[test]
+        fn tupled_basic() {
+            assert_eq!(tupled(add)((1, 2)), 3);
+        }
+
+        #[test]
+        fn untupled_basic() {
+            let add_pair = |(a, b): (i32, i32)| a + b;
+            assert_eq!(untupled(add_pair)(1, 2), 3);
+        }
+
+        #[test]
+        fn round_trip() {
+            let add_again = untupled(tupled(add));
+            assert_eq!(add_again(1, 2), add(1, 2));
+
+            let pairs = [(1, 2), (3, 4)];
+            let sums: [i32; 2] = pairs.map(tupled(add));
+            assert_eq!(sums, [3, 7]);
+        }
+    }
+
+    /// Ties the knot of an open-recursive function, memoizing every result.
+    ///
+    /// `f` receives a handle for its recursive calls instead of calling itself
+    /// by name, so `fix` can check a cache before each call. Each argument is
+    /// computed at most once for the lifetime of the returned function, which
+    /// turns naively exponential recursions like Fibonacci into linear ones.
+    ///
+    /// # Type Parameters
+    /// * `A` - The argument type, used as the cache key
+    /// * `B` - The result type, cloned out of the cache
+    /// * `F` - The open-recursive function
+    ///
+    /// # Returns
+    /// The memoized recursive function.
+    ///
+    /// # Example
+    /// ```rust
+    /// use crab_fp::fix;
+    ///
+    /// let fib = fix(|fib: &dyn Fn(u64) -> u64, n: u64| {
+    ///     if n < 2 { n } else { fib(n - 1) + fib(n - 2) }
+    /// });
+    /// assert_eq!(fib(90), 2880067194370816120);
+    /// ```
+    #[cfg(not(feature = "no_std"))]
+    pub fn fix<A, B, F>(f: F) -> impl Fn(A) -> B
+    where
+        A: Clone + Eq + std::hash::Hash,
+        B: Clone,
+        F: Fn(&dyn Fn(A) -> B, A) -> B,
+    {
+        use std::cell::RefCell;
+        use std::collections::HashMap;
+
+        fn go<A, B, F>(f: &F, cache: &RefCell<HashMap<A, B>>, a: A) -> B
+        where
+            A: Clone + Eq + std::hash::Hash,
+            B: Clone,
+            F: Fn(&dyn Fn(A) -> B, A) -> B,
+        {
+            // Release the borrow before recursing, which needs the cache again
+            let cached = cache.borrow().get(&a).cloned();
+            if let Some(b) = cached {
+                return b;
+            }
+            let b = f(&|x| go(f, cache, x), a.clone());
+            cache.borrow_mut().insert(a, b.clone());
+            b
+        }
+
+        let cache = RefCell::new(HashMap::new());
+        move |a| go(&f, &cache, a)
+    }
+
+    #[cfg(test)]
+    #[cfg(not(feature = "no_std"))]
+    mod fix_tests {
+        use super::*;
+        use std::cell::Cell;
+
+        #[test]
+        fn fibonacci() {
+            let fib = fix(
+                |fib: &dyn Fn(u64) -> u64, n: u64| {
+                    if n < 2 { n } else { fib(n - 1) + fib(n - 2) }
+                },
+            );
+            assert_eq!(fib(10), 55);
+            assert_eq!(fib(40), 102_334_155);
+        }
+
+        #[test]
+        fn each_argument_computed_once() {
+            let calls = Cell::new(0);
+            let fib = fix(|fib: &dyn Fn(u64) -> u64, n: u64| {
+                calls.set(calls.get() + 1);
+                if n < 2 { n } else { fib(n - 1) + fib(n - 2) }
+            });
+            assert_eq!(fib(40), 102_334_155);
+            assert_eq!(calls.get(), 41);
+
+            // Later calls are answered from the cache
+            assert_eq!(fib(30), 832_040);
+            assert_eq!(calls.get(), 41);
+        }
+
+        #[test]
+        fn non_numeric_keys() {
+            let steps = fix(|steps: &dyn Fn(String) -> usize, s: String| match s.len() {
+                0 => 0,
+                n => 1 + steps(s[..n / 2].to_string()),
+            });
+            assert_eq!(steps("abcdefgh".to_string()), 4);
+        }
+    }
+
+    /// Curry a function of two arguments, returning a function of one argument that returns a function of the other argument
+    ///
+    /// # Example
+    /// ```rust
+    /// use crab_fp::curry;
+    ///
+    /// fn add(a: i32, b: i32) -> i32 {
+    ///     a + b
+    /// }
+    ///
+    /// let add_curried = curry(add);
+    /// let add_one = add_curried(1);
+    /// assert_eq!(add_one(2), 3);
+    /// ```
+    #[cfg(not(feature = "no_std"))]
+    pub fn curry<A, B, C>(f: fn(A, B) -> C) -> impl Fn(A) -> Box<dyn Fn(B) -> C>
+    where
+        A: Clone + 'static, // we need to be able to clone so that we can use the same value multiple times
+        B: 'static,
+        C: 'static,
+    {
+        move |a: A| Box::new(move |b: B| f(a.clone(), b))
+    }
+
+    #[cfg(test)]
+    #[cfg(not(feature = "no_std"))]
+    mod curry_tests {
+        use super::*;
+
+        #[test]
+        fn basic() {
+            let f = curry(|a, b| a + b);
+            let g = f(1);
+            let h = g(2);
+            assert_eq!(h, 3);
+        }
+
+        #[test]
+        fn multiple_calls() {
+            let f = curry(|a, b| a + b);
+            let g = f(1);
+            let h1 = g(2);
+            let h2 = g(3);
+            assert_eq!(h1, 3);
+            assert_eq!(h2, 4);
+        }
+
+        #[test]
+        fn non_copy() {
+            let f = curry(|a: String, b: &str| a + b);
+            let g = f("hello".to_string());
+            let h1 = g(", world");
+            let h2 = g("!");
+            assert_eq!(h1, "hello, world");
+            assert_eq!(h2, "hello!");
+        }
+
+        #[test]
+        fn large_struct() {
+            #[derive(Debug, PartialEq, Clone)]
+            struct LargeStruct {
+                data: [u8; 1024],
+                value: i32,
+            }
+
+            let f = curry(|a: LargeStruct, b: i32| a.value + b);
+            let large = LargeStruct {
+                data: [0; 1024],
+                value: 42,
+            };
+            let g = f(large);
+            let h1 = g(8);
+            let h2 = g(58);
+            assert_eq!(h1, 50);
+            assert_eq!(h2, 100);
+        }
+    }
+
+    /// Uncurry a function of one argument that returns a function of another argument
+    ///
+    /// # Example
+    /// ```rust
+    /// use crab_fp::{curry, uncurry};
+    ///
+    /// let add = curry(|a, b| a + b);
+    /// let add_uncurried = uncurry(add);
+    /// assert_eq!(add_uncurried(1, 2), 3);
+    /// ```
+    #[cfg(not(feature = "no_std"))]
+    pub fn uncurry<A: 'static, B: 'static, C: 'static>(
+        f: impl Fn(A) -> Box<dyn Fn(B) -> C> + 'static,
+    ) -> impl Fn(A, B) -> C {
+        move |a, b| (f(a))(b)
+    }
+
+    /// Convert a value of type Option<T> to Result<T, E> with a default error
+    pub fn option_to_result<T, E>(opt: Option<T>, err: E) -> Result<T, E> {
+        match opt {
+            Some(v) => Ok(v),
+            None => Err(err),
+        }
+    }
+
+    /// A function that applies a function to a functor.
+    ///
+    /// This function takes a functor and a function, and applies the function to
+    /// each element of the functor.
+    ///
+    /// # Type Parameters
+    /// * `A` - The type of values contained in the functor
+    /// * `B` - The type of values returned by the function
+    /// * `FA` - The type of the functor
+    /// * `F` - The type of the function
+    ///
+    /// # Returns
+    /// A new functor of the same kind containing the transformed values.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::fmap;
+    ///
+    /// let x = Some(5);
+    /// let f = |x| x * 2;
+    /// let y = fmap(x, f);
+    /// assert_eq!(y, Some(10));
+    ///
+    /// #[cfg(not(feature = "no_std"))]
+    /// {
+    ///     let x = vec![1, 2, 3];
+    ///     let f = |x| x * 2;
+    ///     let y = fmap(x, f);
+    ///     assert_eq!(y, vec![2, 4, 6]);
+    /// }
+    ///
+    /// let x = Result::<i32, &str>::Ok(5);
+    /// let f = |x| x * 2;
+    /// let y = fmap(x, f);
+    /// assert_eq!(y, Ok(10));
+    /// ```
+    pub fn fmap<A, B, FA: Functor<A>, F: FnMut(A) -> B>(f: FA, g: F) -> Apply1<FA::Kind1, B> {
+        f.fmap(g)
+    }
+
+    /// Lifts a plain function into one that maps over a functor.
+    ///
+    /// This is `fmap` with the function supplied first, as in Haskell's
+    /// `fmap f`: the result is a reusable transform for any container of the
+    /// kind it is used with. Each lifted function works on a single functor
+    /// type, inferred from its first use; lift again for another container.
+    ///
+    /// # Type Parameters
+    /// * `A` - The type of the values the function accepts
+    /// * `B` - The type of the values the function returns
+    /// * `FA` - The functor the lifted function accepts
+    ///
+    /// # Returns
+    /// A function from `FA` to the same kind of functor holding `B`s.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::lift_fn;
+    ///
+    /// let double = lift_fn(|x: i32| x * 2);
+    /// assert_eq!(double(Some(5)), Some(10));
+    /// assert_eq!(double(None), None);
+    /// ```
+    pub fn lift_fn<A, B, FA: Functor<A>>(
+        f: impl Fn(A) -> B,
+    ) -> impl Fn(FA) -> Apply1<FA::Kind1, B> {
+        move |fa| fa.fmap(&f)
+    }
+
+    #[cfg(test)]
+    mod lift_fn_tests {
+        use super::*;
+
+        #[test]
+        fn option() {
+            let inc = lift_fn(|x: i32| x + 1);
+            assert_eq!(inc(Some(5)), Some(6));
+            assert_eq!(inc(None), None);
+        }
+
+        #[test]
+        #[cfg(not(feature = "no_std"))]
+        fn vec() {
+            let inc = lift_fn(|x: i32| x + 1);
+            assert_eq!(inc(vec![1, 2]), vec![2, 3]);
+        }
+
+        #[test]
+        fn result() {
+            let inc = lift_fn(|x: i32| x + 1);
+            assert_eq!(inc(Ok::<_, &str>(5)), Ok(6));
+            assert_eq!(inc(Err("bad")), Err("bad"));
+        }
+
+        #[test]
+        fn reusable_and_matches_fmap() {
+            let sq = lift_fn(square);
+            for x in [Some(3), None, Some(-4)] {
+                assert_eq!(sq(x), x.fmap(square));
+            }
+        }
+    }
+
+    /// A function that lifts a value into an applicative context.
+    ///
+    /// This function takes a value and an applicative functor, and lifts the value into the
+    /// applicative context of the functor.
+    ///
+    /// # Type Parameters
+    /// * `A` - The type of the value to lift
+    /// * `FA` - The type of the applicative functor
+    ///
+    /// # Returns
+    /// A new applicative functor containing the lifted value.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::pure;
+    ///
+    /// let y = pure::<i32, Option<_>>(5);
+    /// assert_eq!(y, Some(5));
+    /// ```
+    pub fn pure<A, FA: Applicative<A>>(a: A) -> Apply1<FA::Kind1, A> {
+        FA::pure(a)
+    }
+
+    /// Applies functions contained in an applicative context to values in another applicative context.
+    ///
+    /// This function takes an applicative functor containing values and another applicative functor
+    /// containing functions, and applies each function to each value, collecting the results in a
+    /// new applicative functor.
+    ///
+    /// # Type Parameters
+    /// * `A` - The type of values contained in the first applicative
+    /// * `B` - The type of values in the resulting applicative
+    /// * `FA` - The type of the applicative containing values
+    /// * `F` - The type of function that transforms A into B
+    ///
+    /// # Returns
+    /// A new applicative functor containing the results of applying the functions to the values.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::ap;
+    ///
+    /// fn add_one(x: i32) -> i32 { x + 1 }
+    ///
+    /// let x = Some(5);
+    /// let f = Some(add_one);
+    /// let y = ap(x, f);
+    /// assert_eq!(y, Some(6));
+    /// ```
+    pub fn ap<A, B, F, FA>(x: FA, fs: Apply1<FA::Kind1, F>) -> Apply1<FA::Kind1, B>
+    where
+        F: FnMut(A) -> B,
+        FA: Applicative<A>,
+    {
+        x.apply::<B, F>(fs)
+    }
+
+    /// Applies functions in an applicative context to values in another, with
+    /// the functions given first.
+    ///
+    /// This is `ap` with its arguments flipped, matching Haskell's `<**>`, for
+    /// when the function-functor is already on hand and reads better first.
+    ///
+    /// # Type Parameters
+    /// * `A` - The type of values contained in the value applicative
+    /// * `B` - The type of values in the resulting applicative
+    /// * `F` - The type of function that transforms A into B
+    /// * `FA` - The type of the applicative containing values
+    ///
+    /// # Returns
+    /// A new applicative functor containing the results of applying the functions to the values.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::ap_flip;
+    ///
+    /// fn add_one(x: i32) -> i32 { x + 1 }
+    ///
+    /// assert_eq!(ap_flip(Some(add_one), Some(5)), Some(6));
+    /// ```
+    pub fn ap_flip<A, B, F, FA>(fs: Apply1<FA::Kind1, F>, x: FA) -> Apply1<FA::Kind1, B>
+    where
+        F: FnMut(A) -> B,
+        FA: Applicative<A>,
+    {
+        x.apply::<B, F>(fs)
+    }
+
+    #[cfg(test)]
+    mod ap_flip_tests {
+        use super::*;
+
+        #[test]
+        fn option() {
+            assert_eq!(ap_flip(Some(square), Some(4)), Some(square(4)));
+            assert_eq!(ap_flip(None::<fn(i32) -> i32>, Some(4)), None);
+        }
+
+        #[test]
+        fn result() {
+            let x: Result<i32, &str> = Ok(4);
+            assert_eq!(ap_flip(Ok(add_one), x), Ok(add_one(4)));
+
+            let x: Result<i32, &str> = Err("bad");
+            assert_eq!(ap_flip(Ok(add_one), x), Err("bad"));
+        }
+
+        #[test]
+        #[cfg(not(feature = "no_std"))]
+        fn vec() {
+            assert_eq!(ap_flip(vec![multiply_by_two], vec![1, 2]), vec![2, 4]);
+        }
+
+        #[test]
+        fn matches_ap() {
+            assert_eq!(ap_flip(Some(add_one), Some(1)), ap(Some(1), Some(add_one)));
+        }
+    }
+
+    /// Applies functions in a monadic context to values in another, sequencing
+    /// the function effect before the value effect.
+    ///
+    /// This is `ap` defined through the monad, as `mf.bind(|f| ma.fmap(f))`,
+    /// so it always agrees with the monad's own sequencing. For `Option` it
+    /// matches `apply`. For `Result` it differs only when both sides fail: the
+    /// function's error is returned, where `apply` returns the value's. For
+    /// `Vec` it produces every value for the first function, then every value
+    /// for the next, which is also the order `Vec`'s `apply` uses.
+    ///
+    /// The value container is cloned for each function, since `bind` may call
+    /// its continuation more than once.
+    ///
+    /// # Type Parameters
+    /// * `A` - The type of values contained in the value monad
+    /// * `B` - The type of values in the resulting monad
+    /// * `F` - The type of function that transforms A into B
+    /// * `MF` - The type of the monad containing functions
+    /// * `MA` - The type of the monad containing values
+    ///
+    /// # Returns
+    /// A new monad containing the results of applying the functions to the values.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::ap_seq;
+    ///
+    /// let f: Result<fn(i32) -> i32, &str> = Err("no function");
+    /// let x: Result<i32, &str> = Err("no value");
+    /// assert_eq!(ap_seq(f, x), Err("no function"));
+    /// ```
+    pub fn ap_seq<A, B, F, MF, MA>(mf: MF, ma: MA) -> Apply1<MF::Kind1, B>
+    where
+        F: FnMut(A) -> B,
+        MF: Monad<F>,
+        MA: Functor<A, Kind1 = MF::Kind1> + Clone,
+    {
+        mf.bind::<B, _>(|f| ma.clone().fmap(f))
+    }
+
+    #[cfg(test)]
+    mod ap_seq_tests {
+        use super::*;
+
+        #[test]
+        fn option_matches_apply() {
+            let f = Some(fn_ptr!(add_one));
+            assert_eq!(ap_seq(f, Some(4)), Some(4).apply(f));
+            assert_eq!(ap_seq(None::<fn(i32) -> i32>, Some(4)), None);
+            assert_eq!(ap_seq(f, None), None);
+        }
+
+        #[test]
+        fn result_matches_apply() {
+            let f: Result<fn(i32) -> i32, &str> = Ok(square);
+            assert_eq!(ap_seq(f, Ok(4)), Ok(4).apply(f));
+
+            // The function effect is sequenced first, so its error wins
+            let f: Result<fn(i32) -> i32, &str> = Err("no function");
+            let x: Result<i32, &str> = Err("no value");
+            assert_eq!(ap_seq(f, x), Err("no function"));
+            assert_eq!(x.apply(f), Err("no value"));
+        }
+
+        #[test]
+        #[cfg(not(feature = "no_std"))]
+        fn vec_order_is_function_outer() {
+            let fs = vec![fn_ptr!(add_one), fn_ptr!(multiply_by_two)];
+            let xs = vec![10, 20];
+            assert_eq!(ap_seq(fs.clone(), xs.clone()), vec![11, 21, 20, 40]);
+            assert_eq!(ap_seq(fs.clone(), xs.clone()), xs.apply(fs));
+        }
+    }
+
+    #[cfg(test)]
+    mod standalone_ap_tests {
+        use super::*;
+
+        #[test]
+        fn test_ap() {
+            fn add_one(x: i32) -> i32 {
+                x + 1
+            }
+
+            let x = Some(5);
+            let f = Some(add_one);
+            let y = ap(x, f);
+            assert_eq!(y, Some(6));
+
+            #[cfg(not(feature = "no_std"))]
+            {
+                let x = vec![1, 2, 3];
+                let f = vec![add_one];
+                let y = ap(x, f);
+                assert_eq!(y, vec![2, 3, 4]);
+
+                let x = vec![1, 2, 3];
+                let f = vec![add_one, add_one];
+                let y = ap(x, f);
+                assert_eq!(y, vec![2, 3, 4, 2, 3, 4]);
+            }
+
+            let x: Result<i32, &str> = Ok(5);
+            let f = Ok(add_one);
+            let y = ap(x, f);
+            assert_eq!(y, Ok(6));
+        }
+    }
+
+    #[cfg(test)]
+    mod standalone_pure_tests {
+        use super::*;
+
+        #[test]
+        fn test_pure() {
+            let y = pure::<i32, Option<_>>(5);
+            assert_eq!(y, Some(5));
+
+            #[cfg(not(feature = "no_std"))]
+            {
+                let y = pure::<i32, Vec<_>>(5);
+                assert_eq!(y, vec![5]);
+            }
+
+            let y = pure::<i32, Result<_, &str>>(5);
+            assert_eq!(y, Ok(5));
+        }
+    }
+
+    /// Lifts a value into the same applicative context as an existing value.
+    ///
+    /// Unlike `pure`, the applicative kind does not need to be annotated: it
+    /// is inferred from `_witness`, which is only used for its type.
+    ///
+    /// # Type Parameters
+    /// * `A` - The type of the value to lift
+    /// * `FA` - The type of the witness applicative
+    ///
+    /// # Returns
+    /// A new applicative of the witness's kind containing the lifted value.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::pure_like;
+    ///
+    /// let existing: Option<i32> = None;
+    /// assert_eq!(pure_like(&existing, 5), Some(5));
+    /// ```
+    pub fn pure_like<A, FA: Applicative<A>>(_witness: &FA, a: A) -> Apply1<FA::Kind1, A> {
+        FA::pure(a)
+    }
+
+    #[cfg(test)]
+    mod pure_like_tests {
+        use super::*;
+
+        #[test]
+        fn option() {
+            assert_eq!(pure_like(&Some(1), 5), Some(5));
+            assert_eq!(pure_like(&None::<i32>, 5), Some(5));
+        }
+
+        #[test]
+        fn result() {
+            let failed: Result<i32, &str> = Err("bad");
+            assert_eq!(pure_like(&failed, 5), Ok(5));
+        }
+
+        #[test]
+        #[cfg(not(feature = "no_std"))]
+        fn vec() {
+            assert_eq!(pure_like(&vec![1, 2, 3], 5), vec![5]);
+        }
+    }
+
+    /// Transforms every value of a map with a fallible function, keeping the keys.
+    ///
+    /// Values are visited in the map's iteration order and the traversal stops at
+    /// the first error, which is returned in place of the map.
+    ///
+    /// # Type Parameters
+    /// * `K` - The type of the map's keys
+    /// * `V` - The type of the map's values
+    /// * `W` - The type of the transformed values
+    /// * `E` - The type of the error
+    /// * `F` - The type of the fallible function
+    ///
+    /// # Returns
+    /// `Ok` with a map of the transformed values, or the first error encountered.
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use crab_fp::traverse_values;
+    ///
+    /// let config = HashMap::from([("port", "8080"), ("workers", "4")]);
+    /// let parsed = traverse_values(config, |v| v.parse::<u16>());
+    /// assert_eq!(parsed.unwrap()["port"], 8080);
+    /// ```
+    #[cfg(not(feature = "no_std"))]
+    pub fn traverse_values<K, V, W, E, F>(
+        m: std::collections::HashMap<K, V>,
+        mut f: F,
+    ) -> Result<std::collections::HashMap<K, W>, E>
+    where
+        K: Eq + std::hash::Hash,
+        F: FnMut(V) -> Result<W, E>,
+    {
+        m.into_iter().map(|(k, v)| f(v).map(|w| (k, w))).collect()
+    }
+
+    #[cfg(test)]
+    #[cfg(not(feature = "no_std"))]
+    mod traverse_values_tests {
+        use super::*;
+        use std::collections::HashMap;
+
+        #[test]
+        fn all_ok() {
+            let m = HashMap::from([("a", "1"), ("b", "2")]);
+            let result = traverse_values(m, |v| v.parse::<i32>());
+            assert_eq!(result, Ok(HashMap::from([("a", 1), ("b", 2)])));
+        }
+
+        #[test]
+        fn one_fails() {
+            let m = HashMap::from([("a", "1"), ("b", "two"), ("c", "3")]);
+            let result = traverse_values(m, |v| v.parse::<i32>().map_err(|_| v));
+            assert_eq!(result, Err("two"));
+        }
+
+        #[test]
+        fn empty() {
+            let m: HashMap<&str, &str> = HashMap::new();
+            let result = traverse_values(m, |v| v.parse::<i32>());
+            assert_eq!(result, Ok(HashMap::new()));
+        }
+    }
+
+    /// Maps a fallible function over an iterator, collecting the successes
+    /// into any `FromIterator` target.
+    ///
+    /// This is `traverse` for `Result` without committing to `Vec`: the results
+    /// can land directly in a `HashSet`, a `BTreeMap` of pairs or a `String`.
+    /// Items are pulled in order and nothing is pulled after the first error.
+    ///
+    /// # Type Parameters
+    /// * `A` - The type of the input items
+    /// * `B` - The type of the transformed items
+    /// * `E` - The type of the error
+    /// * `C` - The collection to build
+    /// * `F` - The type of the fallible function
+    ///
+    /// # Returns
+    /// `Ok` with the collected results, or the first error.
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use crab_fp::traverse_into;
+    ///
+    /// let ids: Result<BTreeSet<u32>, _> = traverse_into(["3", "1", "3"].into_iter(), str::parse);
+    /// assert_eq!(ids, Ok(BTreeSet::from([1, 3])));
+    /// ```
+    pub fn traverse_into<A, B, E, C, F>(iter: impl Iterator<Item = A>, f: F) -> Result<C, E>
+    where
+        C: FromIterator<B>,
+        F: FnMut(A) -> Result<B, E>,
+    {
+        iter.map(f).collect()
+    }
+
+    #[cfg(test)]
+    #[cfg(not(feature = "no_std"))]
+    mod traverse_into_tests {
+        use super::*;
+        use std::collections::HashSet;
+
+        #[test]
+        fn into_hash_set() {
+            let words = ["b", "a", "b", "c"].into_iter();
+            let result: Result<HashSet<char>, &str> =
+                traverse_into(words, |w| w.chars().next().ok_or("empty"));
+            assert_eq!(result, Ok(HashSet::from(['a', 'b', 'c'])));
+        }
+
+        #[test]
+        fn into_string() {
+            let digits = [1, 2, 3].into_iter();
+            let result: Result<String, u32> =
+                traverse_into(digits, |d| char::from_digit(d, 10).ok_or(d));
+            assert_eq!(result, Ok("123".to_string()));
+        }
+
+        #[test]
+        fn short_circuits() {
+            let mut pulled = 0;
+            let digits = [1, 2, 42, 3].into_iter().inspect(|_| pulled += 1);
+            let result: Result<String, u32> =
+                traverse_into(digits, |d| char::from_digit(d, 10).ok_or(d));
+            assert_eq!(result, Err(42));
+            assert_eq!(pulled, 3);
+        }
+    }
+
+    /// Keeps the `Some` values of a vector, dropping every `None`.
+    ///
+    /// This is the "filter" reading of `Vec<Option<A>>`: missing values are
+    /// expected and skipped. Use `require_all` when any `None` should
+    /// invalidate the whole vector.
+    ///
+    /// # Type Parameters
+    /// * `A` - The type of the present values
+    ///
+    /// # Returns
+    /// The present values, in order.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::catch_nones;
+    ///
+    /// assert_eq!(catch_nones(vec![Some(1), None, Some(3)]), vec![1, 3]);
+    /// ```
+    #[cfg(not(feature = "no_std"))]
+    pub fn catch_nones<A>(v: Vec<Option<A>>) -> Vec<A> {
+        v.into_iter().flatten().collect()
+    }
+
+    /// Collects a vector of options, failing if any value is missing.
+    ///
+    /// This is the "validate" reading of `Vec<Option<A>>`, the same as
+    /// sequencing it in the `Option` applicative: every value must be
+    /// present. Use `catch_nones` to skip missing values instead.
+    ///
+    /// # Type Parameters
+    /// * `A` - The type of the present values
+    ///
+    /// # Returns
+    /// `Some` with every value in order, or `None` if any was missing.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::require_all;
+    ///
+    /// assert_eq!(require_all(vec![Some(1), Some(3)]), Some(vec![1, 3]));
+    /// assert_eq!(require_all(vec![Some(1), None, Some(3)]), None);
+    /// ```
+    #[cfg(not(feature = "no_std"))]
+    pub fn require_all<A>(v: Vec<Option<A>>) -> Option<Vec<A>> {
+        v.into_iter().collect()
+    }
+
+    #[cfg(test)]
+    #[cfg(not(feature = "no_std"))]
+    mod catch_nones_tests {
+        use super::*;
+
+        #[test]
+        fn drops_nones() {
+            assert_eq!(
+                catch_nones(vec![None, Some('a'), None, Some('b')]),
+                vec!['a', 'b']
+            );
+            assert_eq!(catch_nones(vec![None::<i32>, None]), vec![]);
+        }
+
+        #[test]
+        fn without_nones() {
+            assert_eq!(catch_nones(vec![Some(1), Some(2)]), vec![1, 2]);
+        }
+
+        #[test]
+        fn empty() {
+            assert_eq!(catch_nones(Vec::<Option<i32>>::new()), vec![]);
+        }
+    }
+
+    #[cfg(test)]
+    #[cfg(not(feature = "no_std"))]
+    mod require_all_tests {
+        use super::*;
+
+        #[test]
+        fn any_none_fails() {
+            assert_eq!(require_all(vec![Some('a'), None, Some('b')]), None);
+            assert_eq!(require_all(vec![None::<i32>]), None);
+        }
+
+        #[test]
+        fn without_nones() {
+            assert_eq!(require_all(vec![Some(1), Some(2)]), Some(vec![1, 2]));
+        }
+
+        #[test]
+        fn empty() {
+            assert_eq!(require_all(Vec::<Option<i32>>::new()), Some(vec![]));
+        }
+
+        #[test]
+        fn agrees_with_transpose() {
+            let v = vec![Some(4), Some(5)];
+            assert_eq!(require_all(v.clone()), v.transpose());
+        }
+    }
+
+    /// Maps every element to an alternative and combines them with `alt`.
+    ///
+    /// This is Haskell's `asum . map f`. For `Option` it returns the first
+    /// `Some`, which suits trying candidates until one works; for `Vec` it
+    /// concatenates every result. `f` still runs on every element, since
+    /// `alt` takes both sides by value.
+    ///
+    /// # Type Parameters
+    /// * `A` - The type of the candidates
+    /// * `B` - The type of the successful results
+    /// * `FU` - The alternative each candidate is mapped to
+    /// * `F` - The type of the function trying a candidate
+    ///
+    /// # Returns
+    /// The combination of every result, or `nothing()` for an empty vector.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::traverse_first;
+    ///
+    /// let port = traverse_first(vec!["http", "8080", "443"], |s| s.parse::<u16>().ok());
+    /// assert_eq!(port, Some(8080));
+    /// ```
+    #[cfg(not(feature = "no_std"))]
+    pub fn traverse_first<A, B, FU, F>(v: Vec<A>, f: F) -> Apply1<FU::Kind1, B>
+    where
+        FU: Alternative<B>,
+        F: FnMut(A) -> FU,
+    {
+        v.into_iter().map(f).fold(FU::nothing(), |acc, fu| {
+            <Apply1<FU::Kind1, B> as Alternative<B>>::alt(acc, fu)
+        })
+    }
+
+    #[cfg(test)]
+    #[cfg(not(feature = "no_std"))]
+    mod traverse_first_tests {
+        use super::*;
+
+        #[test]
+        fn option_first_success() {
+            let first_even =
+                traverse_first(vec![1, 3, 4, 5, 6], |x| (x % 2 == 0).then_some(x * 10));
+            assert_eq!(first_even, Some(40));
+        }
+
+        #[test]
+        fn option_all_fail() {
+            let parsed = traverse_first(vec!["a", "b"], |s| s.parse::<i32>().ok());
+            assert_eq!(parsed, None);
+        }
+
+        #[test]
+        fn option_empty() {
+            assert_eq!(traverse_first(Vec::<i32>::new(), Some), None);
+        }
+
+        #[test]
+        fn vec_concatenates() {
+            let all = traverse_first(vec![1, 2, 3], |x| vec![x; x]);
+            assert_eq!(all, vec![1, 2, 2, 3, 3, 3]);
+        }
+    }
+
+    /// Transforms every key of a map, keeping the values.
+    ///
+    /// If `f` maps several keys to the same new key, the entries are inserted
+    /// in the map's iteration order and the last one inserted wins. Because
+    /// `HashMap` iteration order is unspecified, which of the colliding values
+    /// survives is unspecified too; use an injective `f` to keep every entry.
+    ///
+    /// # Type Parameters
+    /// * `K` - The type of the original keys
+    /// * `K2` - The type of the new keys
+    /// * `V` - The type of the values
+    /// * `F` - The type of the key function
+    ///
+    /// # Returns
+    /// A map with the transformed keys.
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use crab_fp::map_keys;
+    ///
+    /// let ports = HashMap::from([("http", 80), ("https", 443)]);
+    /// let upper = map_keys(ports, |k| k.to_uppercase());
+    /// assert_eq!(upper["HTTPS"], 443);
+    /// ```
+    #[cfg(not(feature = "no_std"))]
+    pub fn map_keys<K, K2, V, F>(
+        m: std::collections::HashMap<K, V>,
+        mut f: F,
+    ) -> std::collections::HashMap<K2, V>
+    where
+        K2: Eq + std::hash::Hash,
+        F: FnMut(K) -> K2,
+    {
+        m.into_iter().map(|(k, v)| (f(k), v)).collect()
+    }
+
+    #[cfg(test)]
+    #[cfg(not(feature = "no_std"))]
+    mod map_keys_tests {
+        use super::*;
+        use std::collections::HashMap;
+
+        #[test]
+        fn non_colliding() {
+            let m = HashMap::from([(1, "one"), (2, "two")]);
+            let result = map_keys(m, |k| k * 10);
+            assert_eq!(result, HashMap::from([(10, "one"), (20, "two")]));
+        }
+
+        #[test]
+        fn colliding_keeps_one_value() {
+            let m = HashMap::from([(1, "odd"), (2, "even"), (3, "also odd")]);
+            let result = map_keys(m, |k| k % 2);
+            assert_eq!(result.len(), 2);
+            assert_eq!(result[&0], "even");
+            assert!(result[&1] == "odd" || result[&1] == "also odd");
+        }
+
+        #[test]
+        fn colliding_keeps_last_inserted() {
+            let m = HashMap::from([("a", 1), ("b", 2), ("c", 3)]);
+            let last = m.values().last().copied();
+            let result = map_keys(m, |_| ());
+            assert_eq!(result.get(&()).copied(), last);
+        }
+    }
+
+    /// Runs a monadic loop while a condition on the state holds.
+    ///
+    /// Starting from `init`, the state is checked with `cond`; while it holds,
+    /// `body` produces the next state inside the monad and the loop continues
+    /// from there. Once the condition fails the final state is lifted with
+    /// `pure`. The loop stops early whenever the monad does, so with `Result`
+    /// the first `Err` ends it, and with `Option` the first `None`.
+    ///
+    /// The steps run in a plain loop rather than through nested `bind`s, so
+    /// the number of iterations is not limited by the stack.
+    ///
+    /// # Type Parameters
+    /// * `S` - The type of the loop state
+    /// * `M` - The monad the loop body runs in
+    /// * `Cond` - The type of the loop condition
+    /// * `Body` - The type of the loop body
+    ///
+    /// # Returns
+    /// The final state in the monadic context.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::while_m;
+    ///
+    /// let doubled = while_m(1, |n| *n < 100, |n| Ok::<_, &str>(n * 2));
+    /// assert_eq!(doubled, Ok(128));
+    /// ```
+    pub fn while_m<S, M, Cond, Body>(init: S, cond: Cond, mut body: Body) -> M
+    where
+        M: ShortCircuit<S>,
+        Cond: Fn(&S) -> bool,
+        Body: FnMut(S) -> M,
+    {
+        let mut s = init;
+        while cond(&s) {
+            match body(s).branch() {
+                ControlFlow::Continue(next) => s = next,
+                ControlFlow::Break(residual) => return M::from_residual(residual),
+            }
+        }
+        M::pure(s)
+    }
+
+    #[cfg(test)]
+    mod while_m_tests {
+        use super::*;
+
+        #[test]
+        fn result_terminates() {
+            let mut steps = 0;
+            let result = while_m(
+                0,
+                |n| *n < 5,
+                |n| {
+                    steps += 1;
+                    Ok::<_, &str>(n + 1)
+                },
+            );
+            assert_eq!(result, Ok(5));
+            assert_eq!(steps, 5);
+        }
+
+        #[test]
+        fn result_errors_mid_loop() {
+            let mut steps = 0;
+            let result = while_m(
+                0,
+                |n| *n < 5,
+                |n| {
+                    steps += 1;
+                    if n == 2 { Err("too big") } else { Ok(n + 1) }
+                },
+            );
+            assert_eq!(result, Err("too big"));
+            assert_eq!(steps, 3);
+        }
+
+        #[test]
+        fn condition_false_initially() {
+            let result = while_m(10, |n| *n < 5, |n| Ok::<_, &str>(n + 1));
+            assert_eq!(result, Ok(10));
+        }
+
+        #[test]
+        fn long_loop_does_not_grow_stack() {
+            let result = while_m(0u32, |n| *n < 1_000_000, |n| Ok::<_, &str>(n + 1));
+            assert_eq!(result, Ok(1_000_000));
+        }
+
+        #[test]
+        fn option() {
+            let result = while_m(1, |n| *n < 10, |n| Some(n * 3));
+            assert_eq!(result, Some(27));
+
+            let result = while_m(1, |n| *n < 10, |n| if n > 2 { None } else { Some(n * 3) });
+            assert_eq!(result, None);
+        }
+    }
+
+    /// Collects at most `max` successes from a fallible iterator.
+    ///
+    /// Items are pulled one at a time until `max` successes have been
+    /// collected, the iterator is exhausted, or an error is found. Nothing
+    /// beyond the bound is pulled, so this is safe to use on infinite iterators.
+    ///
+    /// # Type Parameters
+    /// * `A` - The type of the successful values
+    /// * `E` - The type of the error
+    ///
+    /// # Returns
+    /// `Ok` with up to `max` values, or the first error met before the bound.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::sequence_bounded;
+    ///
+    /// let readings = (1..).map(|n| if n < 100 { Ok(n) } else { Err("sensor failure") });
+    /// assert_eq!(sequence_bounded(readings, 3), Ok(vec![1, 2, 3]));
+    /// ```
+    #[cfg(not(feature = "no_std"))]
+    pub fn sequence_bounded<A, E>(
+        iter: impl Iterator<Item = Result<A, E>>,
+        max: usize,
+    ) -> Result<Vec<A>, E> {
+        iter.take(max).collect()
+    }
+
+    #[cfg(test)]
+    #[cfg(not(feature = "no_std"))]
+    mod sequence_bounded_tests {
+        use super::*;
+
+        #[test]
+        fn stops_at_bound() {
+            let mut pulled = 0;
+            let iter = (1..).map(|n| {
+                pulled += 1;
+                Ok::<_, &str>(n)
+            });
+            assert_eq!(sequence_bounded(iter, 3), Ok(vec![1, 2, 3]));
+            assert_eq!(pulled, 3);
+        }
+
+        #[test]
+        fn stops_at_first_error_before_bound() {
+            let items = vec![Ok(1), Err("bad"), Ok(3), Err("worse")];
+            assert_eq!(sequence_bounded(items.into_iter(), 10), Err("bad"));
+        }
+
+        #[test]
+        fn error_after_bound_is_ignored() {
+            let items = vec![Ok(1), Ok(2), Err("bad")];
+            assert_eq!(sequence_bounded(items.into_iter(), 2), Ok(vec![1, 2]));
+        }
+
+        #[test]
+        fn shorter_than_bound() {
+            let items: Vec<Result<i32, &str>> = vec![Ok(1), Ok(2)];
+            assert_eq!(sequence_bounded(items.into_iter(), 5), Ok(vec![1, 2]));
+        }
+
+        #[test]
+        fn zero_bound() {
+            let items = vec![Err::<i32, _>("bad")];
+            assert_eq!(sequence_bounded(items.into_iter(), 0), Ok(vec![]));
+        }
+    }
+
+    /// Consumes the successes of a fallible iterator lazily, stopping at the
+    /// first error.
+    ///
+    /// `f` receives an iterator over the `Ok` values, pulled one at a time
+    /// from `iter` as `f` asks for them, so nothing is buffered. When an `Err`
+    /// is pulled the values end there, nothing more is pulled, and the error
+    /// replaces `f`'s result. Errors that `f` never reaches are not seen, so
+    /// an `f` that stops early can succeed on an input that contains errors.
+    ///
+    /// # Type Parameters
+    /// * `A` - The type of the successful values
+    /// * `E` - The type of the error
+    /// * `R` - The type of `f`'s result
+    ///
+    /// # Returns
+    /// `f`'s result, or the first error pulled from `iter`.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::sequence_lazy;
+    ///
+    /// let lines = ["1", "2", "3"].into_iter().map(|s| s.parse::<i32>());
+    /// assert_eq!(sequence_lazy(lines, |nums| nums.sum::<i32>()), Ok(6));
+    ///
+    /// let lines = ["1", "x", "3"].into_iter().map(|s| s.parse::<i32>());
+    /// assert!(sequence_lazy(lines, |nums| nums.sum::<i32>()).is_err());
+    /// ```
+    pub fn sequence_lazy<A, E, R>(
+        iter: impl Iterator<Item = Result<A, E>>,
+        f: impl FnOnce(&mut dyn Iterator<Item = A>) -> R,
+    ) -> Result<R, E> {
+        struct Oks<'e, I, E> {
+            iter: I,
+            error: &'e mut Option<E>,
+        }
+
+        impl<A, E, I: Iterator<Item = Result<A, E>>> Iterator for Oks<'_, I, E> {
+            type Item = A;
+
+            fn next(&mut self) -> Option<A> {
+                if self.error.is_some() {
+                    return None;
+                }
+                match self.iter.next()? {
+                    Ok(a) => Some(a),
+                    Err(e) => {
+                        *self.error = Some(e);
+                        None
+                    }
+                }
+            }
+        }
+
+        let mut error = None;
+        let result = f(&mut Oks {
+            iter,
+            error: &mut error,
+        });
+        match error {
+            Some(e) => Err(e),
+            None => Ok(result),
+        }
+    }
+
+    #[cfg(test)]
+    mod sequence_lazy_tests {
+        use super::*;
+
+        #[test]
+        fn all_ok() {
+            let items = [Ok(1), Ok(2), Ok(3)].into_iter();
+            assert_eq!(sequence_lazy::<_, (), _>(items, |xs| xs.max()), Ok(Some(3)));
+        }
+
+        #[test]
+        fn stops_pulling_after_first_error() {
+            let mut pulled = 0;
+            let items = (1..).map(|n| {
+                pulled += 1;
+                if n == 3 { Err("bad") } else { Ok(n) }
+            });
+            let result = sequence_lazy(items, |xs| xs.count());
+            assert_eq!(result, Err("bad"));
+            assert_eq!(pulled, 3);
+        }
+
+        #[test]
+        fn values_end_at_error() {
+            let items = [Ok(1), Ok(2), Err("bad"), Ok(4)].into_iter();
+            let mut seen = 0;
+            let result = sequence_lazy(items, |xs| {
+                xs.for_each(|x| seen += x);
+                // Asking again after the error yields nothing more
+                xs.next()
+            });
+            assert_eq!(result, Err("bad"));
+            assert_eq!(seen, 3);
+        }
+
+        #[test]
+        fn early_exit_pulls_only_what_is_needed() {
+            let mut pulled = 0;
+            let items = (1..).map(|n| {
+                pulled += 1;
+                Ok::<_, ()>(n)
+            });
+            let result = sequence_lazy(items, |xs| xs.take(2).sum::<i32>());
+            assert_eq!(result, Ok(3));
+            assert_eq!(pulled, 2);
+        }
+
+        #[test]
+        fn empty() {
+            let items = ::core::iter::empty::<Result<i32, ()>>();
+            assert_eq!(sequence_lazy(items, |xs| xs.count()), Ok(0));
+        }
+    }
+
+    /// Folds over a vector with a monadic step function, left to right.
+    ///
+    /// This is the monadic generalisation of `fold`: each step returns the next
+    /// accumulator inside a monad. With `Result` the fold stops at the first
+    /// `Err`, and with `Option` at the first `None`; no later items are
+    /// visited.
+    ///
+    /// Items are moved into `f` one at a time and the steps run in a plain
+    /// loop, so long vectors do not grow the stack.
+    ///
+    /// # Type Parameters
+    /// * `A` - The type of the items being folded
+    /// * `B` - The type of the accumulator
+    /// * `M` - The monad the step function runs in
+    /// * `F` - The type of the step function
+    ///
+    /// # Returns
+    /// The final accumulator in the monadic context.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::fold_m;
+    ///
+    /// let total = fold_m(vec![1, 2, 3], 0, |acc: i32, x| acc.checked_add(x));
+    /// assert_eq!(total, Some(6));
+    /// ```
+    #[cfg(not(feature = "no_std"))]
+    pub fn fold_m<A, B, M, F>(items: Vec<A>, init: B, mut f: F) -> M
+    where
+        M: ShortCircuit<B>,
+        F: FnMut(B, A) -> M,
+    {
+        let mut acc = init;
+        for a in items {
+            match f(acc, a).branch() {
+                ControlFlow::Continue(next) => acc = next,
+                ControlFlow::Break(residual) => return M::from_residual(residual),
+            }
+        }
+        M::pure(acc)
+    }
+
+    #[cfg(test)]
+    #[cfg(not(feature = "no_std"))]
+    mod fold_m_tests {
+        use super::*;
+
+        fn add_non_negative(acc: i32, x: i32) -> Result<i32, String> {
+            if x < 0 {
+                Err(format!("negative element: {}", x))
+            } else {
+                Ok(acc + x)
+            }
+        }
+
+        #[test]
+        fn result_sums() {
+            assert_eq!(fold_m(vec![1, 2, 3], 0, add_non_negative), Ok(6));
+        }
+
+        #[test]
+        fn result_stops_at_negative() {
+            let mut visited = vec![];
+            let result = fold_m(vec![1, -2, 3, -4], 0, |acc, x| {
+                visited.push(x);
+                add_non_negative(acc, x)
+            });
+            assert_eq!(result, Err("negative element: -2".to_string()));
+            assert_eq!(visited, vec![1, -2]);
+        }
+
+        #[test]
+        fn option() {
+            let result = fold_m(vec![2, 3, 4], 1, |acc: i32, x| acc.checked_mul(x));
+            assert_eq!(result, Some(24));
+
+            let result = fold_m(vec![i32::MAX, 2], 1, |acc: i32, x| acc.checked_mul(x));
+            assert_eq!(result, None);
+        }
+
+        #[test]
+        fn empty_returns_init() {
+            assert_eq!(fold_m(vec![], 7, add_non_negative), Ok(7));
+        }
+
+        #[test]
+        fn moves_items_without_clone() {
+            struct Token(u32);
+            let tokens = vec![Token(1), Token(2), Token(3)];
+            let total = fold_m(tokens, 0, |acc, Token(n)| Some(acc + n));
+            assert_eq!(total, Some(6));
+        }
+
+        #[test]
+        fn long_fold_does_not_grow_stack() {
+            let items: Vec<u64> = (0..1_000_000).collect();
+            let total = fold_m(items, 0u64, |acc, x| Ok::<_, &str>(acc + x));
+            assert_eq!(total, Ok(499_999_500_000));
+        }
+    }
+
+    /// Zips two vectors with an effectful combining function.
+    ///
+    /// Pairs are combined left to right, so with `Result` or `Option` the zip
+    /// stops at the first failure and no later pairs are combined. Like
+    /// `Iterator::zip`, the longer vector is truncated to the length of the
+    /// shorter one.
+    ///
+    /// The elements are moved into `f` and each output is pushed onto a
+    /// single vector, so nothing is cloned and the zip runs in a plain loop.
+    ///
+    /// # Type Parameters
+    /// * `A` - The type of the first vector's elements
+    /// * `B` - The type of the second vector's elements
+    /// * `C` - The type of the combined elements
+    /// * `M` - The monad the combining function runs in
+    /// * `F` - The type of the combining function
+    ///
+    /// # Returns
+    /// Every combined element in order, in the monadic context.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::zip_with_m;
+    ///
+    /// let prices = vec![10, 20, 30];
+    /// let quantities = vec![2, 0, 1];
+    /// let totals = zip_with_m(prices, quantities, |p: u32, q: u32| p.checked_mul(q));
+    /// assert_eq!(totals, Some(vec![20, 0, 30]));
+    /// ```
+    #[cfg(not(feature = "no_std"))]
+    pub fn zip_with_m<A, B, C, M, F>(va: Vec<A>, vb: Vec<B>, mut f: F) -> Apply1<M::Kind1, Vec<C>>
+    where
+        M: ShortCircuit<C>,
+        Apply1<M::Kind1, Vec<C>>: ShortCircuit<Vec<C>, Kind1 = M::Kind1, Residual = M::Residual>,
+        F: FnMut(A, B) -> M,
+    {
+        let mut out = Vec::with_capacity(va.len().min(vb.len()));
+        for (a, b) in va.into_iter().zip(vb) {
+            match f(a, b).branch() {
+                ControlFlow::Continue(c) => out.push(c),
+                ControlFlow::Break(residual) => {
+                    return <Apply1<M::Kind1, Vec<C>>>::from_residual(residual);
+                }
+            }
+        }
+        <Apply1<M::Kind1, Vec<C>>>::pure(out)
+    }
+
+    #[cfg(test)]
+    #[cfg(not(feature = "no_std"))]
+    mod zip_with_m_tests {
+        use super::*;
+
+        fn checked_div(a: i32, b: i32) -> Result<i32, String> {
+            if b == 0 {
+                Err(format!("{a} / 0"))
+            } else {
+                Ok(a / b)
+            }
+        }
+
+        #[test]
+        fn result_all_ok() {
+            let result = zip_with_m(vec![10, 20, 30], vec![2, 4, 5], checked_div);
+            assert_eq!(result, Ok(vec![5, 5, 6]));
+        }
+
+        #[test]
+        fn result_fails_mid_zip() {
+            let mut calls = 0;
+            let result = zip_with_m(vec![10, 20, 30], vec![2, 0, 5], |a, b| {
+                calls += 1;
+                checked_div(a, b)
+            });
+            assert_eq!(result, Err("20 / 0".to_string()));
+            assert_eq!(calls, 2);
+        }
+
+        #[test]
+        fn option_effects() {
+            let lookup = |k: &str, v: i32| (!k.is_empty()).then_some((k.len(), v));
+            assert_eq!(
+                zip_with_m(vec!["a", "bb"], vec![1, 2], lookup),
+                Some(vec![(1, 1), (2, 2)])
+            );
+            assert_eq!(zip_with_m(vec!["a", ""], vec![1, 2], lookup), None);
+        }
+
+        #[test]
+        fn truncates_to_shorter() {
+            let result = zip_with_m(vec![1, 2, 3, 4], vec![10, 20], |a, b| Some(a + b));
+            assert_eq!(result, Some(vec![11, 22]));
+
+            let result = zip_with_m(vec![1], vec![10, 20, 30], |a, b| Ok::<_, ()>(a * b));
+            assert_eq!(result, Ok(vec![10]));
+        }
+
+        #[test]
+        fn empty() {
+            let result = zip_with_m(Vec::<i32>::new(), vec![1, 2], |a, b| Some(a + b));
+            assert_eq!(result, Some(vec![]));
+        }
+
+        #[test]
+        fn moves_elements_without_clone() {
+            struct Amount(i32);
+            let result = zip_with_m(
+                vec![Amount(1), Amount(2)],
+                vec![Amount(10), Amount(20)],
+                |a, b| Ok::<_, ()>(Amount(a.0 + b.0)),
+            );
+            assert_eq!(
+                result.map(|cs| cs.into_iter().map(|c| c.0).collect::<Vec<_>>()),
+                Ok(vec![11, 22])
+            );
+        }
+
+        #[test]
+        fn long_zip_does_not_grow_stack() {
+            let n = 1_000_000;
+            let result = zip_with_m(vec![1u8; n], vec![2u8; n], |a, b| Some(a + b));
+            assert_eq!(result.map(|v| v.len()), Some(n));
+        }
+    }
+
+    /// Applies a monadic step repeatedly until the value stops changing.
+    ///
+    /// Each step's result is compared with its input, and the first value the
+    /// step maps to itself is returned. With `Option` or `Result` the search
+    /// stops at the first failure.
+    ///
+    /// `step` is called at most `max_iters` times, which guards against steps
+    /// that never converge. If the cap is reached before a fixed point is
+    /// found, the last value computed is returned as an error, so it cannot
+    /// be mistaken for a fixed point. The steps run in a plain loop, so slow
+    /// convergence does not grow the stack.
+    ///
+    /// # Type Parameters
+    /// * `A` - The type of the value being iterated
+    /// * `M` - The monad the step runs in
+    /// * `F` - The type of the step function
+    ///
+    /// # Returns
+    /// The fixed point or the first failure in the monadic context, or
+    /// `Err` with the value reached after `max_iters` steps.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::iterate_until;
+    ///
+    /// let fixed = iterate_until(100, 50, |x: u32| Some(x / 2 + 1));
+    /// assert_eq!(fixed, Ok(Some(2)));
+    ///
+    /// let oscillating = iterate_until(0, 10, |x: i32| Some(1 - x));
+    /// assert_eq!(oscillating, Err(0));
+    /// ```
+    pub fn iterate_until<A, M, F>(
+        init: A,
+        max_iters: usize,
+        mut step: F,
+    ) -> Result<Apply1<M::Kind1, A>, A>
+    where
+        A: PartialEq + Clone,
+        M: ShortCircuit<A>,
+        F: FnMut(A) -> M,
+    {
+        let mut a = init;
+        for _ in 0..max_iters {
+            match step(a.clone()).branch() {
+                ControlFlow::Continue(next) if next == a => return Ok(M::pure(a)),
+                ControlFlow::Continue(next) => a = next,
+                ControlFlow::Break(residual) => return Ok(M::from_residual(residual)),
+            }
+        }
+        Err(a)
+    }
+
+    #[cfg(test)]
+    mod iterate_until_tests {
+        use super::*;
+
+        #[test]
+        fn option_reaches_fixed_point() {
+            let mut calls = 0;
+            let fixed = iterate_until(100, 50, |x: u32| {
+                calls += 1;
+                Some(x / 2 + 1)
+            });
+            assert_eq!(fixed, Ok(Some(2)));
+            // 51, 26, 14, 8, 5, 3, 2, then 2 again to confirm
+            assert_eq!(calls, 8);
+        }
+
+        #[test]
+        fn init_already_fixed() {
+            let mut calls = 0;
+            let fixed = iterate_until(2, 50, |x: u32| {
+                calls += 1;
+                Some(x / 2 + 1)
+            });
+            assert_eq!(fixed, Ok(Some(2)));
+            assert_eq!(calls, 1);
+        }
+
+        #[test]
+        fn option_failure_short_circuits() {
+            let fixed = iterate_until(100u8, 50, |x| x.checked_add(x / 2));
+            assert_eq!(fixed, Ok(None));
+        }
+
+        #[test]
+        fn max_iteration_cap_reports_no_convergence() {
+            let mut steps = 0;
+            // Oscillates between 0 and 1, so never reaches a fixed point
+            let last = iterate_until(0, 11, |x: i32| {
+                steps += 1;
+                Some(1 - x)
+            });
+            assert_eq!(last, Err(1));
+            assert_eq!(steps, 11);
+        }
+
+        #[test]
+        fn zero_cap_reports_init() {
+            let result = iterate_until(7, 0, |_: i32| -> Option<i32> { panic!("stepped") });
+            assert_eq!(result, Err(7));
+        }
+
+        #[test]
+        fn slow_convergence_does_not_grow_stack() {
+            let fixed = iterate_until(0u32, 2_000_000, |x| Ok::<_, &str>((x + 1).min(1_000_000)));
+            assert_eq!(fixed, Ok(Ok(1_000_000)));
+        }
+
+        #[test]
+        fn result() {
+            let step = |x: i32| if x > 0 { Ok(x - 1) } else { Ok::<_, &str>(x) };
+            assert_eq!(iterate_until(5, 50, step), Ok(Ok(0)));
+
+            let step = |x: i32| if x > 2 { Ok(x - 1) } else { Err("too small") };
+            assert_eq!(iterate_until(5, 50, step), Ok(Err("too small")));
+        }
+    }
+
+    /// Binds the same monadic step `n` times, starting from `start`.
+    ///
+    /// `chain_n(m, 3, f)` is `m.bind(f).bind(f).bind(f)`, which simulates `n`
+    /// steps of a process. With `Option` or `Result` the chain stops at the
+    /// first failure, and with `Vec` every branch is stepped, so the results
+    /// grow like a tree.
+    ///
+    /// # Type Parameters
+    /// * `A` - The type of the value being stepped
+    /// * `M` - The monad the step runs in
+    /// * `F` - The type of the step function
+    ///
+    /// # Returns
+    /// The value after `n` steps in the monadic context, or `start` itself
+    /// when `n` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::chain_n;
+    ///
+    /// let doubled = chain_n(Some(1u8), 3, |x| x.checked_mul(2));
+    /// assert_eq!(doubled, Some(8));
+    /// ```
+    pub fn chain_n<A, M, F>(start: M, n: usize, f: F) -> M
+    where
+        M: Monad<A>,
+        F: Fn(A) -> M,
+    {
+        let mut m = start;
+        for _ in 0..n {
+            m = m.bind::<A, _>(&f);
+        }
+        m
+    }
+
+    #[cfg(test)]
+    mod chain_n_tests {
+        use super::*;
+
+        #[test]
+        fn option_steps() {
+            assert_eq!(chain_n(Some(3), 4, |x: i32| Some(x + 2)), Some(11));
+        }
+
+        #[test]
+        fn option_early_none_stops_chain() {
+            let calls = ::core::cell::Cell::new(0);
+            let result = chain_n(Some(100u8), 5, |x| {
+                calls.set(calls.get() + 1);
+                x.checked_add(100)
+            });
+            assert_eq!(result, None);
+            assert_eq!(calls.get(), 2);
+        }
+
+        #[test]
+        fn zero_returns_start() {
+            assert_eq!(chain_n(Some(3), 0, |_: i32| None), Some(3));
+            assert_eq!(chain_n(None, 0, |x: i32| Some(x)), None);
+        }
+
+        #[test]
+        fn result_steps() {
+            let step = |x: i32| if x < 10 { Ok(x * 3) } else { Err(x) };
+            assert_eq!(chain_n(Ok(1), 2, step), Ok(9));
+            assert_eq!(chain_n(Ok(1), 4, step), Err(27));
+        }
+
+        #[test]
+        #[cfg(not(feature = "no_std"))]
+        fn vec_branches() {
+            let grown = chain_n(vec![1], 3, |x| vec![x * 2, x * 2 + 1]);
+            assert_eq!(grown, (8..16).collect::<Vec<_>>());
+        }
+
+        #[test]
+        #[cfg(not(feature = "no_std"))]
+        fn vec_zero_returns_start() {
+            assert_eq!(chain_n(vec![1, 2], 0, |x| vec![x, x]), vec![1, 2]);
+        }
+    }
+
+    /// Computes the n-ary cartesian product of a vector of vectors.
+    ///
+    /// Each output vector picks one element from every input vector, in
+    /// input order, and the outputs are ordered with the last input varying
+    /// fastest. This generalises the two-way product of `Vec::apply` to any
+    /// number of inputs, and is built on the `Vec` monad's `bind`.
+    ///
+    /// An empty input vector leaves nothing to pick, so the product is empty;
+    /// no inputs at all give a single empty combination.
+    ///
+    /// # Type Parameters
+    /// * `A` - The type of the elements
+    ///
+    /// # Returns
+    /// Every combination of one element per input vector.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::cartesian;
+    ///
+    /// let sizes = cartesian(vec![vec!['S', 'M'], vec!['r', 'b']]);
+    /// assert_eq!(sizes, vec![vec!['S', 'r'], vec!['S', 'b'], vec!['M', 'r'], vec!['M', 'b']]);
+    /// ```
+    #[cfg(not(feature = "no_std"))]
+    pub fn cartesian<A: Clone>(vs: Vec<Vec<A>>) -> Vec<Vec<A>> {
+        vs.into_iter().fold(vec![vec![]], |combos, v| {
+            combos.bind(|prefix: Vec<A>| {
+                v.clone().fmap(|a| {
+                    let mut combo = prefix.clone();
+                    combo.push(a);
+                    combo
+                })
+            })
+        })
+    }
+
+    #[cfg(test)]
+    #[cfg(not(feature = "no_std"))]
+    mod cartesian_tests {
+        use super::*;
+
+        #[test]
+        fn two_inputs_match_apply() {
+            let product = cartesian(vec![vec![1, 2], vec![10, 20, 30]]);
+            assert_eq!(
+                product,
+                vec![
+                    vec![1, 10],
+                    vec![1, 20],
+                    vec![1, 30],
+                    vec![2, 10],
+                    vec![2, 20],
+                    vec![2, 30]
+                ]
+            );
+            let pairs = vec![10, 20, 30].apply(vec![1, 2].fmap(|a| move |b| vec![a, b]));
+            assert_eq!(product, pairs);
+        }
+
+        #[test]
+        fn three_inputs() {
+            let product = cartesian(vec![vec!['a', 'b'], vec!['x'], vec!['0', '1']]);
+            assert_eq!(
+                product,
+                vec![
+                    vec!['a', 'x', '0'],
+                    vec!['a', 'x', '1'],
+                    vec!['b', 'x', '0'],
+                    vec!['b', 'x', '1']
+                ]
+            );
+        }
+
+        #[test]
+        fn empty_inner_collapses() {
+            assert_eq!(
+                cartesian(vec![vec![1, 2], vec![], vec![3]]),
+                Vec::<Vec<i32>>::new()
+            );
+        }
+
+        #[test]
+        fn empty_outer_is_single_empty_combination() {
+            assert_eq!(cartesian(Vec::<Vec<i32>>::new()), vec![vec![]]);
+        }
+    }
+
+    /// Acquires a resource, uses it fallibly, and always releases it.
+    ///
+    /// `acquire` runs first; if it fails there is nothing to clean up and its
+    /// error is returned as is. Otherwise the resource is handed to `use_`,
+    /// and `release` runs afterwards whether `use_` succeeded, failed or
+    /// panicked. The result is that of `use_`.
+    ///
+    /// # Type Parameters
+    /// * `R` - The type of the resource
+    /// * `A` - The type of the value produced by using the resource
+    /// * `E` - The error type shared by acquisition and use
+    ///
+    /// # Returns
+    /// The result of `acquire` bound to `use_`, after `release` has run.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::bracket;
+    /// use core::cell::Cell;
+    ///
+    /// let open = Cell::new(false);
+    /// let read: Result<u8, &str> = bracket(
+    ///     || {
+    ///         open.set(true);
+    ///         Ok([1u8, 2, 3])
+    ///     },
+    ///     |bytes| bytes.first().copied().ok_or("empty"),
+    ///     |_| open.set(false),
+    /// );
+    /// assert_eq!(read, Ok(1));
+    /// assert!(!open.get());
+    /// ```
+    pub fn bracket<R, A, E, Acq, Use, Rel>(acquire: Acq, use_: Use, release: Rel) -> Result<A, E>
+    where
+        Acq: FnOnce() -> Result<R, E>,
+        Use: FnOnce(&mut R) -> Result<A, E>,
+        Rel: FnOnce(R),
+    {
+        struct Release<R, Rel: FnOnce(R)>(Option<(R, Rel)>);
+
+        impl<R, Rel: FnOnce(R)> Drop for Release<R, Rel> {
+            fn drop(&mut self) {
+                if let Some((resource, release)) = self.0.take() {
+                    release(resource);
+                }
+            }
+        }
+
+        acquire().and_then(|resource| {
+            let mut guard = Release(Some((resource, release)));
+            let (resource, _) = guard.0.as_mut().expect("released before use");
+            use_(resource)
+        })
+    }
+
+    #[cfg(test)]
+    mod bracket_tests {
+        use super::*;
+        use ::core::cell::Cell;
+
+        #[test]
+        fn releases_after_success() {
+            let released = Cell::new(false);
+            let result: Result<i32, &str> = bracket(
+                || Ok(20),
+                |r| {
+                    assert!(!released.get());
+                    Ok(add_one(*r))
+                },
+                |r| {
+                    assert_eq!(r, 20);
+                    released.set(true);
+                },
+            );
+            assert_eq!(result, Ok(21));
+            assert!(released.get());
+        }
+
+        #[test]
+        fn releases_after_error() {
+            let released = Cell::new(false);
+            let result: Result<i32, &str> =
+                bracket(|| Ok(20), |_| Err("use failed"), |_| released.set(true));
+            assert_eq!(result, Err("use failed"));
+            assert!(released.get());
+        }
+
+        #[test]
+        fn release_sees_changes_made_by_use() {
+            let last = Cell::new(0);
+            let result: Result<(), &str> = bracket(
+                || Ok(1),
+                |r| {
+                    *r = multiply_by_two(*r);
+                    Ok(())
+                },
+                |r| last.set(r),
+            );
+            assert_eq!(result, Ok(()));
+            assert_eq!(last.get(), 2);
+        }
+
+        #[test]
+        fn failed_acquire_skips_use_and_release() {
+            let result: Result<i32, &str> = bracket(
+                || Err("acquire failed"),
+                |_: &mut i32| panic!("used without a resource"),
+                |_| panic!("released without a resource"),
+            );
+            assert_eq!(result, Err("acquire failed"));
+        }
+
+        #[test]
+        #[cfg(not(feature = "no_std"))]
+        fn releases_after_panic() {
+            let released = Cell::new(false);
+            let outcome = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                bracket::<i32, i32, &str, _, _, _>(
+                    || Ok(1),
+                    |_| panic!("use panicked"),
+                    |_| released.set(true),
+                )
+            }));
+            assert!(outcome.is_err());
+            assert!(released.get());
+        }
+    }
+
+    /// Traverses a vector with a fallible function that also receives each
+    /// element's index.
+    ///
+    /// Elements are visited in order and the traversal stops at the first
+    /// error, so the index is available for error messages that need to point
+    /// at the offending row.
+    ///
+    /// # Type Parameters
+    /// * `A` - The type of the input elements
+    /// * `B` - The type of the output elements
+    /// * `E` - The type of the error
+    /// * `F` - The type of the function, called with the index and the element
+    ///
+    /// # Returns
+    /// `Ok` with every result in order, or the first error.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::traverse_indexed;
+    ///
+    /// let rows = vec!["1", "x", "3"];
+    /// let parsed = traverse_indexed(rows, |i, s| s.parse::<i32>().map_err(|_| format!("row {i}")));
+    /// assert_eq!(parsed, Err("row 1".to_string()));
+    /// ```
+    #[cfg(not(feature = "no_std"))]
+    pub fn traverse_indexed<A, B, E, F>(v: Vec<A>, mut f: F) -> Result<Vec<B>, E>
+    where
+        F: FnMut(usize, A) -> Result<B, E>,
+    {
+        v.into_iter().enumerate().map(|(i, a)| f(i, a)).collect()
+    }
+
+    #[cfg(test)]
+    #[cfg(not(feature = "no_std"))]
+    mod traverse_indexed_tests {
+        use super::*;
+
+        #[test]
+        fn passes_indices_in_order() {
+            let result = traverse_indexed(vec!['a', 'b', 'c'], |i, c| Ok::<_, ()>((i, c)));
+            assert_eq!(result, Ok(vec![(0, 'a'), (1, 'b'), (2, 'c')]));
+        }
+
+        #[test]
+        fn error_carries_failing_index() {
+            let mut visited = vec![];
+            let result = traverse_indexed(vec![1, -2, 3, -4], |i, x| {
+                visited.push(i);
+                if x < 0 { Err(i) } else { Ok(x * 10) }
+            });
+            assert_eq!(result, Err(1));
+            assert_eq!(visited, vec![0, 1]);
+        }
+
+        #[test]
+        fn empty() {
+            let result = traverse_indexed(Vec::<i32>::new(), |_, x| Ok::<_, ()>(x));
+            assert_eq!(result, Ok(vec![]));
+        }
+    }
+
+    /// Traverses a vector with a fallible function, reporting which input
+    /// failed.
+    ///
+    /// Elements are visited in order and the traversal stops at the first
+    /// error, which is returned together with the failing element's index and
+    /// a copy of the element itself. Each element is cloned before it is
+    /// handed to `f`, so the original is still available for the report.
+    ///
+    /// # Type Parameters
+    /// * `A` - The type of the input elements
+    /// * `B` - The type of the output elements
+    /// * `E` - The type of the error
+    /// * `F` - The type of the fallible function
+    ///
+    /// # Returns
+    /// `Ok` with every result in order, or the index, input and error of the
+    /// first failure.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::traverse_result_with_context;
+    ///
+    /// let rows = vec!["1", "two", "3"];
+    /// let parsed = traverse_result_with_context(rows, |s| s.parse::<i32>().map_err(|e| e.to_string()));
+    /// assert_eq!(parsed, Err((1, "two", "invalid digit found in string".to_string())));
+    /// ```
+    #[cfg(not(feature = "no_std"))]
+    pub fn traverse_result_with_context<A, B, E, F>(
+        v: Vec<A>,
+        mut f: F,
+    ) -> Result<Vec<B>, (usize, A, E)>
+    where
+        A: Clone,
+        F: FnMut(A) -> Result<B, E>,
+    {
+        let mut out = Vec::with_capacity(v.len());
+        for (i, a) in v.into_iter().enumerate() {
+            match f(a.clone()) {
+                Ok(b) => out.push(b),
+                Err(e) => return Err((i, a, e)),
+            }
+        }
+        Ok(out)
+    }
+
+    #[cfg(test)]
+    #[cfg(not(feature = "no_std"))]
+    mod traverse_result_with_context_tests {
+        use super::*;
+
+        #[derive(Debug, Clone, PartialEq)]
+        struct Row {
+            id: u32,
+            amount: &'static str,
+        }
+
+        fn parse_amount(row: Row) -> Result<i64, String> {
+            row.amount
+                .parse()
+                .map_err(|_| format!("bad amount {:?}", row.amount))
+        }
+
+        #[test]
+        fn reports_failing_index_and_value() {
+            let rows = vec![
+                Row {
+                    id: 7,
+                    amount: "10",
+                },
+                Row {
+                    id: 8,
+                    amount: "20",
+                },
+                Row {
+                    id: 9,
+                    amount: "1O",
+                },
+                Row {
+                    id: 10,
+                    amount: "x",
+                },
+            ];
+            let result = traverse_result_with_context(rows, parse_amount);
+            assert_eq!(
+                result,
+                Err((
+                    2,
+                    Row {
+                        id: 9,
+                        amount: "1O"
+                    },
+                    "bad amount \"1O\"".to_string()
+                ))
+            );
+        }
+
+        #[test]
+        fn stops_at_first_failure() {
+            let mut visited = vec![];
+            let result = traverse_result_with_context(vec![1, -2, -3], |x| {
+                visited.push(x);
+                if x < 0 { Err("negative") } else { Ok(x) }
+            });
+            assert_eq!(result, Err((1, -2, "negative")));
+            assert_eq!(visited, vec![1, -2]);
+        }
+
+        #[test]
+        fn all_succeed() {
+            let rows = vec![
+                Row { id: 1, amount: "5" },
+                Row {
+                    id: 2,
+                    amount: "-3",
+                },
+            ];
+            assert_eq!(
+                traverse_result_with_context(rows, parse_amount),
+                Ok(vec![5, -3])
+            );
+        }
+
+        #[test]
+        fn empty() {
+            let result = traverse_result_with_context(Vec::<i32>::new(), Ok::<_, ()>);
+            assert_eq!(result, Ok(vec![]));
+        }
+    }
+
+    /// Sequences a vector of results, reporting where the first error was.
+    ///
+    /// Like collecting into `Result<Vec<A>, E>`, this stops at the first
+    /// `Err`, but the error is paired with the index of the element that
+    /// produced it.
+    ///
+    /// # Type Parameters
+    /// * `A` - The type of the successful values
+    /// * `E` - The type of the error
+    ///
+    /// # Returns
+    /// `Ok` with every value in order, or the index and value of the first
+    /// error.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::sequence_located;
+    ///
+    /// let results = vec![Ok(1), Err("bad"), Ok(3)];
+    /// assert_eq!(sequence_located(results), Err((1, "bad")));
+    /// ```
+    #[cfg(not(feature = "no_std"))]
+    pub fn sequence_located<A, E>(v: Vec<Result<A, E>>) -> Result<Vec<A>, (usize, E)> {
+        traverse_indexed(v, |i, r| r.map_err(|e| (i, e)))
+    }
+
+    #[cfg(test)]
+    #[cfg(not(feature = "no_std"))]
+    mod sequence_located_tests {
+        use super::*;
+
+        #[test]
+        fn all_ok() {
+            let results: Vec<Result<i32, &str>> = vec![Ok(1), Ok(2), Ok(3)];
+            assert_eq!(sequence_located(results), Ok(vec![1, 2, 3]));
+        }
+
+        #[test]
+        fn reports_middle_error_index() {
+            let results = vec![Ok(1), Ok(2), Err("bad"), Ok(4), Err("worse")];
+            assert_eq!(sequence_located(results), Err((2, "bad")));
+        }
+
+        #[test]
+        fn empty() {
+            let results: Vec<Result<i32, &str>> = vec![];
+            assert_eq!(sequence_located(results), Ok(vec![]));
+        }
+    }
+
+    /// Extracts the value from a `Result` whose `Ok` and `Err` types match.
+    ///
+    /// This is a common endpoint after `bimap` has normalised both channels
+    /// to the same type.
+    ///
+    /// # Type Parameters
+    /// * `T` - The type carried by both channels
+    ///
+    /// # Returns
+    /// The inner value, whichever channel it was in.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::*;
+    ///
+    /// let parsed: Result<i32, &str> = "x".parse::<i32>().map_err(|_| "x");
+    /// let message = collapse(parsed.bimap(|n| n.to_string(), |s| format!("bad: {s}")));
+    /// assert_eq!(message, "bad: x");
+    /// ```
+    pub fn collapse<T>(r: Result<T, T>) -> T {
+        match r {
+            Ok(t) | Err(t) => t,
+        }
+    }
+
+    #[cfg(test)]
+    mod collapse_tests {
+        use super::*;
+
+        #[test]
+        fn ok() {
+            assert_eq!(collapse(Ok::<_, i32>(1)), 1);
+        }
+
+        #[test]
+        fn err() {
+            assert_eq!(collapse(Err::<i32, _>(2)), 2);
+        }
+    }
+
+    /// Checks that every value in a vector is `true`.
+    ///
+    /// Folds the vector with the `All` monoid, so an empty vector yields
+    /// `true`.
+    ///
+    /// # Returns
+    /// `true` if no value is `false`.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::all_of;
+    ///
+    /// assert!(all_of(vec![true, true]));
+    /// assert!(!all_of(vec![true, false]));
+    /// ```
+    #[cfg(not(feature = "no_std"))]
+    pub fn all_of(v: Vec<bool>) -> bool {
+        v.fold_map(crate::monoid::monoid_impls::All).0
+    }
+
+    /// Checks that at least one value in a vector is `true`.
+    ///
+    /// Folds the vector with the `Any` monoid, so an empty vector yields
+    /// `false`.
+    ///
+    /// # Returns
+    /// `true` if some value is `true`.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::any_of;
+    ///
+    /// assert!(any_of(vec![false, true]));
+    /// assert!(!any_of(vec![false, false]));
+    /// ```
+    #[cfg(not(feature = "no_std"))]
+    pub fn any_of(v: Vec<bool>) -> bool {
+        v.fold_map(crate::monoid::monoid_impls::Any).0
+    }
+
+    #[cfg(test)]
+    #[cfg(not(feature = "no_std"))]
+    mod all_of_any_of_tests {
+        use super::*;
+
+        #[test]
+        fn all_of_mixed() {
+            assert!(all_of(vec![true, true, true]));
+            assert!(!all_of(vec![true, false, true]));
+            assert!(!all_of(vec![false, false]));
+        }
+
+        #[test]
+        fn any_of_mixed() {
+            assert!(any_of(vec![false, true, false]));
+            assert!(any_of(vec![true, true]));
+            assert!(!any_of(vec![false, false, false]));
+        }
+
+        #[test]
+        fn empty_is_identity() {
+            assert!(all_of(vec![]));
+            assert!(!any_of(vec![]));
+        }
+    }
+
+    /// Traverses a vector with a fallible function, reporting progress as it
+    /// goes.
+    ///
+    /// After each element is transformed successfully, `on_progress` is called
+    /// with the number of elements done so far and the total. The traversal
+    /// stops at the first error without reporting progress for it.
+    ///
+    /// # Type Parameters
+    /// * `A` - The type of the input elements
+    /// * `B` - The type of the output elements
+    /// * `E` - The type of the error
+    /// * `F` - The type of the fallible function
+    /// * `P` - The type of the progress callback, called with `(done, total)`
+    ///
+    /// # Returns
+    /// `Ok` with every result in order, or the first error.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::traverse_with_progress;
+    ///
+    /// let mut reports = vec![];
+    /// let parsed = traverse_with_progress(
+    ///     vec!["1", "2"],
+    ///     |s| s.parse::<i32>(),
+    ///     |done, total| reports.push(format!("{done}/{total}")),
+    /// );
+    /// assert_eq!(parsed, Ok(vec![1, 2]));
+    /// assert_eq!(reports, vec!["1/2", "2/2"]);
+    /// ```
+    #[cfg(not(feature = "no_std"))]
+    pub fn traverse_with_progress<A, B, E, F, P>(
+        v: Vec<A>,
+        mut f: F,
+        mut on_progress: P,
+    ) -> Result<Vec<B>, E>
+    where
+        F: FnMut(A) -> Result<B, E>,
+        P: FnMut(usize, usize),
+    {
+        let total = v.len();
+        let mut out = Vec::with_capacity(total);
+        for a in v {
+            out.push(f(a)?);
+            on_progress(out.len(), total);
+        }
+        Ok(out)
+    }
+
+    #[cfg(test)]
+    #[cfg(not(feature = "no_std"))]
+    mod traverse_with_progress_tests {
+        use super::*;
+
+        #[test]
+        fn reports_every_element() {
+            let mut reports = vec![];
+            let result = traverse_with_progress(
+                vec![1, 2, 3],
+                |x| Ok::<_, ()>(x * 2),
+                |done, total| reports.push((done, total)),
+            );
+            assert_eq!(result, Ok(vec![2, 4, 6]));
+            assert_eq!(reports, vec![(1, 3), (2, 3), (3, 3)]);
+        }
+
+        #[test]
+        fn stops_reporting_on_error() {
+            let mut reports = vec![];
+            let result = traverse_with_progress(
+                vec![1, -2, 3, 4],
+                |x| if x < 0 { Err("negative") } else { Ok(x) },
+                |done, total| reports.push((done, total)),
+            );
+            assert_eq!(result, Err("negative"));
+            assert_eq!(reports, vec![(1, 4)]);
+        }
+
+        #[test]
+        fn empty_never_reports() {
+            let mut calls = 0;
+            let result = traverse_with_progress(Vec::<i32>::new(), Ok::<_, ()>, |_, _| calls += 1);
+            assert_eq!(result, Ok(vec![]));
+            assert_eq!(calls, 0);
+        }
+    }
+
+    /// The error returned by `traverse_cancellable` when its flag is set.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Cancelled;
+
+    /// Traverses a vector with a fallible function, stopping early if a
+    /// cancellation flag is set.
+    ///
+    /// The flag is checked before each element, so a traversal cancelled from
+    /// another thread, or from inside `f`, stops before the next element runs.
+    /// Cancellation is reported as `Cancelled` converted into `E`; the results
+    /// gathered so far are dropped.
+    ///
+    /// # Type Parameters
+    /// * `A` - The type of the input elements
+    /// * `B` - The type of the output elements
+    /// * `E` - The type of the error, which must be able to represent
+    ///   `Cancelled`
+    /// * `F` - The type of the fallible function
+    ///
+    /// # Returns
+    /// `Ok` with every result in order, the first error, or `Cancelled` if the
+    /// flag was set before the traversal finished.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::{traverse_cancellable, Cancelled};
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    ///
+    /// let cancel = AtomicBool::new(false);
+    /// let doubled = traverse_cancellable(vec![1, 2, 3], &cancel, |x| Ok::<_, Cancelled>(x * 2));
+    /// assert_eq!(doubled, Ok(vec![2, 4, 6]));
+    ///
+    /// cancel.store(true, Ordering::Relaxed);
+    /// let doubled = traverse_cancellable(vec![1, 2, 3], &cancel, |x| Ok::<_, Cancelled>(x * 2));
+    /// assert_eq!(doubled, Err(Cancelled));
+    /// ```
+    #[cfg(not(feature = "no_std"))]
+    pub fn traverse_cancellable<A, B, E, F>(
+        v: Vec<A>,
+        cancel: &std::sync::atomic::AtomicBool,
+        mut f: F,
+    ) -> Result<Vec<B>, E>
+    where
+        E: From<Cancelled>,
+        F: FnMut(A) -> Result<B, E>,
+    {
+        let mut out = Vec::with_capacity(v.len());
+        for a in v {
+            if cancel.load(std::sync::atomic::Ordering::Relaxed) {
+                return Err(Cancelled.into());
+            }
+            out.push(f(a)?);
+        }
+        Ok(out)
+    }
+
+    #[cfg(test)]
+    #[cfg(not(feature = "no_std"))]
+    mod traverse_cancellable_tests {
+        use super::*;
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        #[derive(Debug, PartialEq)]
+        enum Error {
+            Negative(i32),
+            Cancelled,
+        }
+
+        impl From<Cancelled> for Error {
+            fn from(_: Cancelled) -> Self {
+                Error::Cancelled
+            }
+        }
+
+        #[test]
+        fn runs_to_completion() {
+            let cancel = AtomicBool::new(false);
+            let result = traverse_cancellable(vec![1, 2, 3], &cancel, |x| Ok::<_, Error>(x + 1));
+            assert_eq!(result, Ok(vec![2, 3, 4]));
+        }
+
+        #[test]
+        fn stops_after_flag_set_mid_traversal() {
+            let cancel = AtomicBool::new(false);
+            let mut visited = vec![];
+            let result = traverse_cancellable(vec![1, 2, 3, 4, 5], &cancel, |x| {
+                visited.push(x);
+                if x == 2 {
+                    cancel.store(true, Ordering::Relaxed);
+                }
+                Ok::<_, Error>(x)
+            });
+            assert_eq!(result, Err(Error::Cancelled));
+            assert_eq!(visited, vec![1, 2]);
+        }
+
+        #[test]
+        fn already_cancelled_runs_nothing() {
+            let cancel = AtomicBool::new(true);
+            let mut calls = 0;
+            let result = traverse_cancellable(vec![1, 2], &cancel, |x| {
+                calls += 1;
+                Ok::<_, Cancelled>(x)
+            });
+            assert_eq!(result, Err(Cancelled));
+            assert_eq!(calls, 0);
+        }
+
+        #[test]
+        fn error_before_cancellation_wins() {
+            let cancel = AtomicBool::new(false);
+            let result = traverse_cancellable(vec![1, -2, 3], &cancel, |x| {
+                cancel.store(x < 0, Ordering::Relaxed);
+                if x < 0 {
+                    Err(Error::Negative(x))
+                } else {
+                    Ok(x)
+                }
+            });
+            assert_eq!(result, Err(Error::Negative(-2)));
+        }
+
+        #[test]
+        fn empty() {
+            let cancel = AtomicBool::new(true);
+            let result = traverse_cancellable(Vec::<i32>::new(), &cancel, Ok::<_, Cancelled>);
+            assert_eq!(result, Ok(vec![]));
+        }
+    }
+
+    /// Traverses a vector with a fallible function, running it in parallel.
+    ///
+    /// Elements are transformed on the rayon thread pool and the results keep
+    /// the input order. If any element fails, one of the errors is returned;
+    /// when several fail, which one is unspecified.
+    ///
+    /// # Type Parameters
+    /// * `A` - The type of the input elements
+    /// * `B` - The type of the output elements
+    /// * `E` - The type of the error
+    /// * `F` - The type of the fallible function
+    ///
+    /// # Returns
+    /// `Ok` with every result in order, or an error from a failing element.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::par_traverse;
+    ///
+    /// let parsed = par_traverse(vec!["1", "2", "3"], |s| s.parse::<i32>());
+    /// assert_eq!(parsed, Ok(vec![1, 2, 3]));
+    /// ```
+    #[cfg(all(feature = "rayon", not(feature = "no_std")))]
+    pub fn par_traverse<A, B, E, F>(v: Vec<A>, f: F) -> Result<Vec<B>, E>
+    where
+        A: Send,
+        B: Send,
+        E: Send,
+        F: Fn(A) -> Result<B, E> + Sync + Send,
+    {
+        use rayon::prelude::*;
+
+        v.into_par_iter().map(f).collect()
+    }
+
+    #[cfg(test)]
+    #[cfg(all(feature = "rayon", not(feature = "no_std")))]
+    mod par_traverse_tests {
+        use super::*;
+
+        fn checked_square(x: i64) -> Result<i64, String> {
+            if x < 0 {
+                Err(format!("{x} is negative"))
+            } else {
+                Ok(x * x)
+            }
+        }
+
+        #[test]
+        fn matches_sequential_on_success() {
+            let v: Vec<i64> = (0..10_000).collect();
+            let sequential: Result<Vec<i64>, String> =
+                v.clone().into_iter().map(checked_square).collect();
+            assert_eq!(par_traverse(v, checked_square), sequential);
+        }
+
+        #[test]
+        fn surfaces_an_error() {
+            let mut v: Vec<i64> = (0..10_000).collect();
+            v[5_000] = -1;
+            assert_eq!(
+                par_traverse(v, checked_square),
+                Err("-1 is negative".to_string())
+            );
+        }
+
+        #[test]
+        fn returns_one_of_several_errors() {
+            let v = vec![1, -2, 3, -4];
+            let err = par_traverse(v, checked_square).unwrap_err();
+            assert!(err == "-2 is negative" || err == "-4 is negative");
+        }
+    }
+
+    /// Counts the elements of a vector by a key, producing a histogram.
+    ///
+    /// # Type Parameters
+    /// * `A` - The type of the elements
+    /// * `K` - The type of the key
+    /// * `F` - The type of the key function
+    ///
+    /// # Returns
+    /// A map from each key to the number of elements with that key.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::count_by;
+    ///
+    /// let counts = count_by(vec![1, 2, 3, 4, 5], |x| x % 2 == 0);
+    /// assert_eq!(counts[&true], 2);
+    /// assert_eq!(counts[&false], 3);
+    /// ```
+    #[cfg(not(feature = "no_std"))]
+    pub fn count_by<A, K, F>(v: Vec<A>, mut f: F) -> std::collections::HashMap<K, usize>
+    where
+        K: Eq + std::hash::Hash,
+        F: FnMut(&A) -> K,
+    {
+        let mut counts = std::collections::HashMap::new();
+        for a in &v {
+            *counts.entry(f(a)).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    #[cfg(test)]
+    #[cfg(not(feature = "no_std"))]
+    mod count_by_tests {
+        use super::*;
+        use std::collections::HashMap;
+
+        #[test]
+        fn counts_by_key() {
+            let words = vec!["apple", "bob", "cat", "avocado", "banana", "ant"];
+            let counts = count_by(words, |w| w.chars().next().unwrap());
+            assert_eq!(counts, HashMap::from([('a', 3), ('b', 2), ('c', 1)]));
+        }
+
+        #[test]
+        fn identity_key_counts_occurrences() {
+            let counts = count_by(vec![3, 1, 3, 3, 2], |x| *x);
+            assert_eq!(counts, HashMap::from([(1, 1), (2, 1), (3, 3)]));
+        }
+
+        #[test]
+        fn empty_input() {
+            let counts = count_by(Vec::<i32>::new(), |x| *x);
+            assert!(counts.is_empty());
+        }
+    }
+
+    /// Combines a pair of results of different value types into a result of
+    /// a pair.
+    ///
+    /// The first error, checked left to right, is returned.
+    ///
+    /// # Type Parameters
+    /// * `A` - The value type of the first result
+    /// * `B` - The value type of the second result
+    /// * `E` - The shared error type
+    ///
+    /// # Returns
+    /// `Ok` with both values, or the first error.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::sequence_result_tuple2;
+    ///
+    /// let name: Result<&str, &str> = Ok("Ada");
+    /// let age: Result<u8, &str> = Ok(36);
+    /// assert_eq!(sequence_result_tuple2((name, age)), Ok(("Ada", 36)));
+    /// ```
+    pub fn sequence_result_tuple2<A, B, E>(
+        pair: (Result<A, E>, Result<B, E>),
+    ) -> Result<(A, B), E> {
+        Ok((pair.0?, pair.1?))
+    }
+
+    /// Combines a pair of results of different value types into a result of
+    /// a pair, collecting every error.
+    ///
+    /// Unlike `sequence_result_tuple2`, a failure in the first result does not
+    /// hide a failure in the second: all errors are returned, in order.
+    ///
+    /// # Type Parameters
+    /// * `A` - The value type of the first result
+    /// * `B` - The value type of the second result
+    /// * `E` - The shared error type
+    ///
+    /// # Returns
+    /// `Ok` with both values, or every error that occurred.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::sequence_result_tuple2_accum;
+    ///
+    /// let name: Result<&str, &str> = Err("name is empty");
+    /// let age: Result<u8, &str> = Err("age is negative");
+    /// assert_eq!(
+    ///     sequence_result_tuple2_accum((name, age)),
+    ///     Err(vec!["name is empty", "age is negative"])
+    /// );
+    /// ```
+    #[cfg(not(feature = "no_std"))]
+    pub fn sequence_result_tuple2_accum<A, B, E>(
+        pair: (Result<A, E>, Result<B, E>),
+    ) -> Result<(A, B), Vec<E>> {
+        match pair {
+            (Ok(a), Ok(b)) => Ok((a, b)),
+            (Err(e1), Err(e2)) => Err(vec![e1, e2]),
+            (Err(e), _) | (_, Err(e)) => Err(vec![e]),
+        }
+    }
+
+    #[cfg(test)]
+    mod sequence_result_tuple2_tests {
+        use super::*;
+
+        #[derive(Debug, PartialEq)]
+        struct User<'a> {
+            name: &'a str,
+            age: u8,
+        }
+
+        fn validate_name(name: &str) -> Result<&str, &'static str> {
+            if name.is_empty() {
+                Err("name is empty")
+            } else {
+                Ok(name)
+            }
+        }
+
+        fn validate_age(age: i32) -> Result<u8, &'static str> {
+            u8::try_from(age).map_err(|_| "age is out of range")
+        }
+
+        #[test]
+        fn all_ok_builds_struct() {
+            let user = sequence_result_tuple2((validate_name("Ada"), validate_age(36)))
+                .map(|(name, age)| User { name, age });
+            assert_eq!(
+                user,
+                Ok(User {
+                    name: "Ada",
+                    age: 36
+                })
+            );
+        }
+
+        #[test]
+        fn first_failure_short_circuits() {
+            let result = sequence_result_tuple2((validate_name(""), validate_age(-1)));
+            assert_eq!(result, Err("name is empty"));
+
+            let result = sequence_result_tuple2((validate_name("Ada"), validate_age(-1)));
+            assert_eq!(result, Err("age is out of range"));
+        }
+
+        #[test]
+        #[cfg(not(feature = "no_std"))]
+        fn accum_collects_every_error() {
+            let result = sequence_result_tuple2_accum((validate_name(""), validate_age(-1)));
+            assert_eq!(result, Err(vec!["name is empty", "age is out of range"]));
+
+            let result = sequence_result_tuple2_accum((validate_name(""), validate_age(3)));
+            assert_eq!(result, Err(vec!["name is empty"]));
+        }
+
+        #[test]
+        #[cfg(not(feature = "no_std"))]
+        fn accum_all_ok() {
+            let result = sequence_result_tuple2_accum((validate_name("Ada"), validate_age(36)));
+            assert_eq!(result, Ok(("Ada", 36)));
+        }
+    }
+
+    /// Calls a fallible generator `n` times, collecting the results.
+    ///
+    /// The generator is called in order and never again after its first
+    /// error.
+    ///
+    /// # Type Parameters
+    /// * `A` - The type of the generated values
+    /// * `E` - The type of the error
+    /// * `F` - The type of the generator
+    ///
+    /// # Returns
+    /// `Ok` with `n` values, or the first error.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::replicate_result;
+    ///
+    /// let mut ids = 10..;
+    /// let batch = replicate_result(3, || ids.next().ok_or("exhausted"));
+    /// assert_eq!(batch, Ok(vec![10, 11, 12]));
+    /// ```
+    #[cfg(not(feature = "no_std"))]
+    pub fn replicate_result<A, E, F>(n: usize, mut f: F) -> Result<Vec<A>, E>
+    where
+        F: FnMut() -> Result<A, E>,
+    {
+        (0..n).map(|_| f()).collect()
+    }
 
     #[cfg(test)]
-    mod standalone_ap_tests {
+    #[cfg(not(feature = "no_std"))]
+    mod replicate_result_tests {
         use super::*;
 
         #[test]
-        fn test_ap() {
-            fn add_one(x: i32) -> i32 {
-                x + 1
-            }
+        fn all_success() {
+            let mut next = 0;
+            let result = replicate_result(4, || {
+                next += 1;
+                Ok::<_, ()>(next * next)
+            });
+            assert_eq!(result, Ok(vec![1, 4, 9, 16]));
+        }
 
-            let x = Some(5);
-            let f = Some(add_one);
-            let y = ap(x, f);
-            assert_eq!(y, Some(6));
+        #[test]
+        fn stops_at_first_error() {
+            let mut calls = 0;
+            let result = replicate_result(5, || {
+                calls += 1;
+                if calls == 2 {
+                    Err("second call failed")
+                } else {
+                    Ok(calls)
+                }
+            });
+            assert_eq!(result, Err("second call failed"));
+            assert_eq!(calls, 2);
+        }
 
-            #[cfg(not(feature = "no_std"))]
-            {
-                let x = vec![1, 2, 3];
-                let f = vec![add_one];
-                let y = ap(x, f);
-                assert_eq!(y, vec![2, 3, 4]);
+        #[test]
+        fn zero_times() {
+            let mut calls = 0;
+            let result = replicate_result(0, || {
+                calls += 1;
+                Err::<i32, _>("never called")
+            });
+            assert_eq!(result, Ok(vec![]));
+            assert_eq!(calls, 0);
+        }
+    }
 
-                let x = vec![1, 2, 3];
-                let f = vec![add_one, add_one];
-                let y = ap(x, f);
-                assert_eq!(y, vec![2, 3, 4, 2, 3, 4]);
+    /// Swaps an optional result into a result of an option.
+    ///
+    /// `None` becomes `Ok(None)`, so a missing value is not an error, while a
+    /// present value that failed stays a failure.
+    ///
+    /// # Type Parameters
+    /// * `A` - The type of the value
+    /// * `E` - The type of the error
+    ///
+    /// # Returns
+    /// `Ok(Some(a))` for `Some(Ok(a))`, `Err(e)` for `Some(Err(e))` and
+    /// `Ok(None)` for `None`.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::transpose_option_result;
+    ///
+    /// let port: Option<&str> = Some("8080");
+    /// let parsed = transpose_option_result(port.map(str::parse::<u16>));
+    /// assert_eq!(parsed, Ok(Some(8080)));
+    /// ```
+    pub fn transpose_option_result<A, E>(x: Option<Result<A, E>>) -> Result<Option<A>, E> {
+        x.transpose()
+    }
+
+    /// Swaps a result of an option into an optional result.
+    ///
+    /// The inverse of `transpose_option_result`.
+    ///
+    /// # Type Parameters
+    /// * `A` - The type of the value
+    /// * `E` - The type of the error
+    ///
+    /// # Returns
+    /// `Some(Ok(a))` for `Ok(Some(a))`, `Some(Err(e))` for `Err(e)` and `None`
+    /// for `Ok(None)`.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::transpose_result_option;
+    ///
+    /// let x: Result<Option<i32>, &str> = Ok(None);
+    /// assert_eq!(transpose_result_option(x), None);
+    /// ```
+    pub fn transpose_result_option<A, E>(x: Result<Option<A>, E>) -> Option<Result<A, E>> {
+        x.transpose()
+    }
+
+    #[cfg(test)]
+    mod transpose_option_result_tests {
+        use super::*;
+
+        #[test]
+        fn some_ok() {
+            let x: Option<Result<i32, &str>> = Some(Ok(5));
+            assert_eq!(transpose_option_result(x), Ok(Some(5)));
+        }
+
+        #[test]
+        fn some_err() {
+            let x: Option<Result<i32, &str>> = Some(Err("bad"));
+            assert_eq!(transpose_option_result(x), Err("bad"));
+        }
+
+        #[test]
+        fn none() {
+            let x: Option<Result<i32, &str>> = None;
+            assert_eq!(transpose_option_result(x), Ok(None));
+        }
+
+        #[test]
+        fn inverse_round_trips() {
+            let cases: [Option<Result<i32, &str>>; 3] = [Some(Ok(5)), Some(Err("bad")), None];
+            for x in cases {
+                assert_eq!(transpose_result_option(transpose_option_result(x)), x);
             }
+        }
+    }
 
-            let x: Result<i32, &str> = Ok(5);
-            let f = Ok(add_one);
-            let y = ap(x, f);
-            assert_eq!(y, Ok(6));
+    /// Sequences a vector of results up to the first error, keeping the
+    /// successes that came before it.
+    ///
+    /// Like collecting into `Result<Vec<A>, E>` this stops at the first `Err`,
+    /// but the work already done is returned rather than discarded, so a
+    /// pipeline can commit it before handling the failure.
+    ///
+    /// # Type Parameters
+    /// * `A` - The type of the successful values
+    /// * `E` - The type of the error
+    ///
+    /// # Returns
+    /// The successes before the first error, and that error if there was one.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::sequence_partial;
+    ///
+    /// let batch = vec![Ok(1), Ok(2), Err("disk full"), Ok(4)];
+    /// assert_eq!(sequence_partial(batch), (vec![1, 2], Some("disk full")));
+    /// ```
+    #[cfg(not(feature = "no_std"))]
+    pub fn sequence_partial<A, E>(v: Vec<Result<A, E>>) -> (Vec<A>, Option<E>) {
+        let mut done = Vec::with_capacity(v.len());
+        for r in v {
+            match r {
+                Ok(a) => done.push(a),
+                Err(e) => return (done, Some(e)),
+            }
         }
+        (done, None)
     }
 
     #[cfg(test)]
-    mod standalone_pure_tests {
+    #[cfg(not(feature = "no_std"))]
+    mod sequence_partial_tests {
         use super::*;
 
         #[test]
-        fn test_pure() {
-            let y = pure::<i32, Option<_>>(5);
-            assert_eq!(y, Some(5));
+        fn all_ok() {
+            let v: Vec<Result<i32, &str>> = vec![Ok(1), Ok(2), Ok(3)];
+            assert_eq!(sequence_partial(v), (vec![1, 2, 3], None));
+        }
 
-            #[cfg(not(feature = "no_std"))]
-            {
-                let y = pure::<i32, Vec<_>>(5);
-                assert_eq!(y, vec![5]);
-            }
+        #[test]
+        fn error_in_middle() {
+            let v = vec![Ok(1), Ok(2), Err("second"), Ok(4), Err("third")];
+            assert_eq!(sequence_partial(v), (vec![1, 2], Some("second")));
+        }
 
-            let y = pure::<i32, Result<_, &str>>(5);
-            assert_eq!(y, Ok(5));
+        #[test]
+        fn error_first() {
+            let v = vec![Err("first"), Ok(2)];
+            assert_eq!(sequence_partial(v), (vec![], Some("first")));
+        }
+
+        #[test]
+        fn empty() {
+            let v: Vec<Result<i32, &str>> = vec![];
+            assert_eq!(sequence_partial(v), (vec![], None));
+        }
+    }
+
+    /// Keeps or discards the rest of a monadic computation based on a condition.
+    ///
+    /// Produces `pure(())` when `cond` holds and the empty value otherwise, so
+    /// binding through it continues only while the condition is met. With
+    /// `Vec` this filters the branches of a comprehension; with `Option` it
+    /// turns a failed check into `None`.
+    ///
+    /// # Type Parameters
+    /// * `FA` - The applicative to guard in, such as `Vec<()>` or `Option<()>`
+    ///
+    /// # Returns
+    /// `pure(())` if `cond` is `true`, `Monoid::empty()` otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::*;
+    ///
+    /// let checked = |x: i32| guard::<Option<()>>(x > 0).bind(|()| Some(x));
+    /// assert_eq!(checked(3), Some(3));
+    /// assert_eq!(checked(-3), None);
+    /// ```
+    pub fn guard<FA: Applicative<()>>(cond: bool) -> Apply1<FA::Kind1, ()>
+    where
+        Apply1<FA::Kind1, ()>: Monoid,
+    {
+        if cond { FA::pure(()) } else { Monoid::empty() }
+    }
+
+    #[cfg(test)]
+    mod guard_tests {
+        use super::*;
+
+        #[test]
+        fn option() {
+            assert_eq!(guard::<Option<()>>(true), Some(()));
+            assert_eq!(guard::<Option<()>>(false), None);
+        }
+
+        #[test]
+        #[cfg(not(feature = "no_std"))]
+        fn vec() {
+            assert_eq!(guard::<Vec<()>>(true), vec![()]);
+            assert_eq!(guard::<Vec<()>>(false), vec![]);
+        }
+    }
+
+    /// Runs a monadic action only when a condition holds.
+    ///
+    /// The action is passed as a thunk, so an effectful computation is not
+    /// even built when `cond` is `false`; `pure(())` is returned instead.
+    ///
+    /// # Type Parameters
+    /// * `M` - The monad to run in, such as `Result<(), E>` or `Option<()>`
+    ///
+    /// # Returns
+    /// The action's result if `cond` is `true`, `pure(())` otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::*;
+    ///
+    /// let check = |x: i32| when_m::<Result<(), &str>>(x < 0, || Err("negative"));
+    /// assert_eq!(check(3), Ok(()));
+    /// assert_eq!(check(-3), Err("negative"));
+    /// ```
+    pub fn when_m<M: Monad<()>>(
+        cond: bool,
+        action: impl FnOnce() -> Apply1<M::Kind1, ()>,
+    ) -> Apply1<M::Kind1, ()> {
+        if cond { action() } else { M::pure(()) }
+    }
+
+    /// Runs a monadic action only when a condition does not hold.
+    ///
+    /// The complement of `when_m`: the thunk is evaluated only when `cond` is
+    /// `false`.
+    ///
+    /// # Type Parameters
+    /// * `M` - The monad to run in, such as `Result<(), E>` or `Option<()>`
+    ///
+    /// # Returns
+    /// The action's result if `cond` is `false`, `pure(())` otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::*;
+    ///
+    /// let require = |present: bool| unless_m::<Option<()>>(present, || None);
+    /// assert_eq!(require(true), Some(()));
+    /// assert_eq!(require(false), None);
+    /// ```
+    pub fn unless_m<M: Monad<()>>(
+        cond: bool,
+        action: impl FnOnce() -> Apply1<M::Kind1, ()>,
+    ) -> Apply1<M::Kind1, ()> {
+        when_m::<M>(!cond, action)
+    }
+
+    #[cfg(test)]
+    mod when_m_tests {
+        use super::*;
+        use ::core::cell::Cell;
+
+        #[test]
+        fn result_runs_action_when_true() {
+            let calls = Cell::new(0);
+            let result = when_m::<Result<(), &str>>(true, || {
+                calls.set(calls.get() + 1);
+                Err("failed")
+            });
+            assert_eq!(result, Err("failed"));
+            assert_eq!(calls.get(), 1);
+        }
+
+        #[test]
+        fn result_skips_action_when_false() {
+            let calls = Cell::new(0);
+            let result = when_m::<Result<(), &str>>(false, || {
+                calls.set(calls.get() + 1);
+                Err("failed")
+            });
+            assert_eq!(result, Ok(()));
+            assert_eq!(calls.get(), 0);
+        }
+
+        #[test]
+        fn option_unless() {
+            let calls = Cell::new(0);
+            let action = || {
+                calls.set(calls.get() + 1);
+                None
+            };
+            assert_eq!(unless_m::<Option<()>>(true, action), Some(()));
+            assert_eq!(calls.get(), 0);
+            assert_eq!(unless_m::<Option<()>>(false, action), None);
+            assert_eq!(calls.get(), 1);
+        }
+
+        #[test]
+        fn chains_with_bind() {
+            let validate = |x: i32| {
+                when_m::<Result<(), &str>>(x > 100, || Err("too large")).bind(|()| Ok(x * 2))
+            };
+            assert_eq!(validate(5), Ok(10));
+            assert_eq!(validate(500), Err("too large"));
         }
     }
 
@@ -435,6 +3768,159 @@ pub mod utilities {
             $fn as fn(_) -> _
         };
     }
+
+    /// Maps a `const fn` over an `Option` in a const context.
+    ///
+    /// `Functor::fmap` cannot be called in const contexts, and a `const fn`
+    /// taking the function as a parameter would have to call a function
+    /// pointer, which stable Rust does not allow during const evaluation. This
+    /// macro instead expands to a `match` that calls the named function
+    /// directly, so it works in `const` items as long as the function is a
+    /// `const fn`. In practice that restricts it to primitive and other
+    /// `Copy` values, since few operations on heap types are `const`.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::fmap_const;
+    ///
+    /// const fn double(x: i32) -> i32 { x * 2 }
+    ///
+    /// const DOUBLED: Option<i32> = fmap_const!(Some(2), double);
+    /// assert_eq!(DOUBLED, Some(4));
+    /// ```
+    #[macro_export]
+    macro_rules! fmap_const {
+        ($opt:expr, $f:path) => {
+            match $opt {
+                Some(x) => Some($f(x)),
+                None => None,
+            }
+        };
+    }
+
+    #[cfg(test)]
+    mod fmap_const_tests {
+        const fn double(x: i32) -> i32 {
+            x * 2
+        }
+
+        const fn is_even(x: u8) -> bool {
+            x.is_multiple_of(2)
+        }
+
+        const DOUBLED: Option<i32> = fmap_const!(Some(2), double);
+        const NOTHING: Option<i32> = fmap_const!(None, double);
+        const TABLE: [Option<bool>; 3] = [
+            fmap_const!(Some(1), is_even),
+            fmap_const!(Some(2), is_even),
+            fmap_const!(None, is_even),
+        ];
+
+        #[test]
+        fn evaluates_at_compile_time() {
+            assert_eq!(DOUBLED, Some(4));
+            assert_eq!(NOTHING, None);
+            assert_eq!(TABLE, [Some(false), Some(true), None]);
+        }
+
+        #[test]
+        fn matches_fmap() {
+            use crate::*;
+
+            assert_eq!(fmap_const!(Some(21), double), Some(21).fmap(double));
+        }
+    }
+
+    /// Builds a `Vec` with a Haskell-style list comprehension.
+    ///
+    /// The comprehension is a result expression followed by `;`-separated
+    /// generators (`x <- vec`) and filters (`if cond`), read left to right.
+    /// It desugars to `Vec` `bind` for each generator and `guard` for each
+    /// filter, so later clauses may refer to names bound by earlier ones.
+    /// Values bound by a generator are reused for every element of the later
+    /// generators, so they must be `Copy` or cloned explicitly.
+    ///
+    /// # Example
+    /// ```
+    /// use crab_fp::*;
+    ///
+    /// let pairs = vec_comp![(x, y); x <- vec![1, 2, 3]; y <- vec![1, 2, 3]; if x + y == 4];
+    /// assert_eq!(pairs, vec![(1, 3), (2, 2), (3, 1)]);
+    /// ```
+    #[cfg(not(feature = "no_std"))]
+    #[macro_export]
+    macro_rules! vec_comp {
+        (@go $body:expr; $x:tt <- $e:expr; $($rest:tt)+) => {
+            $crate::Monad::bind($e, move |$x| $crate::vec_comp!(@go $body; $($rest)+))
+        };
+        (@go $body:expr; $x:tt <- $e:expr) => {
+            $crate::Monad::bind($e, move |$x| vec![$body])
+        };
+        (@go $body:expr; if $cond:expr; $($rest:tt)+) => {
+            $crate::Monad::bind($crate::guard::<Vec<()>>($cond), move |()| {
+                $crate::vec_comp!(@go $body; $($rest)+)
+            })
+        };
+        (@go $body:expr; if $cond:expr) => {
+            $crate::Monad::bind($crate::guard::<Vec<()>>($cond), move |()| vec![$body])
+        };
+        ($body:expr; $($rest:tt)+) => {
+            $crate::vec_comp!(@go $body; $($rest)+)
+        };
+    }
+
+    #[cfg(test)]
+    #[cfg(not(feature = "no_std"))]
+    mod vec_comp_tests {
+        use super::*;
+
+        #[test]
+        fn single_generator() {
+            assert_eq!(vec_comp![x * 2; x <- vec![1, 2, 3]], vec![2, 4, 6]);
+        }
+
+        #[test]
+        fn filtered_pairs_match_bind_chain() {
+            let comp = vec_comp![(x, y); x <- vec![1, 2, 3]; y <- vec![1, 2, 3]; if x + y == 4];
+            let manual = vec![1, 2, 3].bind(|x| {
+                vec![1, 2, 3]
+                    .bind(move |y| guard::<Vec<()>>(x + y == 4).bind(move |()| vec![(x, y)]))
+            });
+            assert_eq!(comp, manual);
+            assert_eq!(comp, vec![(1, 3), (2, 2), (3, 1)]);
+        }
+
+        #[test]
+        fn filter_between_generators() {
+            let comp =
+                vec_comp![(x, y); x <- vec![1, 2, 3, 4]; if x % 2 == 0; y <- vec![x, x * 10]];
+            assert_eq!(comp, vec![(2, 2), (2, 20), (4, 4), (4, 40)]);
+        }
+
+        #[test]
+        fn dependent_generators() {
+            let triangles = vec_comp![
+                (a, b, c);
+                c <- (1..=13).collect::<Vec<_>>();
+                b <- (1..=c).collect::<Vec<_>>();
+                a <- (1..=b).collect::<Vec<_>>();
+                if a * a + b * b == c * c
+            ];
+            assert_eq!(triangles, vec![(3, 4, 5), (6, 8, 10), (5, 12, 13)]);
+        }
+
+        #[test]
+        fn tuple_pattern() {
+            let comp = vec_comp![k * v; (k, v) <- vec![(1, 2), (3, 4)]];
+            assert_eq!(comp, vec![2, 12]);
+        }
+
+        #[test]
+        fn filter_rejects_everything() {
+            let comp: Vec<i32> = vec_comp![x; x <- vec![1, 2, 3]; if x > 5];
+            assert!(comp.is_empty());
+        }
+    }
 }
 
 #[cfg(test)]